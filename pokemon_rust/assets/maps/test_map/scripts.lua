@@ -59,5 +59,6 @@ function interact_with_npc(npc)
         TextEvent:new("Hello, world!"):dispatch()
     elseif npc == SECOND_NPC then
         print("Interacted with the second NPC")
+        TrainerBattleEvent:new(npc, BattleAiKinds["lookahead"]):dispatch()
     end
 end
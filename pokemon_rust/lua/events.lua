@@ -72,6 +72,22 @@ function TextEvent:new(text)
 end
 
 
+BattleAiKinds = {
+    random = 0,
+    greedy = 1,
+    lookahead = 2,
+}
+
+TrainerBattleEvent = Event:new()
+TrainerBattleEvent.__index = TrainerBattleEvent
+
+function TrainerBattleEvent:new(npc, ai)
+    local obj = { rust_create_trainer_battle_event(npc, ai) }
+    setmetatable(obj, self)
+    return obj
+end
+
+
 WarpEvent = Event:new()
 WarpEvent.__index = WarpEvent
 
@@ -86,12 +86,14 @@ pub enum AudioFileFormat {
 /// An enumeration with the possible sound effects of the game.
 #[derive(Eq, Hash, PartialEq)]
 pub enum Sound {
+    BattleRun,
     SelectOption,
 }
 
 impl Sound {
     fn get_filename(&self) -> &str {
         match self {
+            Sound::BattleRun => "sfx/battle_run.wav",
             Sound::SelectOption => "sfx/select_option.wav",
         }
     }
@@ -174,15 +176,11 @@ pub fn initialise_audio(world: &mut World) {
         let mut sound_storage = SoundStorage {
             sounds: HashMap::new(),
         };
-        sound_storage.sounds.insert(
-            Sound::SelectOption,
-            loader.load(
-                Sound::SelectOption.get_filename(),
-                WavFormat,
-                (),
-                &world.read_resource(),
-            ),
-        );
+
+        for sound in vec![Sound::BattleRun, Sound::SelectOption] {
+            let handle = loader.load(sound.get_filename(), WavFormat, (), &world.read_resource());
+            sound_storage.sounds.insert(sound, handle);
+        }
 
         sound_storage
     };
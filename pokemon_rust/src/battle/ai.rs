@@ -0,0 +1,385 @@
+//! Decision making for the Pokémon that aren't controlled by the player.
+
+use crate::{
+    battle::backend::{
+        rng::BattleRng,
+        BattleBackend,
+        FieldPosition,
+        FrontendEvent,
+        FrontendEventKind,
+        Team,
+    },
+    constants::MOVE_LIMIT,
+    pokemon::{
+        get_all_moves,
+        movement::{FixedDamage, Move, MoveCategory, MoveFlag, MovePower},
+        Stat,
+    },
+};
+
+use serde::{Deserialize, Serialize};
+
+pub trait BattleAi {
+    /// Chooses the action of an active Pokémon for the current turn. The
+    /// returned action is always accepted by the backend, i.e it's either a
+    /// move that can be used or a switch that is allowed. Random decisions
+    /// must be drawn from `rng`, which is the RNG of the battle, so that the
+    /// battle can be replayed.
+    fn choose_action(
+        &mut self,
+        backend: &BattleBackend,
+        rng: &mut dyn BattleRng,
+        pokemon: usize,
+    ) -> FrontendEventKind;
+}
+
+/// The strategies that an opponent can use in battle.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum BattleAiKind {
+    /// Picks any move that can be used. Wild Pokémon behave this way.
+    Random,
+    /// Picks the move that is expected to deal the most damage.
+    Greedy,
+    /// Also considers what the foes are likely to do in response, switching
+    /// out if the current Pokémon is in trouble.
+    Lookahead,
+}
+
+impl BattleAiKind {
+    pub fn create(self) -> Box<dyn BattleAi + Sync + Send> {
+        match self {
+            BattleAiKind::Random => Box::new(RandomAi),
+            BattleAiKind::Greedy => Box::new(GreedyAi),
+            BattleAiKind::Lookahead => Box::new(LookaheadAi),
+        }
+    }
+}
+
+pub struct RandomAi;
+
+impl BattleAi for RandomAi {
+    fn choose_action(
+        &mut self,
+        backend: &BattleBackend,
+        rng: &mut dyn BattleRng,
+        pokemon: usize,
+    ) -> FrontendEventKind {
+        let usable_moves = get_usable_moves(backend, pokemon);
+        let move_index = if usable_moves.is_empty() {
+            0
+        } else {
+            usable_moves[rng.get_random_ai_choice(usable_moves.len())]
+        };
+
+        FrontendEventKind::UseMove {
+            move_index,
+            target: None,
+        }
+    }
+}
+
+pub struct GreedyAi;
+
+impl BattleAi for GreedyAi {
+    fn choose_action(
+        &mut self,
+        backend: &BattleBackend,
+        _rng: &mut dyn BattleRng,
+        pokemon: usize,
+    ) -> FrontendEventKind {
+        let mut best_action = None;
+        let mut best_damage = 0;
+
+        for move_index in get_usable_moves(backend, pokemon) {
+            let movement = get_move(backend, pokemon, move_index);
+
+            for foe in get_foes(backend, pokemon) {
+                let damage = estimate_damage(backend, pokemon, foe, movement);
+
+                if damage > best_damage {
+                    best_action = Some(use_move(backend, move_index, foe));
+                    best_damage = damage;
+                }
+            }
+        }
+
+        // If no move deals damage, the first one that can be used is picked
+        best_action.unwrap_or_else(|| FrontendEventKind::UseMove {
+            move_index: get_usable_moves(backend, pokemon).first().copied().unwrap_or(0),
+            target: None,
+        })
+    }
+}
+
+pub struct LookaheadAi;
+
+impl LookaheadAi {
+    /// Returns the fraction of the current HP of a Pokémon that it's expected
+    /// to lose to the foes during the turn. The foes are assumed to use their
+    /// most damaging moves. The foe that is going to be knocked out before it
+    /// can act, if any, is left out.
+    fn get_threat(
+        backend: &BattleBackend,
+        pokemon: usize,
+        foes: &[usize],
+        knocked_out_foe: Option<usize>,
+    ) -> f32 {
+        let damage: usize = foes
+            .iter()
+            .filter(|foe| Some(**foe) != knocked_out_foe)
+            .map(|foe| {
+                get_usable_moves(backend, *foe)
+                    .into_iter()
+                    .map(|move_index| {
+                        let movement = get_move(backend, *foe, move_index);
+
+                        estimate_damage(backend, *foe, pokemon, movement)
+                    })
+                    .max()
+                    .unwrap_or(0)
+            })
+            .sum();
+
+        let current_hp = backend.get_pokemon(pokemon).current_hp.max(1);
+
+        (damage as f32 / current_hp as f32).min(1.)
+    }
+
+    /// Returns whether a Pokémon is expected to use a move before a foe acts.
+    /// Speed ties are assumed to be lost.
+    fn moves_first(backend: &BattleBackend, pokemon: usize, movement: &Move, foe: usize) -> bool {
+        if movement.priority != 0 {
+            return movement.priority > 0;
+        }
+
+        backend.get_stat(pokemon, Stat::Speed) > backend.get_stat(foe, Stat::Speed)
+    }
+
+    /// Switching is only considered when a single Pokémon of the team is on
+    /// the field, so that two Pokémon never try to send in the same one.
+    fn can_switch_out(backend: &BattleBackend, pokemon: usize) -> bool {
+        let team = backend.get_pokemon_team(pokemon);
+        let active_pokemon_count = backend
+            .get_active_positions()
+            .into_iter()
+            .filter(|position| position.team == team)
+            .count();
+
+        active_pokemon_count == 1
+            && !backend.is_trapped(pokemon)
+            && !backend.has_forced_action(pokemon)
+    }
+}
+
+impl BattleAi for LookaheadAi {
+    fn choose_action(
+        &mut self,
+        backend: &BattleBackend,
+        _rng: &mut dyn BattleRng,
+        pokemon: usize,
+    ) -> FrontendEventKind {
+        let foes = get_foes(backend, pokemon);
+        let mut best_action = None;
+        let mut best_score = f32::MIN;
+
+        // Moves are rated by the damage they deal, with a bonus for knocking
+        // out a foe, minus the damage that the user is going to take
+        for move_index in get_usable_moves(backend, pokemon) {
+            let movement = get_move(backend, pokemon, move_index);
+
+            for foe in &foes {
+                let foe_hp = backend.get_pokemon(*foe).current_hp.max(1);
+                let damage = estimate_damage(backend, pokemon, *foe, movement).min(foe_hp);
+                let knocks_out = damage == foe_hp;
+
+                let knocked_out_foe = if knocks_out
+                    && Self::moves_first(backend, pokemon, movement, *foe)
+                {
+                    Some(*foe)
+                } else {
+                    None
+                };
+
+                let mut score = damage as f32 / foe_hp as f32
+                    - Self::get_threat(backend, pokemon, &foes, knocked_out_foe);
+
+                if knocks_out {
+                    score += 1.;
+                }
+
+                if score > best_score {
+                    best_action = Some(use_move(backend, move_index, *foe));
+                    best_score = score;
+                }
+            }
+        }
+
+        // Switching in a Pokémon means that it takes the hits of the foes
+        // without dealing any damage
+        if Self::can_switch_out(backend, pokemon) {
+            let team = backend.get_pokemon_team(pokemon);
+
+            for party_index in 0..backend.get_party(team).count() {
+                if !backend.can_switch_in(team, party_index) {
+                    continue;
+                }
+
+                let member = backend.get_party_member(team, party_index);
+                let score = -Self::get_threat(backend, member, &foes, None);
+
+                if score > best_score {
+                    best_action = Some(FrontendEventKind::Switch(party_index));
+                    best_score = score;
+                }
+            }
+        }
+
+        best_action.unwrap_or(FrontendEventKind::UseMove {
+            move_index: 0,
+            target: None,
+        })
+    }
+}
+
+/// Returns the actions of a team controlled by an AI for the next tick: the
+/// Pokémon to send in if the team has to replace one, or the action of each
+/// of its active Pokémon otherwise. No actions are returned while the foes
+/// are sending in replacements.
+pub fn choose_team_actions(
+    backend: &mut BattleBackend,
+    team: Team,
+    ai: &mut dyn BattleAi,
+) -> Vec<FrontendEvent> {
+    if backend.requires_replacement(team) {
+        let party_indices: Vec<_> = backend
+            .get_party(team)
+            .enumerate()
+            .map(|(index, _)| index)
+            .filter(|index| backend.can_switch_in(team, *index))
+            .collect();
+
+        backend
+            .get_empty_slots(team)
+            .zip(party_indices)
+            .map(|(slot, party_index)| FrontendEvent {
+                team,
+                slot,
+                event: FrontendEventKind::Switch(party_index),
+            })
+            .collect()
+    } else if !backend.requires_replacement(team.opponent()) {
+        let slots: Vec<_> = backend
+            .get_active_positions()
+            .into_iter()
+            .filter(|position| position.team == team)
+            .map(|position| position.slot)
+            .collect();
+
+        // The AI borrows the RNG of the battle while looking at the backend,
+        // and hands it back with the values it drew already consumed
+        let mut rng = backend.rng.boxed_clone();
+
+        let actions = slots
+            .into_iter()
+            .map(|slot| {
+                let user = backend.get_pokemon_at(FieldPosition { team, slot }).unwrap();
+
+                FrontendEvent {
+                    team,
+                    slot,
+                    event: ai.choose_action(backend, &mut *rng, user),
+                }
+            })
+            .collect();
+
+        backend.rng = rng;
+        actions
+    } else {
+        Vec::new()
+    }
+}
+
+/// Returns the indices of the moves that a Pokémon is allowed to choose.
+/// If there are none, the Pokémon is going to use Struggle regardless of the
+/// chosen move.
+fn get_usable_moves(backend: &BattleBackend, pokemon: usize) -> Vec<usize> {
+    (0..MOVE_LIMIT)
+        .filter(|move_index| {
+            backend.get_pokemon(pokemon).moves[*move_index].is_some()
+                && backend.get_move_restriction(pokemon, *move_index).is_none()
+        })
+        .collect()
+}
+
+fn get_move(backend: &BattleBackend, pokemon: usize, move_index: usize) -> &'static Move {
+    let move_id = backend.get_pokemon(pokemon).moves[move_index].as_ref().unwrap();
+
+    get_all_moves().get_move(move_id).unwrap()
+}
+
+/// Returns the active Pokémon of the team opposing a given Pokémon.
+fn get_foes(backend: &BattleBackend, pokemon: usize) -> Vec<usize> {
+    let team = backend.get_pokemon_team(pokemon);
+
+    backend
+        .get_active_positions()
+        .into_iter()
+        .filter(|position| position.team != team)
+        .filter_map(|position| backend.get_pokemon_at(position))
+        .collect()
+}
+
+fn use_move(backend: &BattleBackend, move_index: usize, target: usize) -> FrontendEventKind {
+    FrontendEventKind::UseMove {
+        move_index,
+        target: backend.get_pokemon_position(target),
+    }
+}
+
+/// Returns a rough estimate of the damage that a move would deal, taking
+/// type effectiveness and STAB into account. Accuracy, multiple hits and the
+/// other modifiers of the damage formula are ignored.
+fn estimate_damage(backend: &BattleBackend, user: usize, target: usize, movement: &Move) -> usize {
+    let effectiveness = backend.get_type_effectiveness(movement, target);
+
+    if movement.category == MoveCategory::Status || effectiveness <= 0. {
+        return 0;
+    }
+
+    let target_hp = backend.get_pokemon(target).current_hp;
+
+    if movement.flags.contains(&MoveFlag::OneHitKO) {
+        return target_hp;
+    }
+
+    if let Some(fixed_damage) = &movement.fixed_damage {
+        return match fixed_damage {
+            FixedDamage::Constant(damage) => *damage,
+            FixedDamage::UserLevel => backend.get_pokemon(user).level,
+            FixedDamage::TargetCurrentHp(percentage) => (target_hp * percentage / 100).max(1),
+            FixedDamage::Custom(callback) => callback(backend, user, target),
+        };
+    }
+
+    let power = match movement.power_modifier {
+        Some(modifier) => modifier(backend, user, target, movement),
+        None => match movement.base_power {
+            MovePower::Constant(value) => value,
+            MovePower::Special => 0,
+        },
+    };
+
+    let (attack, defense) = match movement.category {
+        MoveCategory::Physical => (Stat::Attack, Stat::Defense),
+        _ => (Stat::SpecialAttack, Stat::SpecialDefense),
+    };
+
+    let level = backend.get_pokemon(user).level as f32;
+    let stat_ratio =
+        backend.get_stat(user, attack) as f32 / backend.get_stat(target, defense) as f32;
+    let stab = if backend.check_stab(movement, user) { 1.5 } else { 1. };
+
+    let base_damage = ((2. * level / 5. + 2.) * power as f32 * stat_ratio / 50.).floor() + 2.;
+
+    (base_damage * stab * effectiveness) as usize
+}
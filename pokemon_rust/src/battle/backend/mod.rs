@@ -1,12 +1,18 @@
 pub mod rng;
 
 use crate::{
+    constants::MOVE_LIMIT,
     overworld::entities::character::CharacterId,
     pokemon::{
+        get_ability_effect,
         get_all_moves,
         get_all_pokemon_species,
+        get_confusion_move,
+        get_item_effect,
         get_status_condition_effect,
         movement::{
+            CalledMove,
+            FixedDamage,
             ModifiedAccuracy,
             ModifiedUsageAttempt,
             Move,
@@ -16,7 +22,10 @@ use crate::{
             MultiHit,
             SimpleEffect,
             SimpleEffectTarget,
+            TargetType,
         },
+        AbilityEffect,
+        ItemEffect,
         Pokemon,
         PokemonSpeciesData,
         PokemonType,
@@ -27,29 +36,53 @@ use crate::{
     },
 };
 
-use std::collections::{HashMap, VecDeque};
+use serde::{Deserialize, Serialize};
+
+use std::{
+    collections::{HashMap, VecDeque},
+    time::SystemTime,
+};
 
 use self::rng::BattleRng;
 
 use super::types::{Battle, BattleType};
 
 /// Represents an event that can be sent from the frontend to the backend.
-#[derive(Debug)]
+/// Every turn, each team must send one event for each of its active Pokémon.
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct FrontendEvent {
     pub team: Team,
+    /// The active slot of the Pokémon that performs this action.
+    pub slot: usize,
     pub event: FrontendEventKind,
 }
 
 /// The kind of events that the frontend can send to the backend.
-#[derive(Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum FrontendEventKind {
-    UseMove(usize),
+    /// Uses the move at the given index of the Pokémon's move list. Moves
+    /// that affect a single Pokémon other than the user also need a target,
+    /// otherwise the first valid one is picked.
+    UseMove {
+        move_index: usize,
+        target: Option<FieldPosition>,
+    },
+    /// Switches the active Pokémon with the one at the given index of the
+    /// team's party.
+    Switch(usize),
+    /// Attempts to flee from the battle. Only possible in wild battles.
+    Run,
+    /// Throws a Pokéball of the given kind at the opponent's active Pokémon.
+    /// Only possible in wild battles.
+    ThrowPokeball(String),
 }
 
 /// The kind of events that the backend can send to the frontend.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum BattleEvent {
     InitialSwitchIn(event::InitialSwitchIn),
+    SwitchOut(event::SwitchOut),
+    SwitchIn(event::SwitchIn),
     ChangeTurn(event::ChangeTurn),
     UseMove(event::UseMove),
     Damage(event::Damage),
@@ -61,25 +94,62 @@ pub enum BattleEvent {
     ExpiredNonVolatileStatusCondition(event::ExpiredNonVolatileStatusCondition),
     FailedMove(event::FailedMove),
     Faint(event::Faint),
+    Escaped(event::Escaped),
+    EscapeFailed(event::EscapeFailed),
+    CaptureAttempt(event::CaptureAttempt),
+    InvalidPokeball(event::InvalidPokeball),
+    CaptureRefused(event::CaptureRefused),
+    WeatherStart(event::WeatherStart),
+    WeatherEnd(event::WeatherEnd),
+    WeatherDamage(event::WeatherDamage),
+    SideConditionStart(event::SideConditionStart),
+    SideConditionEnd(event::SideConditionEnd),
+    AbilityActivated(event::AbilityActivated),
+    Heal(event::Heal),
+    ItemActivated(event::ItemActivated),
+    ItemConsumed(event::ItemConsumed),
+    Protected(event::Protected),
+    Charging(event::Charging),
+    Recharging(event::Recharging),
+    DisabledMove(event::DisabledMove),
+    PpReduction(event::PpReduction),
+    NoPpLeft(event::NoPpLeft),
+    SubstituteCreated(event::SubstituteCreated),
+    SubstituteDamaged(event::SubstituteDamaged),
+    SubstituteBroken(event::SubstituteBroken),
+    PerishCount(event::PerishCount),
+    DestinyBond(event::DestinyBond),
+    StatStagesReset(event::StatStagesReset),
+    StatStagesCopied(event::StatStagesCopied),
+    BattleEnd(event::BattleEnd),
 }
 
 pub mod event {
+    use serde::{Deserialize, Serialize};
+
     use super::{
+        BattleEndReason,
+        CaptureRefusalCause,
         DamageCause,
+        EscapeFailureCause,
         Flag,
+        Protection,
+        SideCondition,
         SimpleStatusCondition,
         Stat,
         StatChangeKind,
         StatusCondition,
         Team,
         TypeEffectiveness,
+        Weather,
     };
 
     /// Corresponds to the very first switch-in of a battle participant in a
     /// battle.
-    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct InitialSwitchIn {
         pub team: Team,
+        pub slot: usize,
         pub pokemon: usize,
         /// Indicates if the Pokémon is already sent out when the battle
         /// started. The frontend uses this to decide whether a pokéball
@@ -88,18 +158,35 @@ pub mod event {
         pub is_already_sent_out: bool,
     }
 
-    #[derive(Clone, Debug, Eq, PartialEq)]
+    /// Corresponds to a Pokémon being withdrawn from the battlefield by its
+    /// trainer.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct SwitchOut {
+        pub team: Team,
+        pub slot: usize,
+        pub pokemon: usize,
+    }
+
+    /// Corresponds to a Pokémon entering the battlefield after the first turn.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct SwitchIn {
+        pub team: Team,
+        pub slot: usize,
+        pub pokemon: usize,
+    }
+
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct ChangeTurn {
         pub new_turn: usize,
     }
 
-    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct UseMove {
         pub move_user: usize,
         pub move_name: String,
     }
 
-    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct Damage {
         pub target: usize,
         pub amount: usize,
@@ -111,64 +198,307 @@ pub mod event {
         pub cause: DamageCause,
     }
 
-    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct Miss {
         pub target: usize,
         pub move_user: usize,
-        pub caused_by_confusion: bool,
     }
 
-    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct StatChange {
         pub target: usize,
         pub kind: StatChangeKind,
         pub stat: Stat,
     }
 
-    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct VolatileStatusCondition {
         pub target: usize,
         pub added_flag: Flag,
     }
 
-    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct ExpiredVolatileStatusCondition {
         pub target: usize,
         pub flag: Flag,
     }
 
-    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct NonVolatileStatusCondition {
         pub target: usize,
         pub condition: StatusCondition,
     }
 
-    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct ExpiredNonVolatileStatusCondition {
         pub target: usize,
         pub condition: SimpleStatusCondition,
     }
 
-    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct FailedMove {
         pub move_user: usize,
     }
 
-    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct Faint {
         pub target: usize,
     }
+
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct Escaped {
+        pub team: Team,
+    }
+
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct EscapeFailed {
+        pub team: Team,
+        pub cause: EscapeFailureCause,
+    }
+
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct CaptureAttempt {
+        pub team: Team,
+        pub target: usize,
+        pub pokeball: String,
+        /// How many times the Pokéball shook, between 0 and 3.
+        pub shakes: usize,
+        pub success: bool,
+    }
+
+    /// Corresponds to a team trying to throw an item that isn't a known
+    /// Pokéball. The action is wasted.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct InvalidPokeball {
+        pub team: Team,
+        pub pokeball: String,
+    }
+
+    /// Corresponds to a team trying to throw a Pokéball when no Pokémon can
+    /// be caught. This doesn't consume a turn.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct CaptureRefused {
+        pub team: Team,
+        pub cause: CaptureRefusalCause,
+    }
+
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct WeatherStart {
+        pub weather: Weather,
+    }
+
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct WeatherEnd {
+        pub weather: Weather,
+    }
+
+    /// Corresponds to a Pokémon being hurt by the weather at the end of the
+    /// turn. It's immediately followed by the corresponding `Damage` event.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct WeatherDamage {
+        pub target: usize,
+        pub weather: Weather,
+    }
+
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct SideConditionStart {
+        pub team: Team,
+        pub condition: SideCondition,
+    }
+
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct SideConditionEnd {
+        pub team: Team,
+        pub condition: SideCondition,
+    }
+
+    /// Corresponds to an ability announcing itself, e.g "Gyarados's
+    /// Intimidate!". The effects of the ability are sent as separate events.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct AbilityActivated {
+        pub pokemon: usize,
+        pub ability: String,
+    }
+
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct Heal {
+        pub target: usize,
+        pub amount: usize,
+    }
+
+    /// Corresponds to a held item taking effect without being consumed, e.g
+    /// Leftovers. The effects of the item are sent as separate events.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct ItemActivated {
+        pub pokemon: usize,
+        pub item: String,
+    }
+
+    /// Corresponds to a held item being used up, e.g a berry. The item is
+    /// removed from the Pokémon and its effects are sent as separate events.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct ItemConsumed {
+        pub pokemon: usize,
+        pub item: String,
+    }
+
+    /// Corresponds to a move being blocked by a protection, e.g Protect.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct Protected {
+        pub target: usize,
+        pub protection: Protection,
+    }
+
+    /// Corresponds to a Pokémon spending its turn charging a move, e.g Solar
+    /// Beam.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct Charging {
+        pub pokemon: usize,
+        pub move_id: String,
+    }
+
+    /// Corresponds to a Pokémon spending its turn recharging after using a
+    /// move like Hyper Beam.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct Recharging {
+        pub pokemon: usize,
+    }
+
+    /// Corresponds to a Pokémon trying to use a move that was disabled, e.g
+    /// by Disable.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct DisabledMove {
+        pub pokemon: usize,
+        pub move_id: String,
+    }
+
+    /// Corresponds to a move losing some of its PP, e.g because of Spite.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct PpReduction {
+        pub target: usize,
+        pub move_id: String,
+        pub amount: usize,
+    }
+
+    /// Corresponds to a Pokémon being ordered to use a move that has no PP
+    /// left. This doesn't consume a turn.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct NoPpLeft {
+        pub pokemon: usize,
+        pub move_id: String,
+    }
+
+    /// Corresponds to a Pokémon putting up a substitute.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct SubstituteCreated {
+        pub pokemon: usize,
+    }
+
+    /// Corresponds to a substitute taking a hit in place of its Pokémon.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct SubstituteDamaged {
+        pub pokemon: usize,
+        pub amount: usize,
+    }
+
+    /// Corresponds to a substitute fading after running out of HP.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct SubstituteBroken {
+        pub pokemon: usize,
+    }
+
+    /// Corresponds to the perish count of a Pokémon being shown at the end
+    /// of a turn. The Pokémon faints when it reaches zero.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct PerishCount {
+        pub pokemon: usize,
+        pub count: usize,
+    }
+
+    /// Corresponds to a Pokémon under the effects of Destiny Bond taking down
+    /// the foe that made it faint.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct DestinyBond {
+        pub pokemon: usize,
+        pub target: usize,
+    }
+
+    /// Corresponds to the stat stages of a Pokémon going back to zero, e.g
+    /// because of Haze.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct StatStagesReset {
+        pub pokemon: usize,
+    }
+
+    /// Corresponds to a Pokémon copying the stat stages of another one, e.g
+    /// by using Psych Up.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct StatStagesCopied {
+        pub user: usize,
+        pub target: usize,
+    }
+
+    /// Corresponds to the end of the battle. No further events are emitted
+    /// after this one.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct BattleEnd {
+        pub winner: Team,
+        pub reason: BattleEndReason,
+    }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum DamageCause {
     Move,
     Burn,
     Poison,
     Toxic,
+    Weather,
+    Ability,
+    StealthRock,
+    Recoil,
+    Trap,
+    Substitute,
+    LeechSeed,
+    Curse,
+    PerishSong,
+    DestinyBond,
+    Confusion,
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum BattleEndReason {
+    /// One of the teams has no Pokémon able to fight.
+    AllPokemonFainted,
+    /// The losing team successfully fled from the battle.
+    Escaped,
+    /// The winning team caught the opposing wild Pokémon.
+    PokemonCaught,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum EscapeFailureCause {
+    /// The escape attempt failed the speed-based escape check. This wastes
+    /// the turn.
+    BadOdds,
+    /// Fleeing from trainer battles is not allowed. This doesn't consume a
+    /// turn.
+    TrainerBattle,
+    /// The Pokémon can't flee or switch out, e.g because of Wrap. This
+    /// doesn't consume a turn.
+    Trapped,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum CaptureRefusalCause {
+    /// Pokémon owned by trainers can't be caught.
+    TrainerBattle,
+    /// Pokéballs can only be thrown when a single wild Pokémon is on the
+    /// battlefield.
+    MultipleTargets,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum TypeEffectiveness {
     Immune,
     BarelyEffective,
@@ -194,7 +524,7 @@ impl TypeEffectiveness {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum StatChangeKind {
     WontGoAnyLower,
     SeverelyFell,
@@ -206,16 +536,169 @@ pub enum StatChangeKind {
     WontGoAnyHigher,
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum Team {
     P1,
     P2,
 }
 
+impl Team {
+    pub fn opponent(self) -> Team {
+        match self {
+            Team::P1 => Team::P2,
+            Team::P2 => Team::P1,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum Weather {
+    Rain,
+    Sun,
+    Sandstorm,
+    Hail,
+}
+
+/// The weather that is currently affecting the battlefield.
+#[derive(Debug)]
+struct WeatherState {
+    weather: Weather,
+    /// How many turns are left before the weather ends, including the
+    /// current one.
+    remaining_turns: usize,
+}
+
+/// An effect that applies to one side of the battlefield, affecting every
+/// Pokémon of a team.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum SideCondition {
+    /// Weakens physical moves used against the team.
+    Reflect,
+    /// Weakens special moves used against the team.
+    LightScreen,
+    /// Prevents status conditions and confusion caused by other Pokémon.
+    Safeguard,
+    /// Prevents stat drops caused by other Pokémon.
+    Mist,
+    /// Doubles the speed of the team.
+    Tailwind,
+    /// Hurts Pokémon when they switch in, based on their weakness to Rock.
+    StealthRock,
+}
+
+impl SideCondition {
+    /// Returns how many turns this side condition lasts, or `None` if it
+    /// lasts until the end of the battle.
+    pub fn get_duration(self) -> Option<usize> {
+        match self {
+            SideCondition::Reflect
+            | SideCondition::LightScreen
+            | SideCondition::Safeguard
+            | SideCondition::Mist => Some(5),
+            SideCondition::Tailwind => Some(4),
+            SideCondition::StealthRock => None,
+        }
+    }
+
+    /// Entry hazards are placed on the side of the opponents of the Pokémon
+    /// that creates them.
+    pub fn is_entry_hazard(self) -> bool {
+        match self {
+            SideCondition::StealthRock => true,
+            _ => false,
+        }
+    }
+}
+
+/// An action that a Pokémon has to take instead of choosing a move.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum ForcedAction {
+    /// Unleashes the move that was charged in the previous turn.
+    ChargedMove {
+        move_id: String,
+        target: Option<FieldPosition>,
+    },
+    /// Does nothing after using a move like Hyper Beam.
+    Recharge { move_id: String },
+    /// Keeps using a move like Thrash, becoming confused when it ends.
+    Rampage {
+        move_id: String,
+        target: Option<FieldPosition>,
+        remaining_turns: usize,
+    },
+}
+
+/// Protects Pokémon from moves until the end of the turn.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum Protection {
+    /// Blocks every move used against the user, e.g Protect or Detect.
+    Full,
+    /// Blocks moves that affect multiple Pokémon, protecting the whole team.
+    WideGuard,
+    /// Blocks moves with increased priority, protecting the whole team.
+    QuickGuard,
+    /// Doesn't block anything, but lets the user survive any hit with 1 HP,
+    /// e.g Endure.
+    Endure,
+}
+
+/// A side condition that is currently affecting one of the teams.
+#[derive(Debug)]
+struct SideConditionState {
+    /// How many turns are left before the side condition ends, including the
+    /// current one. Permanent side conditions have no counter.
+    remaining_turns: Option<usize>,
+}
+
+/// The damage that a Pokémon received from the last move that hit it in the
+/// current turn.
+#[derive(Clone, Debug)]
+pub struct ReceivedDamage {
+    /// The Pokémon that used the move.
+    pub source: usize,
+    pub category: MoveCategory,
+    pub amount: usize,
+}
+
+/// A move that was used at some point of the battle.
+#[derive(Clone, Debug)]
+pub struct MoveHistoryEntry {
+    pub user: usize,
+    pub move_id: String,
+}
+
+/// The reasons why a Pokémon might be unable to choose one of its moves.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MoveRestriction {
+    /// The move was disabled, e.g by Disable.
+    Disabled,
+    /// The Pokémon can only use another move, e.g because of Encore.
+    LockedIntoOtherMove,
+    /// The move has no PP left.
+    NoPp,
+}
+
+/// Refers to one of the active slots of the battlefield.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FieldPosition {
+    pub team: Team,
+    pub slot: usize,
+}
+
+/// A move that was chosen by the frontend and is waiting to be executed.
+pub struct PendingMove<'a> {
+    user: usize,
+    target: Option<FieldPosition>,
+    movement: &'a Move,
+}
+
+/// A move being executed against one of its targets.
 pub struct UsedMove<'a> {
     user: usize,
     target: usize,
     movement: &'a Move,
+    /// Whether the move hits more than one Pokémon, which reduces its damage.
+    has_multiple_targets: bool,
 }
 
 #[derive(Debug)]
@@ -236,13 +719,32 @@ pub struct BattleBackend {
     pub(super) pokemon_repository: HashMap<usize, Pokemon>,
     /// The RNG that this battle is using.
     pub(super) rng: Box<dyn BattleRng + Sync + Send>,
+    /// How the battle ended, if it already did.
+    outcome: Option<event::BattleEnd>,
+    /// The wild Pokémon that was caught during this battle, if any.
+    caught_pokemon: Option<usize>,
+    weather: Option<WeatherState>,
+    /// The damage received by each Pokémon in the current turn, e.g for
+    /// Counter.
+    received_damage: HashMap<usize, ReceivedDamage>,
+    /// Every move used in this battle, oldest first. Moves that call other
+    /// moves (e.g Metronome) are recorded as the move they called.
+    move_history: Vec<MoveHistoryEntry>,
 }
 
 #[derive(Debug)]
 pub(super) struct TeamData {
-    pub(super) active_pokemon: Option<usize>,
+    /// The Pokémon in each of the active slots of this team. A slot is empty
+    /// when its Pokémon has fainted and couldn't be replaced yet.
+    pub(super) active_pokemon: Vec<Option<usize>>,
     party: VecDeque<usize>,
     character_id: Option<CharacterId>,
+    /// How many times this team has tried to flee from the battle.
+    escape_attempts: usize,
+    side_conditions: HashMap<SideCondition, SideConditionState>,
+    /// The Pokémon that left an active slot by using Baton Pass, by slot.
+    /// They are kept until their replacement is sent in.
+    baton_passes: HashMap<usize, BatonPass>,
 }
 
 #[derive(Debug, Default)]
@@ -250,11 +752,74 @@ struct FlagContainer {
     flags: HashMap<&'static str, Flag>,
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+/// A Pokémon that used Baton Pass, along with the flags that will be passed
+/// to its replacement.
+#[derive(Debug)]
+struct BatonPass {
+    user: usize,
+    flags: FlagContainer,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum Flag {
+    /// Overrides the ability of a Pokémon, e.g after using Trace.
+    Ability(String),
+    /// Set when a Pokémon holding a Choice item uses a move, which is the
+    /// only one it can use until it switches out.
+    ChoiceLock(String),
     Confusion { remaining_move_attempts: usize },
+    /// Set by a Ghost-type Curse, which hurts the Pokémon at the end of each
+    /// turn.
+    Curse,
+    /// Makes the foe that knocks out the Pokémon faint as well, until the
+    /// Pokémon uses another move.
+    DestinyBond,
+    /// Prevents a Pokémon from using one of its moves, e.g after being hit
+    /// by Disable.
+    Disabled {
+        move_id: String,
+        remaining_turns: usize,
+    },
+    /// Forces a Pokémon to keep using the same move, e.g after being hit by
+    /// Encore.
+    Encore {
+        move_id: String,
+        remaining_turns: usize,
+    },
+    /// Set by Yawn. The Pokémon falls asleep once the counter runs out.
+    Drowsy { remaining_turns: usize },
+    /// Set when Flash Fire absorbs a Fire-type move.
+    FlashFire,
     Flinch,
+    ForcedAction(ForcedAction),
+    /// Set by Leech Seed, which drains HP from the Pokémon at the end of each
+    /// turn. The HP goes to whoever occupies the slot of the seeder.
+    LeechSeed { seeder_position: FieldPosition },
+    /// Set by Perish Song. The Pokémon faints once the count reaches zero.
+    PerishCount(usize),
+    Protection(Protection),
+    /// Set by moves like Wrap, which hurt the target at the end of each turn
+    /// and prevent it from fleeing or switching out.
+    Trapped {
+        trapper: usize,
+        move_id: String,
+        remaining_turns: usize,
+    },
+    /// Counts how many times in a row a Pokémon successfully protected
+    /// itself.
+    ProtectionCounter(usize),
+    /// Set by Roost. The Pokémon loses its Flying type until the end of the
+    /// turn.
+    Roost,
     StatStages(HashMap<Stat, i8>),
+    /// A substitute that takes hits in place of the Pokémon, until its own
+    /// HP run out.
+    Substitute { hp: usize },
+    /// Set when a Pokémon with Unburden loses its held item.
+    Unburden,
+    /// The moves chosen by a Pokémon since it entered the battlefield,
+    /// oldest first.
+    UsedMoves(Vec<String>),
 }
 
 pub struct MultiHitData {
@@ -265,15 +830,22 @@ pub struct MultiHitData {
 impl BattleBackend {
     pub fn new(data: Battle, rng: Box<dyn BattleRng + Sync + Send>) -> BattleBackend {
         let mut pokemon_repository = HashMap::new();
+        let active_slot_count = data.battle_type.get_active_slot_count();
         let mut p1 = TeamData {
-            active_pokemon: None,
+            active_pokemon: vec![None; active_slot_count],
             party: VecDeque::new(),
             character_id: data.p1.character_id,
+            escape_attempts: 0,
+            side_conditions: HashMap::new(),
+            baton_passes: HashMap::new(),
         };
         let mut p2 = TeamData {
-            active_pokemon: None,
+            active_pokemon: vec![None; active_slot_count],
             party: VecDeque::new(),
             character_id: data.p2.character_id,
+            escape_attempts: 0,
+            side_conditions: HashMap::new(),
+            baton_passes: HashMap::new(),
         };
         let mut pokemon_flags = HashMap::new();
 
@@ -302,6 +874,11 @@ impl BattleBackend {
             pokemon_repository,
             rng,
             pokemon_flags,
+            outcome: None,
+            caught_pokemon: None,
+            weather: None,
+            received_damage: HashMap::new(),
+            move_history: Vec::new(),
         }
     }
 
@@ -310,351 +887,1933 @@ impl BattleBackend {
     }
 
     pub fn tick(&mut self) -> impl Iterator<Item = BattleEvent> + '_ {
+        assert!(self.outcome.is_none(), "The battle is already over");
+
         if self.turn == 0 {
             self.first_tick();
+            self.next_turn();
+        } else if self.requires_replacement(Team::P1) || self.requires_replacement(Team::P2) {
+            // Replacing fainted Pokémon doesn't consume a turn
+            self.process_replacements();
         } else {
-            self.process_turn();
+            let is_turn_consumed = self.process_turn();
+
+            if is_turn_consumed && self.outcome.is_none() {
+                self.next_turn();
+            }
         }
 
-        self.next_turn();
         self.event_queue.drain(..)
     }
 
     fn first_tick(&mut self) {
-        self.p1.active_pokemon = self.p1.party.pop_front();
-        assert!(self.p1.active_pokemon.is_some());
+        for team in [Team::P2, Team::P1].iter().copied() {
+            let team_data = self.get_team_mut(team);
 
-        self.p2.active_pokemon = self.p2.party.pop_front();
-        assert!(self.p2.active_pokemon.is_some());
-
-        self.event_queue
-            .push(BattleEvent::InitialSwitchIn(event::InitialSwitchIn {
-                team: Team::P2,
-                pokemon: self.p2.active_pokemon.as_ref().unwrap().clone(),
-                is_already_sent_out: self.p2.character_id.is_none(),
-            }));
-
-        self.event_queue
-            .push(BattleEvent::InitialSwitchIn(event::InitialSwitchIn {
-                team: Team::P1,
-                pokemon: self.p1.active_pokemon.as_ref().unwrap().clone(),
-                is_already_sent_out: false,
-            }));
-
-        // TODO: trigger things like Intimidate, entry hazards, Drought, etc.
-        // The order is determined by speed.
-    }
-
-    fn process_turn(&mut self) {
-        let (p1_action, p2_action) = self.decompose_input_events();
-
-        match (p1_action, p2_action) {
-            (FrontendEventKind::UseMove(p1_index), FrontendEventKind::UseMove(p2_index)) => {
-                let movedex = get_all_moves();
-                let p1 = self.p1.active_pokemon.unwrap();
-                let p2 = self.p2.active_pokemon.unwrap();
-
-                let p1_move = {
-                    let p1_move_id = self.pokemon_repository[&p1].moves[p1_index]
-                        .as_ref()
-                        .unwrap();
-                    let p1_move = movedex.get_move(&p1_move_id).unwrap();
-
-                    UsedMove {
-                        user: p1,
-                        target: p2,
-                        movement: &p1_move,
-                    }
-                };
+            for slot in team_data.active_pokemon.iter_mut() {
+                *slot = team_data.party.pop_front();
+            }
 
-                let p2_move = {
-                    let p2_move_id = self.pokemon_repository[&p2].moves[p2_index]
-                        .as_ref()
-                        .unwrap();
-                    let p2_move = movedex.get_move(&p2_move_id).unwrap();
+            assert!(team_data.active_pokemon[0].is_some());
 
-                    UsedMove {
-                        user: p2,
-                        target: p1,
-                        movement: &p2_move,
-                    }
-                };
+            let is_already_sent_out = team == Team::P2 && team_data.character_id.is_none();
+            let active_pokemon = team_data.active_pokemon.clone();
 
-                self.process_moves(vec![p1_move, p2_move].into_iter());
-            },
+            for (slot, pokemon) in active_pokemon.into_iter().enumerate() {
+                if let Some(pokemon) = pokemon {
+                    self.event_queue
+                        .push(BattleEvent::InitialSwitchIn(event::InitialSwitchIn {
+                            team,
+                            slot,
+                            pokemon,
+                            is_already_sent_out,
+                        }));
+                }
+            }
         }
-    }
 
-    fn process_moves<'a>(&mut self, moves: impl Iterator<Item = UsedMove<'a>>) {
-        let moves = self.sort_moves(moves);
+        let mut active_pokemon: Vec<_> = self
+            .get_active_positions()
+            .into_iter()
+            .filter_map(|position| self.get_pokemon_at(position))
+            .collect();
+
+        active_pokemon
+            .sort_by_key(|pokemon| std::cmp::Reverse(self.get_stat(*pokemon, Stat::Speed)));
 
-        for used_move in moves {
-            self.process_move(used_move);
+        for pokemon in active_pokemon {
+            self.trigger_switch_in_ability(pokemon);
         }
     }
 
-    fn sort_moves<'a>(
-        &mut self,
-        moves: impl Iterator<Item = UsedMove<'a>>,
-    ) -> impl Iterator<Item = UsedMove<'a>> {
-        let mut result: Vec<_> = moves.collect();
-
-        // Ensures random move order if both the priority and speed are equal
-        self.rng.shuffle_moves(&mut result);
-
-        result.sort_by(|a, b| {
-            b.movement.priority.cmp(&a.movement.priority).then_with(|| {
-                let a_speed = self.get_stat(a.user, Stat::Speed);
-                let b_speed = self.get_stat(b.user, Stat::Speed);
-
-                b_speed.cmp(&a_speed)
-            })
-        });
-
-        result.into_iter()
-    }
+    /// Processes the actions chosen by both teams. Returns `false` if the
+    /// actions were refused, in which case the turn doesn't advance.
+    fn process_turn(&mut self) -> bool {
+        let actions = self.decompose_input_events();
 
-    fn process_move(&mut self, used_move: UsedMove) {
-        if self.is_fainted(used_move.user) {
-            return;
-        }
+        for FrontendEvent { team, event, .. } in &actions {
+            if let FrontendEventKind::Run = event {
+                if !self.can_escape(*team) {
+                    self.event_queue.push(BattleEvent::EscapeFailed(event::EscapeFailed {
+                        team: *team,
+                        cause: EscapeFailureCause::TrainerBattle,
+                    }));
 
-        if self.has_flag(used_move.user, "flinch") {
-            return;
+                    return false;
+                }
+            }
         }
 
-        if let Some(flag) = self.get_flag_mut(used_move.user, "confusion") {
-            let remaining_move_attempts = match flag {
-                Flag::Confusion { remaining_move_attempts } => remaining_move_attempts,
-                _ => unreachable!(),
+        for FrontendEvent { team, slot, event } in &actions {
+            let leaves_battlefield = match event {
+                FrontendEventKind::Run | FrontendEventKind::Switch(_) => true,
+                _ => false,
             };
 
-            if *remaining_move_attempts > 0 {
-                *remaining_move_attempts -= 1;
-            } else {
-                let flag = flag.clone();
+            let pokemon = self.get_team(*team).active_pokemon[*slot].unwrap();
 
-                self.event_queue.push(BattleEvent::ExpiredVolatileStatusCondition(
-                    event::ExpiredVolatileStatusCondition {
-                        target: used_move.user,
-                        flag,
-                    }
-                ));
+            if leaves_battlefield && self.is_trapped(pokemon) {
+                self.event_queue.push(BattleEvent::EscapeFailed(event::EscapeFailed {
+                    team: *team,
+                    cause: EscapeFailureCause::Trapped,
+                }));
 
-                self.remove_flag(used_move.user, "confusion");
+                return false;
             }
         }
 
-        let active_effects = self.active_effects
-            .get(&used_move.user)
-            .unwrap_or(&Vec::new())
-            .clone();
+        for FrontendEvent { team, event, .. } in &actions {
+            if let FrontendEventKind::ThrowPokeball(_) = event {
+                let cause = if self.get_team(team.opponent()).character_id.is_some() {
+                    Some(CaptureRefusalCause::TrainerBattle)
+                } else if !self.can_capture(*team) {
+                    Some(CaptureRefusalCause::MultipleTargets)
+                } else {
+                    None
+                };
 
-        for effect in active_effects.iter().filter_map(|effect| effect.on_before_use_move) {
-            if effect(self, used_move.user, &used_move.movement) == ModifiedUsageAttempt::Fail {
-                self.event_queue.push(BattleEvent::FailedMove(event::FailedMove {
-                    move_user: used_move.user,
-                }));
-                return;
+                if let Some(cause) = cause {
+                    self.event_queue.push(BattleEvent::CaptureRefused(event::CaptureRefused {
+                        team: *team,
+                        cause,
+                    }));
+
+                    return false;
+                }
             }
         }
 
-        self.event_queue.push(BattleEvent::UseMove(event::UseMove {
-            move_user: used_move.user,
-            move_name: used_move.movement.display_name.clone(),
-        }));
-
-        for effect in active_effects.iter().filter_map(|effect| effect.on_try_use_move) {
-            if effect(self, used_move.user, &used_move.movement) == ModifiedUsageAttempt::Fail {
-                self.event_queue.push(BattleEvent::FailedMove(event::FailedMove {
-                    move_user: used_move.user,
-                }));
-                return;
+        for FrontendEvent { team, slot, event } in &actions {
+            if let FrontendEventKind::UseMove { move_index, .. } = event {
+                let user = self.get_team(*team).active_pokemon[*slot].unwrap();
+                let is_chosen_move_used = self.get_forced_action(user).is_none()
+                    && self.get_locked_move(user).is_none()
+                    && !self.must_use_struggle(user);
+
+                if is_chosen_move_used
+                    && self.get_move_restriction(user, *move_index) == Some(MoveRestriction::NoPp)
+                {
+                    let move_id = self.get_pokemon(user).moves[*move_index].clone().unwrap();
+                    self.event_queue.push(BattleEvent::NoPpLeft(event::NoPpLeft {
+                        pokemon: user,
+                        move_id,
+                    }));
+
+                    return false;
+                }
             }
         }
 
-        if let Some(handler) = used_move.movement.on_usage_attempt {
-            let result = handler(self, used_move.user, used_move.target, &used_move.movement);
-            if result == ModifiedUsageAttempt::Fail {
-                self.event_queue.push(BattleEvent::FailedMove(event::FailedMove {
-                    move_user: used_move.user,
-                }));
-                return;
+        // Escape attempts happen before anything else
+        for FrontendEvent { team, slot, event } in &actions {
+            if let FrontendEventKind::Run = event {
+                if self.try_escape(*team, *slot) {
+                    return true;
+                }
             }
         }
 
-        if self.has_flag(used_move.user, "confusion") {
-            if self.rng.check_confusion_miss() {
-                self.event_queue.push(BattleEvent::Miss(event::Miss {
-                    target: used_move.target,
-                    move_user: used_move.user,
-                    caused_by_confusion: true,
-                }));
-                return;
+        for FrontendEvent { team, event, .. } in &actions {
+            if let FrontendEventKind::ThrowPokeball(pokeball) = event {
+                if self.throw_pokeball(*team, pokeball) {
+                    return true;
+                }
             }
         }
 
-        if self.check_miss(&used_move) {
-            self.event_queue.push(BattleEvent::Miss(event::Miss {
-                target: used_move.target,
-                move_user: used_move.user,
-                caused_by_confusion: false,
-            }));
-            return;
+        // Party indices refer to the party before any switch happens
+        let switches: Vec<_> = actions
+            .iter()
+            .filter_map(|FrontendEvent { team, slot, event }| match event {
+                FrontendEventKind::Switch(party_index) => {
+                    Some((*team, *slot, self.get_party_member(*team, *party_index)))
+                },
+                _ => None,
+            })
+            .collect();
+
+        // Switches always happen before any move is used
+        for (team, slot, incoming) in switches {
+            self.switch_pokemon(team, slot, incoming);
+        }
+
+        let movedex = get_all_moves();
+        let mut pending_moves = Vec::new();
+
+        for FrontendEvent { team, slot, event } in actions {
+            if let FrontendEventKind::UseMove { move_index, target } = event {
+                let user = self.get_team(team).active_pokemon[slot].unwrap();
+                let (move_id, target) = match self.get_forced_action(user).cloned() {
+                    Some(ForcedAction::ChargedMove { move_id, target })
+                    | Some(ForcedAction::Rampage { move_id, target, .. }) => (move_id, target),
+                    Some(ForcedAction::Recharge { move_id }) => (move_id, None),
+                    None if self.must_use_struggle(user) => ("Struggle".to_string(), None),
+                    None => {
+                        let move_id = match self.get_locked_move(user) {
+                            Some(locked_move) => locked_move,
+                            None => self.pokemon_repository[&user].moves[move_index]
+                                .as_ref()
+                                .unwrap(),
+                        };
+
+                        (move_id.clone(), target)
+                    },
+                };
+
+                pending_moves.push(PendingMove {
+                    user,
+                    target,
+                    movement: movedex.get_move(&move_id).unwrap(),
+                });
+            }
+        }
+
+        self.process_moves(pending_moves.into_iter());
+
+        true
+    }
+
+    /// Tries to flee from the battle using the speed-based escape formula,
+    /// comparing the Pokémon in the given slot against the fastest opponent.
+    /// Returns `true` if the escape succeeded.
+    fn try_escape(&mut self, team: Team, slot: usize) -> bool {
+        let user = self.get_team(team).active_pokemon[slot].unwrap();
+
+        let team_data = self.get_team_mut(team);
+        team_data.escape_attempts += 1;
+        let escape_attempts = team_data.escape_attempts;
+
+        let user_speed = self.get_stat(user, Stat::Speed);
+        let opponent_speed = self
+            .get_team(team.opponent())
+            .active_pokemon
+            .iter()
+            .flatten()
+            .map(|opponent| self.get_stat(*opponent, Stat::Speed))
+            .max()
+            .unwrap_or(0)
+            .max(1);
+
+        let escaped = self.has_ability(user, "RunAway") || user_speed >= opponent_speed || {
+            let escape_odds = (user_speed * 128) / opponent_speed + 30 * escape_attempts;
+
+            self.rng.check_escape(escape_odds)
+        };
+
+        if escaped {
+            self.event_queue.push(BattleEvent::Escaped(event::Escaped { team }));
+            self.end_battle(team.opponent(), BattleEndReason::Escaped);
+        } else {
+            self.event_queue.push(BattleEvent::EscapeFailed(event::EscapeFailed {
+                team,
+                cause: EscapeFailureCause::BadOdds,
+            }));
+        }
+
+        escaped
+    }
+
+    fn process_replacements(&mut self) {
+        let input_events: Vec<_> = self.input_events.drain(..).collect();
+
+        // Party indices refer to the party before any replacement happens
+        let replacements: Vec<_> = input_events
+            .into_iter()
+            .map(|FrontendEvent { team, slot, event }| match event {
+                FrontendEventKind::Switch(party_index)
+                    if self.get_team(team).active_pokemon.get(slot) == Some(&None) =>
+                {
+                    assert!(
+                        self.can_switch_in(team, party_index),
+                        "Invalid input event: party index {} cannot be sent in.",
+                        party_index,
+                    );
+
+                    (team, slot, self.get_party_member(team, party_index))
+                },
+                _ => panic!("Invalid input event: {:?} must replace its fainted Pokémon.", team),
+            })
+            .collect();
+
+        let mut incoming_pokemon = Vec::new();
+
+        for (team, slot, incoming) in replacements {
+            assert!(
+                self.requires_replacement(team),
+                "Invalid input event: {:?} has no fainted Pokémon left to replace.",
+                team,
+            );
+
+            self.send_in(team, slot, incoming);
+            incoming_pokemon.push(incoming);
+
+            if let Some(baton_pass) = self.get_team_mut(team).baton_passes.remove(&slot) {
+                self.pokemon_flags.insert(incoming, baton_pass.flags);
+            }
+        }
+
+        if self.requires_replacement(Team::P1) || self.requires_replacement(Team::P2) {
+            panic!("Invalid input events: every fainted Pokémon must be replaced.");
+        }
+
+        for incoming in incoming_pokemon {
+            self.process_switch_in(incoming);
+        }
+
+        // Entry hazards might have made the last Pokémon of a team faint
+        if let Some(winner) = self.get_winner() {
+            self.end_battle(winner, BattleEndReason::AllPokemonFainted);
+        }
+    }
+
+    fn switch_pokemon(&mut self, team: Team, slot: usize, incoming: usize) {
+        let outgoing = self.withdraw(team, slot);
+
+        let party_index = self.send_in(team, slot, incoming);
+        self.get_team_mut(team).party.insert(party_index, outgoing);
+
+        self.process_switch_in(incoming);
+    }
+
+    /// Removes the Pokémon in an active slot from the battlefield, leaving
+    /// the slot empty. Returns the withdrawn Pokémon, which the caller is
+    /// responsible for putting back into the party.
+    fn withdraw(&mut self, team: Team, slot: usize) -> usize {
+        let outgoing = self.get_team_mut(team).active_pokemon[slot].take().unwrap();
+
+        if self.has_ability(outgoing, "Regenerator") {
+            let max_hp = self.get_stat(outgoing, Stat::HP);
+            let pokemon = self.get_pokemon_mut(outgoing);
+
+            pokemon.current_hp = (pokemon.current_hp + max_hp / 3).min(max_hp);
+        }
+
+        // Volatile status conditions and stat stages don't persist after
+        // switching out
+        self.pokemon_flags.insert(outgoing, FlagContainer::default());
+        self.release_trapped_pokemon(outgoing);
+
+        if let Some(StatusCondition::Toxic { counter }) =
+            self.get_non_volatile_status_condition_mut(outgoing)
+        {
+            *counter = 1;
+        }
+
+        self.event_queue.push(BattleEvent::SwitchOut(event::SwitchOut {
+            team,
+            slot,
+            pokemon: outgoing,
+        }));
+
+        outgoing
+    }
+
+    /// Withdraws a Pokémon that used Baton Pass. Its stat stages and some of
+    /// its volatile status conditions are kept for the Pokémon that replaces
+    /// it at the start of the next tick.
+    fn baton_pass(&mut self, user: usize) {
+        let FieldPosition { team, slot } = self.get_pokemon_position(user).unwrap();
+        let flags = self.pokemon_flags[&user]
+            .flags
+            .iter()
+            .filter(|(flag_id, _)| is_passed_by_baton_pass(flag_id))
+            .map(|(flag_id, flag)| (*flag_id, flag.clone()))
+            .collect();
+
+        self.withdraw(team, slot);
+
+        let team_data = self.get_team_mut(team);
+        team_data.party.push_back(user);
+        team_data.baton_passes.insert(slot, BatonPass {
+            user,
+            flags: FlagContainer { flags },
+        });
+    }
+
+    /// Moves a Pokémon from the party of a team to one of its empty active
+    /// slots. Returns the party index that the Pokémon used to occupy.
+    fn send_in(&mut self, team: Team, slot: usize, incoming: usize) -> usize {
+        assert!(!self.is_fainted(incoming), "Cannot switch in a fainted Pokémon");
+
+        let team_data = self.get_team_mut(team);
+        let party_index = team_data
+            .party
+            .iter()
+            .position(|pokemon| *pokemon == incoming)
+            .expect("Cannot switch in a Pokémon that is not in the party");
+
+        team_data.party.remove(party_index);
+        team_data.active_pokemon[slot] = Some(incoming);
+
+        self.event_queue.push(BattleEvent::SwitchIn(event::SwitchIn {
+            team,
+            slot,
+            pokemon: incoming,
+        }));
+
+        party_index
+    }
+
+    /// Applies the effects that happen when a Pokémon enters the
+    /// battlefield, i.e entry hazards and abilities like Intimidate.
+    fn process_switch_in(&mut self, pokemon: usize) {
+        let team = self.get_pokemon_team(pokemon);
+
+        if self.has_side_condition(team, SideCondition::StealthRock) {
+            let max_hp = self.get_stat(pokemon, Stat::HP) as f32;
+            let effectiveness: f32 = self
+                .get_pokemon_current_types(pokemon)
+                .map(|t| PokemonType::get_effectiveness(PokemonType::Rock, *t))
+                .product();
+            let damage = (max_hp * effectiveness / 8.) as usize;
+
+            self.inflict_calculated_damage(
+                pokemon,
+                damage.max(1),
+                TypeEffectiveness::Normal,
+                false,
+                None,
+                false,
+                DamageCause::StealthRock,
+            );
+        }
+
+        if !self.is_fainted(pokemon) {
+            self.trigger_switch_in_ability(pokemon);
+        }
+    }
+
+    fn trigger_switch_in_ability(&mut self, pokemon: usize) {
+        if let Some(on_switch_in) = self.get_active_ability_effect(pokemon).on_switch_in {
+            on_switch_in(self, pokemon);
+        }
+    }
+
+    /// Returns the Pokémon at the given index of the party of a team.
+    pub fn get_party_member(&self, team: Team, party_index: usize) -> usize {
+        *self
+            .get_team(team)
+            .party
+            .get(party_index)
+            .unwrap_or_else(|| panic!("Invalid party index: {}", party_index))
+    }
+
+    fn process_moves<'a>(&mut self, moves: impl Iterator<Item = PendingMove<'a>>) {
+        let moves = self.sort_moves(moves);
+
+        for pending_move in moves {
+            self.process_move(pending_move);
+        }
+    }
+
+    fn sort_moves<'a>(
+        &mut self,
+        moves: impl Iterator<Item = PendingMove<'a>>,
+    ) -> impl Iterator<Item = PendingMove<'a>> {
+        let mut result: Vec<_> = moves.collect();
+
+        // Ensures random move order if both the priority and speed are equal
+        self.rng.shuffle_moves(&mut result);
+
+        result.sort_by(|a, b| {
+            b.movement.priority.cmp(&a.movement.priority).then_with(|| {
+                let a_speed = self.get_stat(a.user, Stat::Speed);
+                let b_speed = self.get_stat(b.user, Stat::Speed);
+
+                b_speed.cmp(&a_speed)
+            })
+        });
+
+        result.into_iter()
+    }
+
+    fn process_move(&mut self, pending_move: PendingMove) {
+        let PendingMove { user, movement, .. } = pending_move;
+
+        if self.is_fainted(user) {
+            return;
+        }
+
+        if let Some(ForcedAction::Recharge { .. }) = self.get_forced_action(user) {
+            self.remove_flag(user, "forced_action");
+            self.event_queue.push(BattleEvent::Recharging(event::Recharging {
+                pokemon: user,
+            }));
+            return;
+        }
+
+        // Moves that the Pokémon is forced to continue don't cost PP again
+        let is_forced_action = self.has_forced_action(user);
+
+        if self.has_flag(user, "flinch") {
+            if self.has_ability(user, "Steadfast") {
+                self.activate_ability(user);
+                self.change_stat_stage(user, Stat::Speed, 1);
+            }
+
+            return;
+        }
+
+        if let Some(flag) = self.get_flag_mut(user, "confusion") {
+            let remaining_move_attempts = match flag {
+                Flag::Confusion { remaining_move_attempts } => remaining_move_attempts,
+                _ => unreachable!(),
+            };
+
+            if *remaining_move_attempts > 0 {
+                *remaining_move_attempts -= 1;
+            } else {
+                self.remove_volatile_status_condition(user, "confusion");
+            }
+        }
+
+        // Hitting itself interrupts moves that are being charged or continued,
+        // and the chosen move counts as not used at all
+        if self.has_flag(user, "confusion") && self.rng.check_confusion_self_hit() {
+            self.remove_flag(user, "forced_action");
+            self.hurt_by_confusion(user);
+            return;
+        }
+
+        let active_effects = self.active_effects
+            .get(&user)
+            .unwrap_or(&Vec::new())
+            .clone();
+
+        for effect in active_effects.iter().filter_map(|effect| effect.on_before_use_move) {
+            if effect(self, user, movement) == ModifiedUsageAttempt::Fail {
+                self.event_queue.push(BattleEvent::FailedMove(event::FailedMove {
+                    move_user: user,
+                }));
+                return;
+            }
+        }
+
+        if self.is_move_disabled(user, &movement.id) {
+            self.event_queue.push(BattleEvent::DisabledMove(event::DisabledMove {
+                pokemon: user,
+                move_id: movement.id.clone(),
+            }));
+            return;
+        }
+
+        if !is_forced_action {
+            self.spend_pp(user, movement);
+        }
+
+        if let Some(charge) = &movement.charge {
+            let is_charged = match self.get_forced_action(user) {
+                Some(ForcedAction::ChargedMove { .. }) => true,
+                _ => false,
+            };
+
+            if is_charged {
+                self.remove_flag(user, "forced_action");
+            } else {
+                self.event_queue.push(BattleEvent::Charging(event::Charging {
+                    pokemon: user,
+                    move_id: movement.id.clone(),
+                }));
+
+                let keeps_charging = match charge.on_charge {
+                    Some(on_charge) => on_charge(self, user),
+                    None => true,
+                };
+
+                if keeps_charging {
+                    self.add_flag(user, Flag::ForcedAction(ForcedAction::ChargedMove {
+                        move_id: movement.id.clone(),
+                        target: pending_move.target,
+                    }));
+                    return;
+                }
+            }
+        }
+
+        self.event_queue.push(BattleEvent::UseMove(event::UseMove {
+            move_user: user,
+            move_name: movement.display_name.clone(),
+        }));
+        self.record_move_usage(user, movement);
+        self.remove_flag(user, "destiny_bond");
+
+        if self.get_held_item_effect(user).locks_move && !self.has_flag(user, "choice_lock") {
+            self.add_flag(user, Flag::ChoiceLock(movement.id.clone()));
+        }
+
+        let is_protection_move = match movement.primary_effect {
+            Some(SimpleEffect::Protection(_)) => true,
+            _ => false,
+        };
+
+        if !is_protection_move {
+            self.remove_flag(user, "protection_counter");
+        }
+
+        for effect in active_effects.iter().filter_map(|effect| effect.on_try_use_move) {
+            if effect(self, user, movement) == ModifiedUsageAttempt::Fail {
+                self.event_queue.push(BattleEvent::FailedMove(event::FailedMove {
+                    move_user: user,
+                }));
+                return;
+            }
+        }
+
+        let targets = self.get_move_targets(user, movement, pending_move.target);
+
+        if targets.is_empty() {
+            self.event_queue.push(BattleEvent::FailedMove(event::FailedMove {
+                move_user: user,
+            }));
+            return;
+        }
+
+        self.use_move(user, movement, targets, pending_move.target);
+    }
+
+    /// Adds a move chosen by a Pokémon to its own history and to the battle
+    /// history. Moves that call other moves are left out of the battle
+    /// history, since the called move gets recorded instead.
+    fn record_move_usage(&mut self, user: usize, movement: &Move) {
+        let mut used_moves = self.get_used_moves(user).to_vec();
+        used_moves.push(movement.id.clone());
+        self.add_flag(user, Flag::UsedMoves(used_moves));
+
+        if !is_calling_move(movement) {
+            self.move_history.push(MoveHistoryEntry {
+                user,
+                move_id: movement.id.clone(),
+            });
+        }
+    }
+
+    /// Uses a move in place of a move like Metronome. The called move picks
+    /// its own targets, and skips the checks that were already made for the
+    /// original move.
+    fn call_move(&mut self, user: usize, movement: &Move) {
+        self.event_queue.push(BattleEvent::UseMove(event::UseMove {
+            move_user: user,
+            move_name: movement.display_name.clone(),
+        }));
+        self.move_history.push(MoveHistoryEntry {
+            user,
+            move_id: movement.id.clone(),
+        });
+
+        let targets = self.get_move_targets(user, movement, None);
+
+        if targets.is_empty() {
+            self.event_queue.push(BattleEvent::FailedMove(event::FailedMove {
+                move_user: user,
+            }));
+            return;
+        }
+
+        self.use_move(user, movement, targets, None);
+    }
+
+    /// Executes a move against each of its targets, after every check that
+    /// could prevent the user from moving has passed.
+    fn use_move(
+        &mut self,
+        user: usize,
+        movement: &Move,
+        targets: Vec<usize>,
+        chosen_target: Option<FieldPosition>,
+    ) {
+        let has_multiple_targets = targets.len() > 1;
+
+        for target in targets {
+            let used_move = UsedMove {
+                user,
+                target,
+                movement,
+                has_multiple_targets,
+            };
+
+            self.process_move_against_target(&used_move);
+        }
+
+        self.lock_into_next_action(user, movement, chosen_target);
+    }
+
+    /// Forces a Pokémon to recharge after using moves like Hyper Beam, or to
+    /// keep using moves like Thrash.
+    fn lock_into_next_action(
+        &mut self,
+        user: usize,
+        movement: &Move,
+        target: Option<FieldPosition>,
+    ) {
+        if self.is_fainted(user) {
+            return;
+        }
+
+        if movement.flags.contains(&MoveFlag::Recharge) {
+            self.add_flag(user, Flag::ForcedAction(ForcedAction::Recharge {
+                move_id: movement.id.clone(),
+            }));
+        } else if movement.flags.contains(&MoveFlag::Rampage) {
+            let remaining_turns = match self.get_forced_action(user) {
+                Some(ForcedAction::Rampage { remaining_turns, .. }) => *remaining_turns,
+                _ => self.rng.get_rampage_duration(),
+            } - 1;
+
+            if remaining_turns > 0 {
+                self.add_flag(user, Flag::ForcedAction(ForcedAction::Rampage {
+                    move_id: movement.id.clone(),
+                    target,
+                    remaining_turns,
+                }));
+            } else {
+                self.remove_flag(user, "forced_action");
+
+                if !self.has_flag(user, "confusion") {
+                    let duration = self.rng.get_confusion_duration();
+
+                    self.add_volatile_status_condition(user, Flag::Confusion {
+                        remaining_move_attempts: duration,
+                    });
+                }
+            }
+        }
+    }
+
+    fn process_move_against_target(&mut self, used_move: &UsedMove) {
+        if self.is_fainted(used_move.target) {
+            return;
+        }
+
+        if let Some(protection) = self.get_blocking_protection(&used_move) {
+            self.event_queue.push(BattleEvent::Protected(event::Protected {
+                target: used_move.target,
+                protection,
+            }));
+            return;
+        }
+
+        if let Some(handler) = used_move.movement.on_usage_attempt {
+            let result = handler(self, used_move.user, used_move.target, &used_move.movement);
+            if result == ModifiedUsageAttempt::Fail {
+                self.event_queue.push(BattleEvent::FailedMove(event::FailedMove {
+                    move_user: used_move.user,
+                }));
+                return;
+            }
+        }
+
+        // Decided before the move hits, since the hit that breaks a
+        // substitute doesn't affect the Pokémon behind it either
+        let hits_substitute = self.is_behind_substitute(used_move);
+
+        let is_blocked_by_substitute = hits_substitute
+            && used_move.movement.category == MoveCategory::Status
+            && used_move.movement.primary_effect.as_ref().map_or(false, is_blocked_by_substitute);
+
+        if !self.can_apply_primary_effect(&used_move) || is_blocked_by_substitute {
+            self.event_queue.push(BattleEvent::FailedMove(event::FailedMove {
+                move_user: used_move.user,
+            }));
+            return;
+        }
+
+        if used_move.user != used_move.target {
+            let on_check_move_immunity =
+                self.get_active_ability_effect(used_move.target).on_check_move_immunity;
+
+            if let Some(is_immune) = on_check_move_immunity {
+                if is_immune(self, used_move.target, used_move.user, used_move.movement) {
+                    return;
+                }
+            }
+        }
+
+        if self.check_miss(&used_move) {
+            self.event_queue.push(BattleEvent::Miss(event::Miss {
+                target: used_move.target,
+                move_user: used_move.user,
+            }));
+            return;
+        }
+
+        match used_move.movement.category {
+            MoveCategory::Physical | MoveCategory::Special => {
+                if let Some(multi_hit) = &used_move.movement.multi_hit {
+                    let number_of_hits = match multi_hit {
+                        MultiHit::Uniform { min_hits, max_hits } => {
+                            self.rng.check_uniform_multi_hit(*min_hits, *max_hits)
+                        },
+                        MultiHit::Custom(callback) => {
+                            callback(&mut *self.rng)
+                        },
+                    };
+
+                    for i in 0..number_of_hits {
+                        self.process_damage_effect(&used_move, Some(MultiHitData {
+                            multi_hit_index: i,
+                            maximum_number_of_hits: number_of_hits,
+                        }));
+                    }
+                } else {
+                    self.process_damage_effect(&used_move, None);
+                }
+
+                if !hits_substitute {
+                    self.check_stench_flinch(&used_move);
+                }
+
+                if !self.is_fainted(used_move.target) {
+                    self.process_primary_effect(&used_move, hits_substitute);
+                }
+            },
+            MoveCategory::Status => {
+                self.process_primary_effect(&used_move, hits_substitute);
+            },
+        }
+
+        self.process_secondary_effect(&used_move, hits_substitute);
+    }
+
+    /// Returns the protection that prevents a move from affecting its target,
+    /// if any. Moves that affect the whole field can't be blocked.
+    fn get_blocking_protection(&self, used_move: &UsedMove) -> Option<Protection> {
+        let UsedMove { user, target, movement, .. } = *used_move;
+
+        let is_field_move = match movement.target_type {
+            TargetType::Everyone => true,
+            _ => false,
+        };
+
+        if user == target || is_field_move {
+            return None;
+        }
+
+        let is_spread_move = match movement.target_type {
+            TargetType::AllAdjacent | TargetType::AllAdjacentFoes => true,
+            _ => false,
+        };
+
+        if self.has_flag(target, "protection") {
+            Some(Protection::Full)
+        } else if is_spread_move && self.has_flag(target, "wide_guard") {
+            Some(Protection::WideGuard)
+        } else if movement.priority > 0 && self.has_flag(target, "quick_guard") {
+            Some(Protection::QuickGuard)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the Pokémon affected by a move, according to its target type.
+    /// Single-target moves use the chosen target if it's valid, falling back
+    /// to the first valid one otherwise.
+    fn get_move_targets(
+        &self,
+        user: usize,
+        movement: &Move,
+        chosen_target: Option<FieldPosition>,
+    ) -> Vec<usize> {
+        let user_position = self.get_pokemon_position(user).unwrap();
+        let active_positions = self.get_active_positions();
+        let is_foe = |position: &FieldPosition| position.team != user_position.team;
+        let is_adjacent = |position: &FieldPosition| self.are_adjacent(user_position, *position);
+
+        let targets: Vec<_> = match movement.target_type {
+            TargetType::Everyone => active_positions,
+            TargetType::AllAdjacent => active_positions.into_iter().filter(is_adjacent).collect(),
+            TargetType::AllAdjacentFoes => active_positions
+                .into_iter()
+                .filter(|position| is_foe(position) && is_adjacent(position))
+                .collect(),
+            TargetType::AllyTeam => active_positions
+                .into_iter()
+                .filter(|position| !is_foe(position))
+                .collect(),
+            TargetType::OpposingTeam => active_positions.into_iter().filter(is_foe).collect(),
+            TargetType::User => vec![user_position],
+            TargetType::SingleTarget
+            | TargetType::SingleAdjacentTarget
+            | TargetType::SingleAdjacentAlly
+            | TargetType::SingleAdjacentAllyOrUser
+            | TargetType::SingleAdjacentFoe => {
+                let valid_targets: Vec<_> = active_positions
+                    .into_iter()
+                    .filter(|position| {
+                        let is_adjacent_ally = !is_foe(position) && is_adjacent(position);
+                        let is_adjacent_foe = is_foe(position) && is_adjacent(position);
+
+                        match movement.target_type {
+                            TargetType::SingleTarget => *position != user_position,
+                            TargetType::SingleAdjacentTarget => is_adjacent(position),
+                            TargetType::SingleAdjacentAlly => is_adjacent_ally,
+                            TargetType::SingleAdjacentAllyOrUser => {
+                                *position == user_position || is_adjacent_ally
+                            },
+                            TargetType::SingleAdjacentFoe => is_adjacent_foe,
+                            _ => unreachable!(),
+                        }
+                    })
+                    .collect();
+
+                match chosen_target {
+                    Some(target) if valid_targets.contains(&target) => vec![target],
+                    _ => {
+                        // Moves that can target foes prefer them over allies
+                        let default_target = valid_targets
+                            .iter()
+                            .find(|position| is_foe(position))
+                            .or_else(|| valid_targets.first());
+
+                        default_target.into_iter().copied().collect()
+                    },
+                }
+            },
+        };
+
+        targets
+            .into_iter()
+            .filter_map(|position| self.get_pokemon_at(position))
+            .collect()
+    }
+
+    fn process_damage_effect(
+        &mut self,
+        used_move: &UsedMove,
+        multi_hit_data: Option<MultiHitData>,
+    ) {
+        let is_critical_hit = used_move.movement.critical_hit
+            && !self.has_ability(used_move.target, "BattleArmor")
+            && !self.has_ability(used_move.target, "ShellArmor");
+
+        let (attack, defense) = match (is_critical_hit, used_move.movement.category) {
+            (false, MoveCategory::Physical) => (
+                self.get_stat(used_move.user, Stat::Attack),
+                self.get_stat(used_move.target, Stat::Defense),
+            ),
+            (true, MoveCategory::Physical) => (
+                self.get_attack_critical_hit(used_move.user),
+                self.get_defense_critical_hit(used_move.target),
+            ),
+            (false, MoveCategory::Special) => (
+                self.get_stat(used_move.user, Stat::SpecialAttack),
+                self.get_stat(used_move.target, Stat::SpecialDefense),
+            ),
+            (true, MoveCategory::Special) => (
+                self.get_special_attack_critical_hit(used_move.user),
+                self.get_special_defense_critical_hit(used_move.target),
+            ),
+            _ => unreachable!(),
+        };
+
+        let hits_substitute = self.is_behind_substitute(used_move);
+
+        self.inflict_damage(&used_move, attack, defense, is_critical_hit, multi_hit_data);
+
+        if !hits_substitute {
+            self.process_after_hit_abilities(&used_move);
+        }
+    }
+
+    /// Triggers the abilities that react to a Pokémon being hit by a move,
+    /// e.g Static or Moxie.
+    fn process_after_hit_abilities(&mut self, used_move: &UsedMove) {
+        let UsedMove { user, target, movement, .. } = *used_move;
+
+        if self.is_fainted(target) {
+            if let Some(on_knock_out) = self.get_active_ability_effect(user).on_knock_out {
+                on_knock_out(self, user, target);
+            }
+
+            return;
+        }
+
+        if let Some(on_after_hit) = self.get_active_ability_effect(target).on_after_hit {
+            on_after_hit(self, target, user, movement);
+        }
+
+        if movement.flags.contains(&MoveFlag::Contact) && !self.is_fainted(user) {
+            if let Some(on_contact) = self.get_active_ability_effect(target).on_contact {
+                on_contact(self, target, user);
+            }
+        }
+    }
+
+    /// Stench gives a 10% flinch chance to damaging moves that don't already
+    /// cause flinching.
+    fn check_stench_flinch(&mut self, used_move: &UsedMove) {
+        let causes_flinch = match &used_move.movement.secondary_effect {
+            Some(effect) => match effect.effect {
+                SimpleEffect::Flinch => true,
+                _ => false,
+            },
+            None => false,
+        };
+
+        if causes_flinch
+            || self.is_fainted(used_move.target)
+            || !self.has_ability(used_move.user, "Stench")
+        {
+            return;
+        }
+
+        if self.rng.check_ability_activation(10) {
+            self.add_volatile_status_condition(used_move.target, Flag::Flinch);
+        }
+    }
+
+    fn process_secondary_effect(&mut self, used_move: &UsedMove, hits_substitute: bool) {
+        if let Some(effect) = used_move.movement.secondary_effect.as_ref() {
+            if hits_substitute && is_blocked_by_substitute(&effect.effect) {
+                return;
+            }
+
+            let is_damaging_move = used_move.movement.category != MoveCategory::Status;
+
+            if is_damaging_move && self.has_ability(used_move.user, "SheerForce") {
+                return;
+            }
+
+            let affects_target = match &effect.effect {
+                SimpleEffect::StatChange { target, .. } => match target {
+                    SimpleEffectTarget::MoveTarget => true,
+                    SimpleEffectTarget::MoveUser => false,
+                },
+                _ => true,
+            };
+
+            let is_shielded = affects_target
+                && is_damaging_move
+                && used_move.user != used_move.target
+                && self.has_ability(used_move.target, "ShieldDust");
+
+            if is_shielded {
+                return;
+            }
+
+            if !self.rng.check_secondary_effect(effect.chance) {
+                return;
+            }
+
+            self.apply_simple_effect(used_move, &effect.effect);
         }
+    }
 
-        match used_move.movement.category {
-            MoveCategory::Physical | MoveCategory::Special => {
-                if let Some(multi_hit) = &used_move.movement.multi_hit {
-                    let number_of_hits = match multi_hit {
-                        MultiHit::Uniform { min_hits, max_hits } => {
-                            self.rng.check_uniform_multi_hit(*min_hits, *max_hits)
-                        },
-                        MultiHit::Custom(callback) => {
-                            callback(self.rng.boxed_clone())
-                        },
+    /// Applies the primary effect of a move. For Status moves, this happens
+    /// after can_apply_primary_effect() confirmed that it would do something,
+    /// so there's no chance involved.
+    fn process_primary_effect(&mut self, used_move: &UsedMove, hits_substitute: bool) {
+        if let Some(effect) = used_move.movement.primary_effect.as_ref() {
+            if hits_substitute && is_blocked_by_substitute(effect) {
+                return;
+            }
+
+            self.apply_simple_effect(used_move, effect);
+        }
+    }
+
+    /// Checks if the primary effect of a move would do anything. Moves with
+    /// no primary effect never fail this check.
+    fn can_apply_primary_effect(&self, used_move: &UsedMove) -> bool {
+        let UsedMove { user, target, movement, .. } = *used_move;
+
+        let effect = match movement.primary_effect.as_ref() {
+            Some(effect) => effect,
+            None => return true,
+        };
+
+        match effect {
+            SimpleEffect::Confusion => {
+                !self.has_flag(target, "confusion")
+                    && !self.is_protected_by_safeguard(target, user)
+            },
+            SimpleEffect::StatChange { changes, target: SimpleEffectTarget::MoveTarget } => {
+                let lowers_stats = changes.iter().any(|(_, delta)| *delta < 0);
+                let is_protected_by_mist = target != user
+                    && self.has_side_condition(self.get_pokemon_team(target), SideCondition::Mist);
+
+                !(lowers_stats && is_protected_by_mist)
+            },
+            SimpleEffect::StatusCondition(condition) => {
+                self.can_inflict_non_volatile_status_condition_to(target, (*condition).into())
+                    && !self.is_protected_by_safeguard(target, user)
+            },
+            SimpleEffect::Weather(weather) => {
+                self.weather.as_ref().map(|state| state.weather) != Some(*weather)
+            },
+            SimpleEffect::SideCondition(condition) => {
+                let team = self.get_side_condition_team(user, *condition);
+
+                !self.has_side_condition(team, *condition)
+            },
+            SimpleEffect::Heal(_) | SimpleEffect::WeatherDependentHeal | SimpleEffect::Roost => {
+                self.get_pokemon(target).current_hp < self.get_stat(target, Stat::HP)
+            },
+            SimpleEffect::CallMove(CalledMove::Random) => true,
+            SimpleEffect::CallMove(called_move) => {
+                self.get_copied_move(target, *called_move).is_some()
+            },
+            SimpleEffect::Disable => {
+                !self.has_flag(target, "disabled") && self.get_last_move_index(target).is_some()
+            },
+            SimpleEffect::Encore => {
+                // Encore can't force a Pokémon to repeat moves that call other
+                // moves, nor Encore itself
+                let can_be_encored = self.get_last_used_move(target).map_or(false, |move_id| {
+                    let last_move = get_all_moves().get_move(move_id).unwrap();
+                    let is_encore = match last_move.primary_effect {
+                        Some(SimpleEffect::Encore) => true,
+                        _ => false,
                     };
 
-                    for i in 0..number_of_hits {
-                        self.process_damage_effect(&used_move, Some(MultiHitData {
-                            multi_hit_index: i,
-                            maximum_number_of_hits: number_of_hits,
-                        }));
+                    !is_encore && !is_calling_move(last_move)
+                });
+
+                !self.has_flag(target, "encore")
+                    && can_be_encored
+                    && self.get_last_move_index(target).is_some()
+            },
+            SimpleEffect::Spite => match self.get_last_move_index(target) {
+                Some(index) => self.get_pokemon(target).pp[index] > 0,
+                None => false,
+            },
+            SimpleEffect::LeechSeed => {
+                !self.has_type(target, PokemonType::Grass) && !self.has_flag(target, "leech_seed")
+            },
+            SimpleEffect::Curse => {
+                if self.has_type(user, PokemonType::Ghost) {
+                    self.get_adjacent_foes(user)
+                        .first()
+                        .map_or(false, |foe| !self.has_flag(*foe, "curse"))
+                } else {
+                    true
+                }
+            },
+            SimpleEffect::PerishSong => !self.has_flag(target, "perish_count"),
+            SimpleEffect::Yawn => {
+                !self.has_flag(target, "drowsy")
+                    && self.can_inflict_non_volatile_status_condition_to(
+                        target,
+                        SimpleStatusCondition::Sleep,
+                    )
+                    && !self.is_protected_by_safeguard(target, user)
+            },
+            SimpleEffect::BatonPass => {
+                let team = self.get_pokemon_team(user);
+
+                self.get_team(team)
+                    .party
+                    .iter()
+                    .any(|pokemon| !self.is_fainted(*pokemon))
+            },
+            SimpleEffect::Substitute => {
+                // The user must be able to pay for the substitute and still
+                // have some HP left
+                !self.has_flag(user, "substitute")
+                    && self.get_pokemon(user).current_hp > self.get_stat(user, Stat::HP) / 4
+            },
+            _ => true,
+        }
+    }
+
+    fn apply_simple_effect(&mut self, used_move: &UsedMove, effect: &SimpleEffect) {
+        match effect {
+            SimpleEffect::Confusion => {
+                if self.is_protected_by_safeguard(used_move.target, used_move.user) {
+                    return;
+                }
+
+                let duration = self.rng.get_confusion_duration();
+
+                self.add_volatile_status_condition(used_move.target, Flag::Confusion {
+                    remaining_move_attempts: duration,
+                });
+            },
+            SimpleEffect::Flinch => {
+                self.add_volatile_status_condition(used_move.target, Flag::Flinch);
+            },
+            SimpleEffect::StatChange { changes, target } => {
+                let target = match target {
+                    SimpleEffectTarget::MoveTarget => used_move.target,
+                    SimpleEffectTarget::MoveUser => used_move.user,
+                };
+
+                for (stat, delta) in changes {
+                    if target != used_move.user && *delta < 0 {
+                        self.lower_stat_stage_from_foe(target, *stat, *delta);
+                    } else {
+                        self.change_stat_stage(target, *stat, *delta);
                     }
+                }
+            },
+            SimpleEffect::StatusCondition(status_condition) => {
+                if self.is_protected_by_safeguard(used_move.target, used_move.user) {
+                    return;
+                }
+
+                self.add_non_volatile_status_condition(used_move.target, *status_condition);
+                self.check_synchronize(used_move, *status_condition);
+            },
+            SimpleEffect::Weather(weather) => {
+                self.start_weather(*weather);
+            },
+            SimpleEffect::SideCondition(condition) => {
+                let team = self.get_side_condition_team(used_move.user, *condition);
+
+                self.start_side_condition(team, *condition);
+            },
+            SimpleEffect::Heal(percentage) => {
+                let max_hp = self.get_stat(used_move.target, Stat::HP);
+
+                self.heal(used_move.target, max_hp * percentage / 100);
+            },
+            SimpleEffect::WeatherDependentHeal => {
+                let max_hp = self.get_stat(used_move.target, Stat::HP);
+                let amount = match self.get_weather() {
+                    None => max_hp / 2,
+                    Some(Weather::Sun) => max_hp * 2 / 3,
+                    Some(_) => max_hp / 4,
+                };
+
+                self.heal(used_move.target, amount);
+            },
+            SimpleEffect::Roost => {
+                let max_hp = self.get_stat(used_move.user, Stat::HP);
+
+                self.heal(used_move.user, max_hp / 2);
+                self.add_flag(used_move.user, Flag::Roost);
+            },
+            SimpleEffect::Protection(protection) => {
+                self.start_protection(used_move.user, *protection);
+            },
+            SimpleEffect::Trap => {
+                if self.has_flag(used_move.target, "trapped") {
+                    return;
+                }
+
+                let duration = self.rng.get_trap_duration();
+
+                self.add_volatile_status_condition(used_move.target, Flag::Trapped {
+                    trapper: used_move.user,
+                    move_id: used_move.movement.id.clone(),
+                    remaining_turns: duration,
+                });
+            },
+            SimpleEffect::CallMove(called_move) => {
+                let movement = match called_move {
+                    CalledMove::Random => self.pick_metronome_move(),
+                    _ => self.get_copied_move(used_move.target, *called_move).unwrap(),
+                };
+
+                self.call_move(used_move.user, movement);
+            },
+            SimpleEffect::Disable => {
+                let move_id = self.get_last_used_move(used_move.target).unwrap().clone();
+
+                self.add_volatile_status_condition(used_move.target, Flag::Disabled {
+                    move_id,
+                    remaining_turns: 4,
+                });
+            },
+            SimpleEffect::Encore => {
+                let move_id = self.get_last_used_move(used_move.target).unwrap().clone();
+
+                self.add_volatile_status_condition(used_move.target, Flag::Encore {
+                    move_id,
+                    remaining_turns: 3,
+                });
+            },
+            SimpleEffect::Spite => {
+                let index = self.get_last_move_index(used_move.target).unwrap();
+                let target_pokemon = self.get_pokemon_mut(used_move.target);
+                let amount = target_pokemon.pp[index].min(4);
+                target_pokemon.pp[index] -= amount;
+                let move_id = target_pokemon.moves[index].clone().unwrap();
+
+                self.event_queue.push(BattleEvent::PpReduction(event::PpReduction {
+                    target: used_move.target,
+                    move_id,
+                    amount,
+                }));
+            },
+            SimpleEffect::LeechSeed => {
+                let seeder_position = self.get_pokemon_position(used_move.user).unwrap();
+
+                self.add_volatile_status_condition(used_move.target, Flag::LeechSeed {
+                    seeder_position,
+                });
+            },
+            SimpleEffect::Curse => {
+                let user = used_move.user;
+
+                if self.has_type(user, PokemonType::Ghost) {
+                    let foe = self.get_adjacent_foes(user)[0];
+                    let cost = self.get_stat(user, Stat::HP) / 2;
+
+                    self.add_volatile_status_condition(foe, Flag::Curse);
+                    // The user pays for the curse as part of its own move
+                    self.inflict_calculated_damage(
+                        user,
+                        cost.max(1),
+                        TypeEffectiveness::Normal,
+                        false,
+                        None,
+                        false,
+                        DamageCause::Move,
+                    );
                 } else {
-                    self.process_damage_effect(&used_move, None);
+                    self.change_stat_stage(user, Stat::Speed, -1);
+                    self.change_stat_stage(user, Stat::Attack, 1);
+                    self.change_stat_stage(user, Stat::Defense, 1);
                 }
             },
-            MoveCategory::Status => {
-                // TODO
+            SimpleEffect::PerishSong => {
+                self.add_volatile_status_condition(used_move.target, Flag::PerishCount(3));
+            },
+            SimpleEffect::Yawn => {
+                self.add_volatile_status_condition(used_move.target, Flag::Drowsy {
+                    remaining_turns: 2,
+                });
+            },
+            SimpleEffect::DestinyBond => {
+                self.add_volatile_status_condition(used_move.user, Flag::DestinyBond);
+            },
+            SimpleEffect::Substitute => {
+                let hp = self.get_stat(used_move.user, Stat::HP) / 4;
+
+                self.add_flag(used_move.user, Flag::Substitute { hp });
+                self.event_queue.push(BattleEvent::SubstituteCreated(event::SubstituteCreated {
+                    pokemon: used_move.user,
+                }));
+                self.inflict_calculated_damage(
+                    used_move.user,
+                    hp,
+                    TypeEffectiveness::Normal,
+                    false,
+                    None,
+                    false,
+                    DamageCause::Substitute,
+                );
+            },
+            SimpleEffect::Haze => {
+                if self.has_flag(used_move.target, "stat_stages") {
+                    self.remove_flag(used_move.target, "stat_stages");
+                    self.event_queue.push(BattleEvent::StatStagesReset(event::StatStagesReset {
+                        pokemon: used_move.target,
+                    }));
+                }
+            },
+            SimpleEffect::PsychUp => {
+                match self.pokemon_flags[&used_move.target].flags.get("stat_stages").cloned() {
+                    Some(stat_stages) => self.add_flag(used_move.user, stat_stages),
+                    None => self.remove_flag(used_move.user, "stat_stages"),
+                }
+
+                self.event_queue.push(BattleEvent::StatStagesCopied(event::StatStagesCopied {
+                    user: used_move.user,
+                    target: used_move.target,
+                }));
+            },
+            SimpleEffect::BatonPass => {
+                self.baton_pass(used_move.user);
+            },
+            _ => todo!(),
+        }
+    }
+
+    /// Picks a random move for Metronome among every move that can be
+    /// called.
+    fn pick_metronome_move(&mut self) -> &'static Move {
+        let movedex = get_all_moves();
+        let mut candidates: Vec<_> = movedex
+            .iter()
+            .filter(|movement| can_be_called(movement))
+            .map(|movement| movement.id.as_str())
+            .collect();
+
+        // The movedex has no particular order, so the candidates are sorted
+        // to keep the choice reproducible
+        candidates.sort();
+
+        let index = self.rng.get_metronome_move(&candidates);
+
+        movedex.get_move(candidates[index]).unwrap()
+    }
+
+    /// Returns the move that Copycat or Mirror Move would use against a
+    /// target, if it can be called.
+    fn get_copied_move(&self, target: usize, called_move: CalledMove) -> Option<&'static Move> {
+        let move_id = match called_move {
+            CalledMove::LastUsedInBattle => &self.move_history.last()?.move_id,
+            CalledMove::LastUsedByTarget => self.get_last_used_move(target)?,
+            CalledMove::Random => unreachable!(),
+        };
+
+        let movement = get_all_moves().get_move(move_id).unwrap();
+
+        if can_be_called(movement) {
+            Some(movement)
+        } else {
+            None
+        }
+    }
+
+    /// Protects a Pokémon until the end of the turn. Wide Guard and Quick
+    /// Guard protect every active Pokémon of the user's team instead. Each
+    /// consecutive use halves the odds of success.
+    fn start_protection(&mut self, user: usize, protection: Protection) {
+        let consecutive_uses = match self.pokemon_flags[&user].flags.get("protection_counter") {
+            Some(Flag::ProtectionCounter(counter)) => *counter,
+            _ => 0,
+        };
+
+        if !self.rng.check_protection_success(consecutive_uses) {
+            self.remove_flag(user, "protection_counter");
+            self.event_queue.push(BattleEvent::FailedMove(event::FailedMove {
+                move_user: user,
+            }));
+            return;
+        }
+
+        self.add_flag(user, Flag::ProtectionCounter(consecutive_uses + 1));
+
+        let protected_pokemon = match protection {
+            Protection::Full | Protection::Endure => vec![user],
+            Protection::WideGuard | Protection::QuickGuard => {
+                let team = self.get_pokemon_team(user);
+
+                self.get_active_positions()
+                    .into_iter()
+                    .filter(|position| position.team == team)
+                    .filter_map(|position| self.get_pokemon_at(position))
+                    .collect()
+            },
+        };
+
+        for pokemon in protected_pokemon {
+            self.add_volatile_status_condition(pokemon, Flag::Protection(protection));
+        }
+    }
+
+    /// Returns the team affected by a side condition started by a Pokémon.
+    /// Entry hazards are set up on the opposing side of the field, while
+    /// every other side condition benefits the user's team.
+    fn get_side_condition_team(&self, user: usize, condition: SideCondition) -> Team {
+        let team = self.get_pokemon_team(user);
+
+        if condition.is_entry_hazard() {
+            team.opponent()
+        } else {
+            team
+        }
+    }
+
+    /// Synchronize passes burns, paralysis and poisoning back to the Pokémon
+    /// that caused them.
+    fn check_synchronize(&mut self, used_move: &UsedMove, status_condition: StatusCondition) {
+        let UsedMove { user, target, .. } = *used_move;
+
+        let is_synchronizable = match status_condition {
+            StatusCondition::Burn
+            | StatusCondition::Paralysis
+            | StatusCondition::Poison
+            | StatusCondition::Toxic { .. } => true,
+            _ => false,
+        };
+
+        let was_inflicted = self.get_pokemon(target).status_condition == Some(status_condition);
+
+        if !is_synchronizable
+            || !was_inflicted
+            || user == target
+            || !self.has_ability(target, "Synchronize")
+            || !self.can_inflict_non_volatile_status_condition_to(user, status_condition.into())
+        {
+            return;
+        }
+
+        self.activate_ability(target);
+        self.add_non_volatile_status_condition(user, status_condition);
+    }
+
+    fn next_turn(&mut self) {
+        self.process_turn_end_events();
+        self.received_damage.clear();
+
+        if let Some(winner) = self.get_winner() {
+            self.end_battle(winner, BattleEndReason::AllPokemonFainted);
+            return;
+        }
+
+        self.turn += 1;
+        self.event_queue
+            .push(BattleEvent::ChangeTurn(event::ChangeTurn {
+                new_turn: self.turn,
+            }));
+    }
+
+    fn process_turn_end_events(&mut self) {
+        self.process_weather_turn_end();
+        self.process_side_conditions_turn_end();
+
+        let active_pokemon: Vec<_> = self
+            .get_active_positions()
+            .into_iter()
+            .filter_map(|position| self.get_pokemon_at(position))
+            .collect();
+
+        for index in active_pokemon {
+            self.remove_flag(index, "flinch");
+            self.remove_flag(index, "protection");
+            self.remove_flag(index, "wide_guard");
+            self.remove_flag(index, "quick_guard");
+            self.remove_flag(index, "endure");
+            self.remove_flag(index, "roost");
+
+            if let Some(on_turn_end) = self.get_active_ability_effect(index).on_turn_end {
+                on_turn_end(self, index);
+            }
+
+            if let Some(on_turn_end) = self.get_held_item_effect(index).on_turn_end {
+                on_turn_end(self, index);
+            }
+
+            if self.is_fainted(index) {
+                continue;
+            }
+
+            self.active_effects
+                .get(&index)
+                .unwrap_or(&Vec::new())
+                .clone()
+                .iter()
+                .filter_map(|effect| effect.on_turn_end)
+                .for_each(|effect| {
+                    effect(self, index);
+                });
+
+            if !self.is_fainted(index) {
+                self.process_trap_turn_end(index);
+                self.process_move_restrictions_turn_end(index);
+                self.process_leech_seed_turn_end(index);
+                self.process_curse_turn_end(index);
+                self.process_drowsiness_turn_end(index);
+                self.process_perish_count_turn_end(index);
+            }
+        }
+    }
+
+    /// Hurts a Pokémon trapped by a move like Wrap, or frees it if the trap
+    /// ran out.
+    fn process_trap_turn_end(&mut self, pokemon: usize) {
+        let remaining_turns = match self.get_flag_mut(pokemon, "trapped") {
+            Some(Flag::Trapped { remaining_turns, .. }) => {
+                *remaining_turns -= 1;
+                *remaining_turns
+            },
+            _ => return,
+        };
+
+        if remaining_turns == 0 {
+            self.remove_volatile_status_condition(pokemon, "trapped");
+        } else {
+            let damage = (self.get_stat(pokemon, Stat::HP) / 8).max(1);
+
+            self.inflict_calculated_damage(
+                pokemon,
+                damage,
+                TypeEffectiveness::Normal,
+                false,
+                None,
+                false,
+                DamageCause::Trap,
+            );
+        }
+    }
+
+    /// Counts down the turns left for Disable and Encore, removing them once
+    /// they run out.
+    fn process_move_restrictions_turn_end(&mut self, pokemon: usize) {
+        for flag_id in &["disabled", "encore"] {
+            let remaining_turns = match self.get_flag_mut(pokemon, flag_id) {
+                Some(Flag::Disabled { remaining_turns, .. })
+                | Some(Flag::Encore { remaining_turns, .. }) => {
+                    *remaining_turns -= 1;
+                    *remaining_turns
+                },
+                _ => continue,
+            };
+
+            if remaining_turns == 0 {
+                self.remove_volatile_status_condition(pokemon, flag_id);
+            }
+        }
+    }
+
+    /// Drains HP from a Pokémon hit by Leech Seed, restoring the HP of the
+    /// Pokémon in the slot of the seeder. Liquid Ooze hurts it instead.
+    fn process_leech_seed_turn_end(&mut self, pokemon: usize) {
+        let seeder_position = match self.pokemon_flags[&pokemon].flags.get("leech_seed") {
+            Some(Flag::LeechSeed { seeder_position }) => *seeder_position,
+            _ => return,
+        };
+
+        let current_hp = self.get_pokemon(pokemon).current_hp;
+        let damage = (self.get_stat(pokemon, Stat::HP) / 8).max(1);
+
+        self.inflict_calculated_damage(
+            pokemon,
+            damage,
+            TypeEffectiveness::Normal,
+            false,
+            None,
+            false,
+            DamageCause::LeechSeed,
+        );
+
+        let damage_dealt = current_hp - self.get_pokemon(pokemon).current_hp;
+        let seeder = match self.get_pokemon_at(seeder_position) {
+            Some(seeder) if damage_dealt > 0 => seeder,
+            _ => return,
+        };
+
+        if self.has_ability(pokemon, "LiquidOoze") {
+            self.activate_ability(pokemon);
+            self.inflict_calculated_damage(
+                seeder,
+                damage_dealt,
+                TypeEffectiveness::Normal,
+                false,
+                None,
+                false,
+                DamageCause::Ability,
+            );
+        } else {
+            self.heal(seeder, damage_dealt);
+        }
+    }
+
+    /// Hurts a Pokémon afflicted by a Ghost-type Curse.
+    fn process_curse_turn_end(&mut self, pokemon: usize) {
+        if !self.has_flag(pokemon, "curse") {
+            return;
+        }
+
+        let damage = (self.get_stat(pokemon, Stat::HP) / 4).max(1);
+
+        self.inflict_calculated_damage(
+            pokemon,
+            damage,
+            TypeEffectiveness::Normal,
+            false,
+            None,
+            false,
+            DamageCause::Curse,
+        );
+    }
+
+    /// Puts a drowsy Pokémon to sleep once its counter runs out.
+    fn process_drowsiness_turn_end(&mut self, pokemon: usize) {
+        let remaining_turns = match self.get_flag_mut(pokemon, "drowsy") {
+            Some(Flag::Drowsy { remaining_turns }) => {
+                *remaining_turns -= 1;
+                *remaining_turns
+            },
+            _ => return,
+        };
+
+        if remaining_turns == 0 {
+            self.remove_flag(pokemon, "drowsy");
+            let remaining_turns = self.rng.get_sleep_duration();
+            self.add_non_volatile_status_condition(pokemon, StatusCondition::Sleep {
+                remaining_turns,
+            });
+        }
+    }
+
+    /// Shows the perish count of a Pokémon that heard Perish Song and lowers
+    /// it, making the Pokémon faint when the count reaches zero. The count is
+    /// shown before going down, so it reads 3 at the end of the turn Perish
+    /// Song was used.
+    fn process_perish_count_turn_end(&mut self, pokemon: usize) {
+        let count = match self.get_flag_mut(pokemon, "perish_count") {
+            Some(Flag::PerishCount(count)) => {
+                let current_count = *count;
+                *count = count.saturating_sub(1);
+                current_count
             },
+            _ => return,
+        };
+
+        self.event_queue.push(BattleEvent::PerishCount(event::PerishCount {
+            pokemon,
+            count,
+        }));
+
+        if count == 0 {
+            let current_hp = self.get_pokemon(pokemon).current_hp;
+
+            self.inflict_calculated_damage(
+                pokemon,
+                current_hp,
+                TypeEffectiveness::Normal,
+                false,
+                None,
+                false,
+                DamageCause::PerishSong,
+            );
+        }
+    }
+
+    /// Frees the Pokémon trapped by one that left the battlefield.
+    fn release_trapped_pokemon(&mut self, trapper: usize) {
+        let trapped_pokemon: Vec<_> = self
+            .pokemon_flags
+            .iter()
+            .filter(|(_, container)| match container.flags.get("trapped") {
+                Some(Flag::Trapped { trapper: t, .. }) => *t == trapper,
+                _ => false,
+            })
+            .map(|(pokemon, _)| *pokemon)
+            .collect();
+
+        for pokemon in trapped_pokemon {
+            self.remove_flag(pokemon, "trapped");
         }
+    }
+
+    /// Counts down the duration of the side conditions of both teams, ending
+    /// the ones that run out.
+    fn process_side_conditions_turn_end(&mut self) {
+        for team in [Team::P1, Team::P2].iter().copied() {
+            let mut expired_conditions: Vec<_> = self
+                .get_team_mut(team)
+                .side_conditions
+                .iter_mut()
+                .filter_map(|(condition, state)| {
+                    let remaining_turns = state.remaining_turns.as_mut()?;
+                    *remaining_turns -= 1;
+
+                    if *remaining_turns == 0 {
+                        Some(*condition)
+                    } else {
+                        None
+                    }
+                })
+                .collect();
 
-        self.process_secondary_effect(&used_move);
+            // Keeps the order of the events independent from the hash map
+            expired_conditions.sort_by_key(|condition| *condition as usize);
+
+            for condition in expired_conditions {
+                self.get_team_mut(team).side_conditions.remove(&condition);
+
+                self.event_queue.push(BattleEvent::SideConditionEnd(event::SideConditionEnd {
+                    team,
+                    condition,
+                }));
+            }
+        }
     }
 
-    fn process_damage_effect(
-        &mut self,
-        used_move: &UsedMove,
-        multi_hit_data: Option<MultiHitData>,
-    ) {
-        let is_critical_hit = used_move.movement.critical_hit;
+    /// Counts down the duration of the current weather, ending it when it
+    /// runs out. Otherwise, damaging weathers hurt every active Pokémon that
+    /// isn't immune to them.
+    fn process_weather_turn_end(&mut self) {
+        let weather = match self.weather.as_mut() {
+            Some(state) => {
+                state.remaining_turns -= 1;
 
-        let (attack, defense) = match (is_critical_hit, used_move.movement.category) {
-            (false, MoveCategory::Physical) => (
-                self.get_stat(used_move.user, Stat::Attack),
-                self.get_stat(used_move.target, Stat::Defense),
-            ),
-            (true, MoveCategory::Physical) => (
-                self.get_attack_critical_hit(used_move.user),
-                self.get_defense_critical_hit(used_move.target),
-            ),
-            (false, MoveCategory::Special) => (
-                self.get_stat(used_move.user, Stat::SpecialAttack),
-                self.get_stat(used_move.target, Stat::SpecialDefense),
-            ),
-            (true, MoveCategory::Special) => (
-                self.get_special_attack_critical_hit(used_move.user),
-                self.get_special_defense_critical_hit(used_move.target),
+                if state.remaining_turns > 0 {
+                    state.weather
+                } else {
+                    let weather = state.weather;
+                    self.weather = None;
+                    self.event_queue.push(BattleEvent::WeatherEnd(event::WeatherEnd {
+                        weather,
+                    }));
+                    return;
+                }
+            },
+            None => return,
+        };
+
+        let (immune_types, immune_abilities): (&[PokemonType], &[&str]) = match weather {
+            Weather::Sandstorm => (
+                &[PokemonType::Rock, PokemonType::Ground, PokemonType::Steel],
+                &["MagicGuard", "SandForce", "SandVeil"],
             ),
-            _ => unreachable!(),
+            Weather::Hail => (&[PokemonType::Ice], &["MagicGuard"]),
+            Weather::Rain | Weather::Sun => return,
         };
 
-        self.inflict_damage(&used_move, attack, defense, is_critical_hit, multi_hit_data);
-    }
+        if self.is_weather_suppressed() {
+            return;
+        }
 
-    fn process_secondary_effect(&mut self, used_move: &UsedMove) {
-        if let Some(effect) = used_move.movement.secondary_effect.as_ref() {
-            if !self.rng.check_secondary_effect(effect.chance) {
-                return;
-            }
+        let affected_pokemon: Vec<_> = self
+            .get_active_positions()
+            .into_iter()
+            .filter_map(|position| self.get_pokemon_at(position))
+            .filter(|pokemon| !immune_types.iter().any(|t| self.has_type(*pokemon, *t)))
+            .filter(|pokemon| !immune_abilities.iter().any(|a| self.has_ability(*pokemon, a)))
+            .collect();
 
-            match &effect.effect {
-                SimpleEffect::Confusion => {
-                    let duration = self.rng.get_confusion_duration();
+        for target in affected_pokemon {
+            let max_hp = self.get_stat(target, Stat::HP) as f32;
+            let damage = (max_hp / 16.).ceil() as usize;
 
-                    self.add_volatile_status_condition(used_move.target, Flag::Confusion {
-                        remaining_move_attempts: duration,
-                    });
-                },
-                SimpleEffect::Flinch => {
-                    self.add_volatile_status_condition(used_move.target, Flag::Flinch);
-                },
-                SimpleEffect::StatChange { changes, target } => {
-                    let target = match target {
-                        SimpleEffectTarget::MoveTarget => used_move.target,
-                        SimpleEffectTarget::MoveUser => used_move.user,
-                    };
+            self.event_queue.push(BattleEvent::WeatherDamage(event::WeatherDamage {
+                target,
+                weather,
+            }));
 
-                    for (stat, delta) in changes {
-                        self.change_stat_stage(target, *stat, *delta);
-                    }
-                },
-                SimpleEffect::StatusCondition(status_condition) => {
-                    self.add_non_volatile_status_condition(used_move.target, *status_condition);
-                },
-                _ => todo!(),
-            }
+            self.inflict_calculated_damage(
+                target,
+                damage,
+                TypeEffectiveness::Normal,
+                false,
+                None,
+                false,
+                DamageCause::Weather,
+            );
         }
     }
 
-    fn next_turn(&mut self) {
-        self.process_turn_end_events();
+    /// Throws a Pokéball at the opponent's active Pokémon. The catch rate
+    /// depends on the species' capture rate, the remaining HP of the target,
+    /// its non-volatile status condition and the kind of Pokéball. Returns
+    /// `true` if the Pokémon was caught.
+    fn throw_pokeball(&mut self, team: Team, pokeball: &str) -> bool {
+        let pokeball_modifier = match get_pokeball_modifier(pokeball) {
+            Some(modifier) => modifier,
+            None => {
+                self.event_queue.push(BattleEvent::InvalidPokeball(event::InvalidPokeball {
+                    team,
+                    pokeball: pokeball.to_string(),
+                }));
+                return false;
+            },
+        };
 
-        self.turn += 1;
-        self.event_queue
-            .push(BattleEvent::ChangeTurn(event::ChangeTurn {
-                new_turn: self.turn,
-            }));
-    }
+        let target = self
+            .get_team(team.opponent())
+            .active_pokemon
+            .iter()
+            .flatten()
+            .copied()
+            .next()
+            .unwrap();
+        let pokemon = &self.pokemon_repository[&target];
+        let max_hp = pokemon.stats[Stat::HP as usize];
+
+        let status_condition_multiplier = match pokemon.status_condition {
+            Some(StatusCondition::Sleep { .. }) | Some(StatusCondition::Freeze) => 2.,
+            Some(_) => 1.5,
+            None => 1.,
+        };
 
-    fn process_turn_end_events(&mut self) {
-        if let Some(index) = self.p1.active_pokemon {
-            self.remove_flag(index, "flinch");
+        let catch_rate = ((3 * max_hp - 2 * pokemon.current_hp) as f64
+            * self.get_species(target).capture_rate as f64
+            * pokeball_modifier
+            / (3 * max_hp) as f64
+            * status_condition_multiplier) as usize;
 
-            self.active_effects
-                .get(&index)
-                .unwrap_or(&Vec::new())
-                .clone()
-                .iter()
-                .filter_map(|effect| effect.on_turn_end)
-                .for_each(|effect| {
-                    effect(self, index);
-                });
+        let shake_checks = if catch_rate >= 255 {
+            4
+        } else {
+            let shake_odds = (1_048_560. / (16_711_680. / catch_rate.max(1) as f64).sqrt().sqrt())
+                as usize;
+
+            (0..4)
+                .take_while(|_| self.rng.check_capture_shake(shake_odds))
+                .count()
+        };
+
+        let success = shake_checks == 4;
+
+        self.event_queue.push(BattleEvent::CaptureAttempt(event::CaptureAttempt {
+            team,
+            target,
+            pokeball: pokeball.to_string(),
+            shakes: shake_checks.min(3),
+            success,
+        }));
+
+        if success {
+            let pokemon = self.pokemon_repository.get_mut(&target).unwrap();
+            pokemon.pokeball = Some(pokeball.to_string());
+            pokemon.met_at_date = SystemTime::now();
+            pokemon.met_at_level = pokemon.level;
+
+            self.caught_pokemon = Some(target);
+            self.end_battle(team, BattleEndReason::PokemonCaught);
         }
 
-        if let Some(index) = self.p2.active_pokemon {
-            self.remove_flag(index, "flinch");
+        success
+    }
 
-            self.active_effects
-                .get(&index)
-                .unwrap_or(&Vec::new())
-                .clone()
-                .iter()
-                .filter_map(|effect| effect.on_turn_end)
-                .for_each(|effect| {
-                    effect(self, index);
-                });
+    /// Returns the winner of the battle if one of the teams has no Pokémon
+    /// able to fight. If both teams are out of Pokémon at the same time, the
+    /// player loses.
+    fn get_winner(&self) -> Option<Team> {
+        if !self.has_usable_pokemon(Team::P1) {
+            Some(Team::P2)
+        } else if !self.has_usable_pokemon(Team::P2) {
+            Some(Team::P1)
+        } else {
+            None
         }
     }
 
-    fn decompose_input_events(&mut self) -> (FrontendEventKind, FrontendEventKind) {
-        if self.input_events.len() != 2 {
+    fn end_battle(&mut self, winner: Team, reason: BattleEndReason) {
+        let battle_end = event::BattleEnd { winner, reason };
+
+        self.outcome = Some(battle_end.clone());
+        self.event_queue.push(BattleEvent::BattleEnd(battle_end));
+    }
+
+    /// Returns the input events of this turn sorted by team and slot. There
+    /// must be exactly one event for each active Pokémon.
+    fn decompose_input_events(&mut self) -> Vec<FrontendEvent> {
+        let mut input_events: Vec<_> = self.input_events.drain(..).collect();
+        input_events.sort_by_key(|event| (event.team as usize, event.slot));
+
+        let positions: Vec<_> = input_events
+            .iter()
+            .map(|event| FieldPosition {
+                team: event.team,
+                slot: event.slot,
+            })
+            .collect();
+
+        if positions != self.get_active_positions() {
             panic!(
-                "Invalid number of input events: {}",
-                self.input_events.len()
+                "Invalid input events: expected one event for each active Pokémon, got {:?}",
+                positions,
             );
         }
 
-        let mut input_events = self.input_events.drain(..);
-        let first = input_events.next().unwrap();
-        let second = input_events.next().unwrap();
-
-        match (first.team, second.team) {
-            (Team::P1, Team::P2) => (first.event, second.event),
-            (Team::P2, Team::P1) => (second.event, first.event),
-            _ => panic!("Invalid input events: each event must be emitted by a different team."),
-        }
+        input_events
     }
 }
 
@@ -667,10 +2826,21 @@ impl BattleBackend {
         is_critical_hit: bool,
         multi_hit_data: Option<MultiHitData>,
     ) {
-        let effectiveness = self.get_type_effectiveness(&used_move.movement, used_move.target);
+        let mut effectiveness =
+            self.get_type_effectiveness(&used_move.movement, used_move.target);
 
         let (damage, is_ohko) = if used_move.movement.flags.contains(&MoveFlag::OneHitKO) {
             (None, true)
+        } else if let Some(fixed_damage) = &used_move.movement.fixed_damage {
+            // Fixed damage ignores type effectiveness, except for immunities
+            let damage = if effectiveness > 0. {
+                effectiveness = 1.;
+                self.get_fixed_damage(used_move, fixed_damage)
+            } else {
+                0
+            };
+
+            (Some(damage), false)
         } else {
             let damage =
                 self.get_move_damage(&used_move, attack, defense, effectiveness, is_critical_hit);
@@ -681,20 +2851,44 @@ impl BattleBackend {
         let target = self.pokemon_repository.get_mut(&used_move.target).unwrap();
         let mut damage = damage.unwrap_or(target.current_hp);
 
-        self.active_effects
-            .get(&used_move.user)
-            .unwrap_or(&Vec::new())
-            .iter()
-            .filter_map(|effect| effect.on_try_deal_damage)
-            .for_each(|effect| {
-                damage = effect(
-                    &self,
-                    used_move.user,
-                    used_move.target,
-                    &used_move.movement,
-                    damage
-                );
-            });
+        if used_move.movement.fixed_damage.is_none() {
+            self.active_effects
+                .get(&used_move.user)
+                .unwrap_or(&Vec::new())
+                .iter()
+                .filter_map(|effect| effect.on_try_deal_damage)
+                .for_each(|effect| {
+                    damage = effect(
+                        &self,
+                        used_move.user,
+                        used_move.target,
+                        &used_move.movement,
+                        damage
+                    );
+                });
+        }
+
+        if self.is_behind_substitute(used_move) {
+            let damage_dealt = self.damage_substitute(used_move.target, damage);
+            self.process_drain_and_recoil(used_move, damage_dealt);
+            return;
+        }
+
+        let current_hp = self.get_pokemon(used_move.target).current_hp;
+        let is_lethal = damage >= current_hp;
+        let endures_hit = is_lethal
+            && current_hp == self.get_stat(used_move.target, Stat::HP)
+            && self.has_ability(used_move.target, "Sturdy");
+        let is_enduring = is_lethal && self.has_flag(used_move.target, "endure");
+        let is_spared = is_lethal && used_move.movement.flags.contains(&MoveFlag::NonLethal);
+
+        if endures_hit || is_enduring || is_spared {
+            damage = current_hp - 1;
+        }
+
+        // Fainting resets the flags of the target, so Destiny Bond has to be
+        // checked beforehand
+        let has_destiny_bond = self.has_flag(used_move.target, "destiny_bond");
 
         self.inflict_calculated_damage(
             used_move.target,
@@ -704,7 +2898,181 @@ impl BattleBackend {
             multi_hit_data,
             is_ohko,
             DamageCause::Move,
-        )
+        );
+
+        if let Some(on_damage_taken) = self.get_held_item_effect(used_move.target).on_damage_taken {
+            on_damage_taken(self, used_move.target, used_move.user, used_move.movement);
+        }
+
+        if endures_hit {
+            self.activate_ability(used_move.target);
+        } else if is_enduring {
+            self.event_queue.push(BattleEvent::Protected(event::Protected {
+                target: used_move.target,
+                protection: Protection::Endure,
+            }));
+        }
+
+        let damage_dealt = current_hp - self.get_pokemon(used_move.target).current_hp;
+
+        if has_destiny_bond && self.is_fainted(used_move.target) {
+            self.trigger_destiny_bond(used_move.target, used_move.user);
+        }
+
+        if damage_dealt > 0 {
+            self.received_damage.insert(used_move.target, ReceivedDamage {
+                source: used_move.user,
+                category: used_move.movement.category,
+                amount: damage_dealt,
+            });
+        }
+
+        self.process_drain_and_recoil(used_move, damage_dealt);
+    }
+
+    /// Makes a confused Pokémon attack itself with a typeless 40 power
+    /// physical move. Only the base damage formula applies, so the hit is
+    /// never modified by items, abilities, screens, weather, critical hits
+    /// or the random factor. It can't be absorbed by a substitute and doesn't
+    /// count as damage received from a move (e.g for Counter).
+    fn hurt_by_confusion(&mut self, pokemon: usize) {
+        let power = match get_confusion_move().base_power {
+            MovePower::Constant(value) => value,
+            MovePower::Special => unreachable!(),
+        };
+
+        let level = self.get_pokemon(pokemon).level;
+        let attack = self.get_stat(pokemon, Stat::Attack);
+        let defense = self.get_stat(pokemon, Stat::Defense);
+        let damage = (get_base_damage(level, power, attack, defense) as usize).max(1);
+
+        self.inflict_calculated_damage(
+            pokemon,
+            damage,
+            TypeEffectiveness::Normal,
+            false,
+            None,
+            false,
+            DamageCause::Confusion,
+        );
+    }
+
+    /// Makes the foe that knocked out a Pokémon under the effects of Destiny
+    /// Bond faint as well.
+    fn trigger_destiny_bond(&mut self, pokemon: usize, foe: usize) {
+        let is_foe = self.get_pokemon_team(pokemon) != self.get_pokemon_team(foe);
+
+        if !is_foe || self.is_fainted(foe) {
+            return;
+        }
+
+        self.event_queue.push(BattleEvent::DestinyBond(event::DestinyBond {
+            pokemon,
+            target: foe,
+        }));
+
+        let current_hp = self.get_pokemon(foe).current_hp;
+
+        self.inflict_calculated_damage(
+            foe,
+            current_hp,
+            TypeEffectiveness::Normal,
+            false,
+            None,
+            false,
+            DamageCause::DestinyBond,
+        );
+    }
+
+    /// Makes the substitute of a Pokémon take a hit in its place, breaking
+    /// it if it runs out of HP. Returns the damage taken by the substitute.
+    fn damage_substitute(&mut self, pokemon: usize, damage: usize) -> usize {
+        let (amount, remaining_hp) = match self.get_flag_mut(pokemon, "substitute") {
+            Some(Flag::Substitute { hp }) => {
+                let amount = damage.min(*hp);
+                *hp -= amount;
+
+                (amount, *hp)
+            },
+            _ => unreachable!(),
+        };
+
+        self.event_queue.push(BattleEvent::SubstituteDamaged(event::SubstituteDamaged {
+            pokemon,
+            amount,
+        }));
+
+        if remaining_hp == 0 {
+            self.remove_flag(pokemon, "substitute");
+            self.event_queue.push(BattleEvent::SubstituteBroken(event::SubstituteBroken {
+                pokemon,
+            }));
+        }
+
+        amount
+    }
+
+    /// Returns the damage dealt by a move that doesn't use the damage formula.
+    fn get_fixed_damage(&self, used_move: &UsedMove, fixed_damage: &FixedDamage) -> usize {
+        let UsedMove { user, target, .. } = *used_move;
+
+        match fixed_damage {
+            FixedDamage::Constant(damage) => *damage,
+            FixedDamage::UserLevel => self.get_pokemon(user).level,
+            FixedDamage::TargetCurrentHp(percentage) => {
+                (self.get_pokemon(target).current_hp * percentage / 100).max(1)
+            },
+            FixedDamage::Custom(callback) => callback(self, user, target),
+        }
+    }
+
+    /// Heals the user of a draining move or hurts the user of a recoil move,
+    /// according to the damage dealt by a single hit.
+    fn process_drain_and_recoil(&mut self, used_move: &UsedMove, damage_dealt: usize) {
+        let UsedMove { user, target, movement, .. } = *used_move;
+
+        if damage_dealt == 0 {
+            return;
+        }
+
+        if let Some(drain) = movement.drain {
+            let amount = (damage_dealt as f32 * drain).round() as usize;
+
+            if self.has_ability(target, "LiquidOoze") {
+                self.activate_ability(target);
+                self.inflict_calculated_damage(
+                    user,
+                    amount.max(1),
+                    TypeEffectiveness::Normal,
+                    false,
+                    None,
+                    false,
+                    DamageCause::Ability,
+                );
+            } else {
+                self.heal(user, amount);
+            }
+        }
+
+        if let Some(recoil) = movement.recoil {
+            let amount = if movement.flags.contains(&MoveFlag::MaxHpRecoil) {
+                (self.get_stat(user, Stat::HP) as f32 * recoil).round() as usize
+            } else if self.has_ability(user, "RockHead") {
+                return;
+            } else {
+                (damage_dealt as f32 * recoil).round() as usize
+            };
+
+            self.inflict_calculated_damage(
+                user,
+                amount.max(1),
+                TypeEffectiveness::Normal,
+                false,
+                None,
+                false,
+                DamageCause::Recoil,
+            );
+        }
     }
 
     pub fn inflict_calculated_damage(
@@ -717,6 +3085,22 @@ impl BattleBackend {
         is_ohko: bool,
         cause: DamageCause,
     ) {
+        // Magic Guard only prevents indirect damage. Paying for a substitute,
+        // fainting because of Perish Song or Destiny Bond and hitting itself
+        // in confusion don't count
+        let is_indirect_damage = match cause {
+            DamageCause::Move
+            | DamageCause::Substitute
+            | DamageCause::PerishSong
+            | DamageCause::DestinyBond
+            | DamageCause::Confusion => false,
+            _ => true,
+        };
+
+        if is_indirect_damage && self.has_ability(target, "MagicGuard") {
+            return;
+        }
+
         let target_pokemon = self.pokemon_repository.get_mut(&target).unwrap();
         target_pokemon.current_hp = target_pokemon.current_hp.saturating_sub(damage);
 
@@ -742,27 +3126,88 @@ impl BattleBackend {
             cause,
         }));
 
-        // TODO: trigger effects like Static
-
         if target_pokemon.current_hp == 0 {
-            match self.get_pokemon_team(target) {
-                Team::P1 => {
-                    self.p1.active_pokemon = None;
-                    self.p1.party.push_front(target);
-                },
-                Team::P2 => {
-                    self.p2.active_pokemon = None;
-                    self.p2.party.push_front(target);
-                },
+            self.pokemon_flags.insert(target, FlagContainer::default());
+            self.release_trapped_pokemon(target);
+
+            let team = self.get_pokemon_team(target);
+            let team_data = self.get_team_mut(team);
+
+            for slot in team_data.active_pokemon.iter_mut() {
+                if *slot == Some(target) {
+                    *slot = None;
+                }
             }
 
+            team_data.party.push_front(target);
+
             self.event_queue.push(BattleEvent::Faint(event::Faint {
                 target,
             }));
         }
     }
 
+    /// Restores HP of a Pokémon, without exceeding its maximum HP.
+    pub fn heal(&mut self, target: usize, amount: usize) {
+        let max_hp = self.get_stat(target, Stat::HP);
+        let target_pokemon = self.get_pokemon_mut(target);
+        let amount = amount.max(1).min(max_hp - target_pokemon.current_hp);
+
+        if amount == 0 {
+            return;
+        }
+
+        target_pokemon.current_hp += amount;
+
+        self.event_queue.push(BattleEvent::Heal(event::Heal {
+            target,
+            amount,
+        }));
+    }
+
+    /// Announces that the ability of a Pokémon is taking effect.
+    pub fn activate_ability(&mut self, pokemon: usize) {
+        let ability = self.get_ability(pokemon).to_string();
+
+        self.event_queue.push(BattleEvent::AbilityActivated(event::AbilityActivated {
+            pokemon,
+            ability,
+        }));
+    }
+
+    /// Replaces the current weather with a new one, which lasts for 5 turns.
+    pub fn start_weather(&mut self, weather: Weather) {
+        self.weather = Some(WeatherState {
+            weather,
+            remaining_turns: 5,
+        });
+
+        self.event_queue.push(BattleEvent::WeatherStart(event::WeatherStart {
+            weather,
+        }));
+    }
+
+    /// Adds a side condition to a team, replacing it if it's already active.
+    pub fn start_side_condition(&mut self, team: Team, condition: SideCondition) {
+        self.get_team_mut(team).side_conditions.insert(condition, SideConditionState {
+            remaining_turns: condition.get_duration(),
+        });
+
+        self.event_queue.push(BattleEvent::SideConditionStart(event::SideConditionStart {
+            team,
+            condition,
+        }));
+    }
+
     fn add_volatile_status_condition(&mut self, target: usize, flag: Flag) {
+        let on_check_flag_immunity = self.get_active_ability_effect(target).on_check_flag_immunity;
+
+        if let Some(is_immune) = on_check_flag_immunity {
+            if is_immune(self, target, &flag) {
+                return;
+            }
+        }
+
         self.add_flag(target, flag.clone());
 
         self.event_queue
@@ -770,9 +3215,29 @@ impl BattleBackend {
                 target,
                 added_flag: flag,
             }));
+
+        self.trigger_status_condition_item(target);
     }
 
-    fn add_non_volatile_status_condition(&mut self, target: usize, condition: StatusCondition) {
+    /// Removes a volatile status condition of a Pokémon, e.g when it expires.
+    pub fn remove_volatile_status_condition(&mut self, target: usize, flag_id: &str) {
+        let flag = self.pokemon_flags[&target].flags[flag_id].clone();
+
+        self.event_queue.push(BattleEvent::ExpiredVolatileStatusCondition(
+            event::ExpiredVolatileStatusCondition {
+                target,
+                flag,
+            }
+        ));
+
+        self.remove_flag(target, flag_id);
+    }
+
+    pub fn add_non_volatile_status_condition(
+        &mut self,
+        target: usize,
+        condition: StatusCondition,
+    ) {
         if self.can_inflict_non_volatile_status_condition_to(target, condition.into()) {
             let target_pokemon = self.get_pokemon_mut(target);
 
@@ -794,6 +3259,14 @@ impl BattleBackend {
                         condition,
                     },
                 ));
+
+            self.trigger_status_condition_item(target);
+        }
+    }
+
+    fn trigger_status_condition_item(&mut self, target: usize) {
+        if let Some(on_status_condition) = self.get_held_item_effect(target).on_status_condition {
+            on_status_condition(self, target);
         }
     }
 
@@ -801,6 +3274,8 @@ impl BattleBackend {
         let target_pokemon = self.get_pokemon_mut(target);
         let condition = target_pokemon.status_condition.take().unwrap();
 
+        self.active_effects.remove(&target);
+
         self.event_queue.push(BattleEvent::ExpiredNonVolatileStatusCondition(
             event::ExpiredNonVolatileStatusCondition {
                 target,
@@ -809,11 +3284,32 @@ impl BattleBackend {
         ));
     }
 
-    fn add_flag(&mut self, target: usize, flag: Flag) {
+    pub fn add_flag(&mut self, target: usize, flag: Flag) {
         let key = match flag {
+            Flag::Ability(_) => "ability",
+            Flag::ChoiceLock(_) => "choice_lock",
             Flag::Confusion { .. } => "confusion",
+            Flag::Curse => "curse",
+            Flag::DestinyBond => "destiny_bond",
+            Flag::Disabled { .. } => "disabled",
+            Flag::Encore { .. } => "encore",
+            Flag::Drowsy { .. } => "drowsy",
+            Flag::FlashFire => "flash_fire",
             Flag::Flinch => "flinch",
-            Flag::StatStages(_) => unreachable!(),
+            Flag::ForcedAction(_) => "forced_action",
+            Flag::LeechSeed { .. } => "leech_seed",
+            Flag::PerishCount(_) => "perish_count",
+            Flag::Trapped { .. } => "trapped",
+            Flag::Protection(Protection::Full) => "protection",
+            Flag::Protection(Protection::WideGuard) => "wide_guard",
+            Flag::Protection(Protection::QuickGuard) => "quick_guard",
+            Flag::Protection(Protection::Endure) => "endure",
+            Flag::ProtectionCounter(_) => "protection_counter",
+            Flag::Roost => "roost",
+            Flag::StatStages(_) => "stat_stages",
+            Flag::Substitute { .. } => "substitute",
+            Flag::Unburden => "unburden",
+            Flag::UsedMoves(_) => "used_moves",
         };
 
         self.pokemon_flags
@@ -831,7 +3327,32 @@ impl BattleBackend {
             .remove(flag_id);
     }
 
-    fn change_stat_stage(&mut self, target: usize, stat: Stat, delta: i8) {
+    /// Lowers a stat of a Pokémon because of the actions of another Pokémon,
+    /// which can be prevented by abilities like Clear Body.
+    pub fn lower_stat_stage_from_foe(&mut self, target: usize, stat: Stat, delta: i8) {
+        if self.has_side_condition(self.get_pokemon_team(target), SideCondition::Mist) {
+            return;
+        }
+
+        let on_check_stat_drop_immunity =
+            self.get_active_ability_effect(target).on_check_stat_drop_immunity;
+
+        if let Some(is_immune) = on_check_stat_drop_immunity {
+            if is_immune(self, target, stat) {
+                self.activate_ability(target);
+                return;
+            }
+        }
+
+        self.change_stat_stage(target, stat, delta);
+
+        if self.has_ability(target, "Defiant") {
+            self.activate_ability(target);
+            self.change_stat_stage(target, Stat::Attack, 2);
+        }
+    }
+
+    pub fn change_stat_stage(&mut self, target: usize, stat: Stat, delta: i8) {
         let stat_stages = self
             .pokemon_flags
             .get_mut(&target)
@@ -891,28 +3412,451 @@ impl BattleBackend {
     }
 
     pub fn get_active_pokemon(&self, team: Team) -> impl Iterator<Item = &Pokemon> + '_ {
-        let team_data = match team {
-            Team::P1 => &self.p1,
-            Team::P2 => &self.p2,
-        };
+        self.get_team(team)
+            .active_pokemon
+            .iter()
+            .flatten()
+            .map(move |pokemon| self.get_pokemon(*pokemon))
+    }
 
-        team_data
+    /// Returns the occupied active slots of both teams, sorted by team and
+    /// slot.
+    pub fn get_active_positions(&self) -> Vec<FieldPosition> {
+        [Team::P1, Team::P2]
+            .iter()
+            .flat_map(|team| {
+                self.get_team(*team)
+                    .active_pokemon
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, pokemon)| pokemon.is_some())
+                    .map(move |(slot, _)| FieldPosition { team: *team, slot })
+            })
+            .collect()
+    }
+
+    /// Returns the Pokémon in a given active slot, if any.
+    pub fn get_pokemon_at(&self, position: FieldPosition) -> Option<usize> {
+        self.get_team(position.team)
             .active_pokemon
+            .get(position.slot)
+            .copied()
+            .flatten()
+    }
+
+    /// Returns the active slot of a Pokémon, or `None` if it's not active.
+    pub fn get_pokemon_position(&self, pokemon: usize) -> Option<FieldPosition> {
+        self.get_active_positions()
+            .into_iter()
+            .find(|position| self.get_pokemon_at(*position) == Some(pokemon))
+    }
+
+    /// Returns whether two active slots are next to each other. Pokémon on
+    /// opposite sides of the field face each other, so the first slot of a
+    /// team is in front of the last slot of the other team.
+    fn are_adjacent(&self, a: FieldPosition, b: FieldPosition) -> bool {
+        let slot_count = self.battle_type.get_active_slot_count() as isize;
+        let a_slot = a.slot as isize;
+        let b_slot = b.slot as isize;
+
+        if a.team == b.team {
+            (a_slot - b_slot).abs() == 1
+        } else {
+            (a_slot - (slot_count - 1 - b_slot)).abs() <= 1
+        }
+    }
+
+    /// Returns the Pokémon of a team that are not currently on the field, in
+    /// the order expected by `FrontendEventKind::Switch`.
+    pub fn get_party(&self, team: Team) -> impl Iterator<Item = &Pokemon> + '_ {
+        self.get_team(team)
+            .party
             .iter()
             .map(move |pokemon| self.get_pokemon(*pokemon))
     }
 
-    pub fn get_pokemon_team(&self, pokemon: usize) -> Team {
-        if let Some(index) = self.p1.active_pokemon {
-            if index == pokemon {
-                return Team::P1;
-            }
+    /// Returns the active slots of a team that have no Pokémon in them.
+    pub fn get_empty_slots(&self, team: Team) -> impl Iterator<Item = usize> + '_ {
+        self.get_team(team)
+            .active_pokemon
+            .iter()
+            .enumerate()
+            .filter(|(_, pokemon)| pokemon.is_none())
+            .map(|(slot, _)| slot)
+    }
+
+    /// Returns whether a team has to send in a Pokémon to replace a fainted
+    /// one, or one that used Baton Pass, before the battle can continue.
+    pub fn requires_replacement(&self, team: Team) -> bool {
+        let team_data = self.get_team(team);
+
+        self.outcome.is_none()
+            && team_data.active_pokemon.iter().any(Option::is_none)
+            && (0..team_data.party.len()).any(|index| self.can_switch_in(team, index))
+    }
+
+    /// Returns whether the Pokémon at the given index of the party of a team
+    /// can be sent in. A Pokémon that used Baton Pass can't replace itself.
+    pub fn can_switch_in(&self, team: Team, party_index: usize) -> bool {
+        let pokemon = self.get_party_member(team, party_index);
+
+        !self.is_fainted(pokemon)
+            && !self
+                .get_team(team)
+                .baton_passes
+                .values()
+                .any(|baton_pass| baton_pass.user == pokemon)
+    }
+
+    /// Returns whether a team is allowed to throw Pokéballs, which is only
+    /// the case when the opposing team is a single wild Pokémon.
+    pub fn can_capture(&self, team: Team) -> bool {
+        let opponent = self.get_team(team.opponent());
+
+        opponent.character_id.is_none() && opponent.active_pokemon.iter().flatten().count() == 1
+    }
+
+    /// Returns whether a team is allowed to flee from the battle, which is
+    /// only the case when the opposing team is not owned by a trainer.
+    pub fn can_escape(&self, team: Team) -> bool {
+        self.get_team(team.opponent()).character_id.is_none()
+    }
+
+    /// Returns every Pokémon of a team in the order of the party the team
+    /// had before the battle, e.g to persist their state once it ends.
+    pub fn get_team_pokemon(&self, team: Team) -> impl Iterator<Item = &Pokemon> + '_ {
+        let team_data = self.get_team(team);
+        let mut team_pokemon: Vec<_> = team_data
+            .active_pokemon
+            .iter()
+            .flatten()
+            .chain(team_data.party.iter())
+            .copied()
+            .collect();
+
+        // Pokémon are numbered following the order of their original party
+        team_pokemon.sort_unstable();
+        team_pokemon
+            .into_iter()
+            .map(move |pokemon| self.get_pokemon(pokemon))
+    }
+
+    /// Returns the wild Pokémon that was caught during this battle, if any.
+    pub fn get_caught_pokemon(&self) -> Option<&Pokemon> {
+        self.caught_pokemon.map(|pokemon| &self.pokemon_repository[&pokemon])
+    }
+
+    /// Returns whether a team still has at least one Pokémon able to fight.
+    pub fn has_usable_pokemon(&self, team: Team) -> bool {
+        let team_data = self.get_team(team);
+
+        team_data
+            .active_pokemon
+            .iter()
+            .flatten()
+            .chain(team_data.party.iter())
+            .any(|pokemon| !self.is_fainted(*pokemon))
+    }
+
+    /// Returns the current weather, unless its effects are suppressed by an
+    /// ability like Cloud Nine.
+    pub fn get_weather(&self) -> Option<Weather> {
+        if self.is_weather_suppressed() {
+            return None;
         }
 
-        if let Some(index) = self.p2.active_pokemon {
-            if index == pokemon {
-                return Team::P2;
-            }
+        self.weather.as_ref().map(|state| state.weather)
+    }
+
+    pub fn has_side_condition(&self, team: Team, condition: SideCondition) -> bool {
+        self.get_team(team).side_conditions.contains_key(&condition)
+    }
+
+    /// Checks if Safeguard prevents a Pokémon from getting a status condition
+    /// caused by another one.
+    pub fn is_protected_by_safeguard(&self, target: usize, source: usize) -> bool {
+        target != source
+            && self.has_side_condition(self.get_pokemon_team(target), SideCondition::Safeguard)
+    }
+
+    fn is_weather_suppressed(&self) -> bool {
+        self.get_active_positions()
+            .into_iter()
+            .filter_map(|position| self.get_pokemon_at(position))
+            .any(|pokemon| self.has_ability(pokemon, "CloudNine"))
+    }
+
+    /// Returns the current ability of a Pokémon, which might be different
+    /// from its original one (e.g after using Trace).
+    pub fn get_ability(&self, pokemon: usize) -> &str {
+        match self.pokemon_flags[&pokemon].flags.get("ability") {
+            Some(Flag::Ability(ability)) => ability,
+            _ => &self.get_pokemon(pokemon).ability,
+        }
+    }
+
+    /// Checks if a Pokémon has a given ability, as long as it's not being
+    /// suppressed by Neutralizing Gas.
+    pub fn has_ability(&self, pokemon: usize, ability: &str) -> bool {
+        self.get_ability(pokemon) == ability && !self.is_ability_suppressed(pokemon)
+    }
+
+    fn is_ability_suppressed(&self, pokemon: usize) -> bool {
+        self.get_active_positions()
+            .into_iter()
+            .filter_map(|position| self.get_pokemon_at(position))
+            .any(|other| other != pokemon && self.get_ability(other) == "NeutralizingGas")
+    }
+
+    fn get_active_ability_effect(&self, pokemon: usize) -> AbilityEffect {
+        if self.is_ability_suppressed(pokemon) {
+            AbilityEffect::default()
+        } else {
+            get_ability_effect(self.get_ability(pokemon))
+        }
+    }
+
+    /// Returns the active foes that are adjacent to a Pokémon.
+    pub fn get_adjacent_foes(&self, pokemon: usize) -> Vec<usize> {
+        let position = match self.get_pokemon_position(pokemon) {
+            Some(position) => position,
+            None => return Vec::new(),
+        };
+
+        self.get_active_positions()
+            .into_iter()
+            .filter(|other| other.team != position.team && self.are_adjacent(position, *other))
+            .filter_map(|other| self.get_pokemon_at(other))
+            .collect()
+    }
+
+    pub fn get_held_item(&self, pokemon: usize) -> Option<&str> {
+        self.get_pokemon(pokemon).held_item.as_deref()
+    }
+
+    fn get_held_item_effect(&self, pokemon: usize) -> ItemEffect {
+        match self.get_held_item(pokemon) {
+            Some(item) => get_item_effect(item),
+            None => ItemEffect::default(),
+        }
+    }
+
+    /// Returns the damage received by a Pokémon from the last move that hit
+    /// it in the current turn, if any.
+    pub fn get_received_damage(&self, pokemon: usize) -> Option<&ReceivedDamage> {
+        self.received_damage.get(&pokemon)
+    }
+
+    /// Returns `true` if a Pokémon can't flee or switch out, e.g because of
+    /// Wrap or an adjacent foe's Arena Trap.
+    pub fn is_trapped(&self, pokemon: usize) -> bool {
+        if self.has_flag(pokemon, "trapped") {
+            return true;
+        }
+
+        let is_grounded =
+            !self.has_type(pokemon, PokemonType::Flying) && !self.has_ability(pokemon, "Levitate");
+
+        is_grounded
+            && self
+                .get_adjacent_foes(pokemon)
+                .into_iter()
+                .any(|foe| self.has_ability(foe, "ArenaTrap"))
+    }
+
+    /// Returns `true` if a Pokémon has to take an action instead of choosing
+    /// a move (e.g unleashing a charged move), in which case the move chosen
+    /// by the frontend is ignored.
+    pub fn has_forced_action(&self, pokemon: usize) -> bool {
+        self.has_flag(pokemon, "forced_action")
+    }
+
+    fn get_forced_action(&self, pokemon: usize) -> Option<&ForcedAction> {
+        match self.pokemon_flags[&pokemon].flags.get("forced_action") {
+            Some(Flag::ForcedAction(action)) => Some(action),
+            _ => None,
+        }
+    }
+
+    /// Checks if the target of a move is hiding behind a substitute that the
+    /// move can't go through. Sound-based moves ignore substitutes.
+    fn is_behind_substitute(&self, used_move: &UsedMove) -> bool {
+        used_move.user != used_move.target
+            && self.has_flag(used_move.target, "substitute")
+            && !used_move.movement.flags.contains(&MoveFlag::Sound)
+    }
+
+    /// Checks if a Pokémon is out of reach while charging a move like Dig.
+    fn is_semi_invulnerable(&self, pokemon: usize) -> bool {
+        match self.get_forced_action(pokemon) {
+            Some(ForcedAction::ChargedMove { move_id, .. }) => {
+                let movement = get_all_moves().get_move(move_id).unwrap();
+
+                movement.charge.as_ref().map_or(false, |charge| charge.semi_invulnerable)
+            },
+            _ => false,
+        }
+    }
+
+    /// Returns the only move that a Pokémon can use, if it's locked into one
+    /// (e.g because of Encore or a Choice item).
+    pub fn get_locked_move(&self, pokemon: usize) -> Option<&String> {
+        let flags = &self.pokemon_flags[&pokemon].flags;
+
+        match (flags.get("encore"), flags.get("choice_lock")) {
+            (Some(Flag::Encore { move_id, .. }), _) => Some(move_id),
+            (_, Some(Flag::ChoiceLock(movement))) => Some(movement),
+            _ => None,
+        }
+    }
+
+    /// Checks if a Pokémon can't use a move because of Disable.
+    pub fn is_move_disabled(&self, pokemon: usize, move_id: &str) -> bool {
+        match self.pokemon_flags[&pokemon].flags.get("disabled") {
+            Some(Flag::Disabled { move_id: disabled_move, .. }) => disabled_move == move_id,
+            _ => false,
+        }
+    }
+
+    /// Returns the reason why a Pokémon can't choose the move at the given
+    /// index of its move list, if any. The frontend uses this to prevent the
+    /// player from choosing those moves.
+    pub fn get_move_restriction(
+        &self,
+        pokemon: usize,
+        move_index: usize,
+    ) -> Option<MoveRestriction> {
+        let move_id = self.get_pokemon(pokemon).moves[move_index].as_ref()?;
+
+        if self.get_pokemon(pokemon).pp[move_index] == 0 {
+            return Some(MoveRestriction::NoPp);
+        }
+
+        if self.is_move_disabled(pokemon, move_id) {
+            return Some(MoveRestriction::Disabled);
+        }
+
+        match self.get_locked_move(pokemon) {
+            Some(locked_move) if locked_move != move_id => {
+                Some(MoveRestriction::LockedIntoOtherMove)
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns every move used in this battle, oldest first.
+    pub fn get_move_history(&self) -> &[MoveHistoryEntry] {
+        &self.move_history
+    }
+
+    /// Returns the moves chosen by a Pokémon since it entered the
+    /// battlefield, oldest first.
+    pub fn get_used_moves(&self, pokemon: usize) -> &[String] {
+        match self.pokemon_flags[&pokemon].flags.get("used_moves") {
+            Some(Flag::UsedMoves(used_moves)) => used_moves,
+            _ => &[],
+        }
+    }
+
+    /// Returns the last move chosen by a Pokémon since it entered the
+    /// battlefield, if any.
+    pub fn get_last_used_move(&self, pokemon: usize) -> Option<&String> {
+        self.get_used_moves(pokemon).last()
+    }
+
+    /// Returns the index in the move list of the last move used by a
+    /// Pokémon, as long as it still knows it.
+    fn get_last_move_index(&self, pokemon: usize) -> Option<usize> {
+        let last_move = self.get_last_used_move(pokemon)?;
+
+        self.get_move_index(pokemon, last_move)
+    }
+
+    /// Returns the index of a move in the move list of a Pokémon, if it
+    /// knows that move.
+    fn get_move_index(&self, pokemon: usize, move_id: &str) -> Option<usize> {
+        self.get_pokemon(pokemon)
+            .moves
+            .iter()
+            .position(|movement| movement.as_deref() == Some(move_id))
+    }
+
+    /// Spends a PP of a move chosen by a Pokémon. Moves that the Pokémon
+    /// doesn't know (e.g Struggle) don't cost anything.
+    fn spend_pp(&mut self, user: usize, movement: &Move) {
+        if let Some(index) = self.get_move_index(user, &movement.id) {
+            let pp = &mut self.get_pokemon_mut(user).pp[index];
+            *pp = pp.saturating_sub(1);
+        }
+    }
+
+    /// Returns whether a Pokémon can't choose any of its moves, in which case
+    /// it uses Struggle instead.
+    pub fn must_use_struggle(&self, pokemon: usize) -> bool {
+        (0..MOVE_LIMIT)
+            .filter(|move_index| self.get_pokemon(pokemon).moves[*move_index].is_some())
+            .all(|move_index| self.get_move_restriction(pokemon, move_index).is_some())
+    }
+
+    /// Announces that the held item of a Pokémon is taking effect.
+    pub fn activate_item(&mut self, pokemon: usize) {
+        let item = self.get_held_item(pokemon).unwrap().to_string();
+
+        self.event_queue.push(BattleEvent::ItemActivated(event::ItemActivated {
+            pokemon,
+            item,
+        }));
+    }
+
+    /// Uses up the held item of a Pokémon, removing it.
+    pub fn consume_item(&mut self, pokemon: usize) {
+        let item = self.get_pokemon_mut(pokemon).held_item.take().unwrap();
+
+        self.event_queue.push(BattleEvent::ItemConsumed(event::ItemConsumed {
+            pokemon,
+            item,
+        }));
+
+        if self.has_ability(pokemon, "Unburden") {
+            self.add_flag(pokemon, Flag::Unburden);
+        }
+    }
+
+    /// Checks if a Pokémon can eat its berry, which is prevented by opponents
+    /// with Unnerve.
+    pub fn can_consume_berry(&self, pokemon: usize) -> bool {
+        let team = self.get_pokemon_team(pokemon);
+
+        !self
+            .get_active_positions()
+            .into_iter()
+            .filter(|position| position.team != team)
+            .filter_map(|position| self.get_pokemon_at(position))
+            .any(|foe| self.has_ability(foe, "Unnerve"))
+    }
+
+    /// Returns how the battle ended, or `None` if it's still going on.
+    pub fn get_outcome(&self) -> Option<&event::BattleEnd> {
+        self.outcome.as_ref()
+    }
+
+    fn get_team(&self, team: Team) -> &TeamData {
+        match team {
+            Team::P1 => &self.p1,
+            Team::P2 => &self.p2,
+        }
+    }
+
+    fn get_team_mut(&mut self, team: Team) -> &mut TeamData {
+        match team {
+            Team::P1 => &mut self.p1,
+            Team::P2 => &mut self.p2,
+        }
+    }
+
+    pub fn get_pokemon_team(&self, pokemon: usize) -> Team {
+        if let Some(position) = self.get_pokemon_position(pokemon) {
+            return position.team;
         }
 
         if self.p1.party.contains(&pokemon) {
@@ -959,11 +3903,18 @@ impl BattleBackend {
         condition: SimpleStatusCondition,
     ) -> bool {
         let target_is_not_immune = {
-            let can_affect = get_status_condition_effect(condition)
+            let can_affect = get_status_condition_effect(condition.clone())
                 .can_affect
                 .unwrap_or(|_, _| true);
 
-            can_affect(self, target)
+            let on_check_status_immunity =
+                self.get_active_ability_effect(target).on_check_status_immunity;
+            let has_immunity_ability = match on_check_status_immunity {
+                Some(is_immune) => is_immune(self, target, condition),
+                None => false,
+            };
+
+            can_affect(self, target) && !has_immunity_ability
         };
 
         !self.has_non_volatile_status_condition(target) && target_is_not_immune
@@ -1023,6 +3974,29 @@ impl BattleBackend {
                 result = effect(self, pokemon, stat, result);
             });
 
+        if let Some(effect) = self.get_active_ability_effect(pokemon).on_stat_calculation {
+            result = effect(self, pokemon, stat, result);
+        }
+
+        if let Some(effect) = self.get_held_item_effect(pokemon).on_stat_calculation {
+            result = effect(self, pokemon, stat, result);
+        }
+
+        if stat == Stat::Speed
+            && self.has_side_condition(self.get_pokemon_team(pokemon), SideCondition::Tailwind)
+        {
+            result *= 2;
+        }
+
+        // Rock-type Pokémon are harder to hurt with special moves during a
+        // sandstorm
+        if stat == Stat::SpecialDefense
+            && self.get_weather() == Some(Weather::Sandstorm)
+            && self.has_type(pokemon, PokemonType::Rock)
+        {
+            result = result * 3 / 2;
+        }
+
         result
     }
 
@@ -1070,11 +4044,7 @@ impl BattleBackend {
         let mov = used_move.movement;
 
         match mov.power_modifier {
-            Some(modifier) => {
-                let user = &self.pokemon_repository[&used_move.user];
-                let target = &self.pokemon_repository[&used_move.target];
-                modifier(user, target, mov)
-            },
+            Some(modifier) => modifier(self, used_move.user, used_move.target, mov),
             None => match mov.base_power {
                 MovePower::Constant(value) => value,
                 MovePower::Special => 0,
@@ -1090,30 +4060,31 @@ impl BattleBackend {
         effectiveness: f32,
         is_critical_hit: bool,
     ) -> usize {
-        let level = self.pokemon_repository[&used_move.user].level as f32;
-        let level_modifier = (2. * level) / 5. + 2.;
-        let power = self.get_move_power(&used_move) as f32;
-        let stat_ratio = (attack as f32) / (defense as f32);
+        let level = self.pokemon_repository[&used_move.user].level;
+        let power = self.get_move_power(&used_move);
 
         let modifier = {
-            let targets = 1.; // TODO: handle multi-target moves
-            let weather = 1.; // TODO
-            let critical = if is_critical_hit { 1.25 } else { 1. };
+            let targets = if used_move.has_multiple_targets { 0.75 } else { 1. };
+            let weather = self.get_weather_damage_modifier(used_move.movement.move_type);
+            let critical = match (is_critical_hit, self.has_ability(used_move.user, "Sniper")) {
+                (true, true) => 1.875,
+                (true, false) => 1.25,
+                (false, _) => 1.,
+            };
             let random = self.rng.get_damage_modifier();
             let stab = if self.check_stab(&used_move.movement, used_move.user) {
                 1.5
             } else {
                 1.
             };
-            let other = 1.; // TODO
+            let screen = self.get_screen_damage_modifier(used_move, is_critical_hit);
+            let ability = self.get_ability_damage_modifier(used_move);
+            let item = self.get_item_damage_modifier(used_move);
 
-            targets * weather * critical * random * stab * effectiveness * other
+            targets * weather * critical * random * stab * effectiveness * screen * ability * item
         };
 
-        let power_stat_ratio = (power * stat_ratio).floor();
-        let level_power_stat_ratio = ((level_modifier * power_stat_ratio) / 50.).floor();
-
-        let damage = (level_power_stat_ratio + 2.) * modifier;
+        let damage = get_base_damage(level, power, attack, defense) * modifier;
         let damage = damage as usize;
 
         if damage == 0 {
@@ -1123,18 +4094,94 @@ impl BattleBackend {
         }
     }
 
-    fn get_type_effectiveness(&self, mov: &Move, target: usize) -> f32 {
-        // TODO: handle moves without types (e.g Struggle)
+    /// Reflect and Light Screen weaken moves, unless they are critical hits.
+    /// The reduction is smaller when there's more than one active Pokémon
+    /// per team.
+    fn get_screen_damage_modifier(&self, used_move: &UsedMove, is_critical_hit: bool) -> f32 {
+        let screen = match used_move.movement.category {
+            MoveCategory::Physical => SideCondition::Reflect,
+            MoveCategory::Special => SideCondition::LightScreen,
+            MoveCategory::Status => return 1.,
+        };
+
+        let target_team = self.get_pokemon_team(used_move.target);
+
+        if is_critical_hit || !self.has_side_condition(target_team, screen) {
+            1.
+        } else if self.battle_type.get_active_slot_count() > 1 {
+            2. / 3.
+        } else {
+            0.5
+        }
+    }
+
+    fn get_ability_damage_modifier(&self, used_move: &UsedMove) -> f32 {
+        let UsedMove { user, target, movement, .. } = *used_move;
+
+        let dealt = match self.get_active_ability_effect(user).on_modify_damage_dealt {
+            Some(modifier) => modifier(self, user, target, movement),
+            None => 1.,
+        };
+
+        let taken = match self.get_active_ability_effect(target).on_modify_damage_taken {
+            Some(modifier) => modifier(self, target, user, movement),
+            None => 1.,
+        };
+
+        let target_position = self.get_pokemon_position(target).unwrap();
+        let friend_guards = self
+            .get_active_positions()
+            .into_iter()
+            .filter(|position| {
+                position.team == target_position.team
+                    && *position != target_position
+                    && self.are_adjacent(*position, target_position)
+            })
+            .filter_map(|position| self.get_pokemon_at(position))
+            .filter(|ally| self.has_ability(*ally, "FriendGuard"))
+            .count();
+
+        dealt * taken * 0.75f32.powi(friend_guards as i32)
+    }
+
+    fn get_item_damage_modifier(&self, used_move: &UsedMove) -> f32 {
+        let UsedMove { user, target, movement, .. } = *used_move;
+
+        let dealt = match self.get_held_item_effect(user).on_modify_damage_dealt {
+            Some(modifier) => modifier(self, user, target, movement),
+            None => 1.,
+        };
+
+        let taken = match self.get_held_item_effect(target).on_modify_damage_taken {
+            Some(modifier) => modifier(self, target, user, movement),
+            None => 1.,
+        };
+
+        dealt * taken
+    }
+
+    fn get_weather_damage_modifier(&self, move_type: PokemonType) -> f32 {
+        match (self.get_weather(), move_type) {
+            (Some(Weather::Rain), PokemonType::Water) => 1.5,
+            (Some(Weather::Rain), PokemonType::Fire) => 0.5,
+            (Some(Weather::Sun), PokemonType::Fire) => 1.5,
+            (Some(Weather::Sun), PokemonType::Water) => 0.5,
+            _ => 1.,
+        }
+    }
+
+    pub fn get_type_effectiveness(&self, mov: &Move, target: usize) -> f32 {
+        if mov.flags.contains(&MoveFlag::Typeless) {
+            return 1.;
+        }
 
         self.get_pokemon_current_types(target)
             .map(|t| PokemonType::get_effectiveness(mov.move_type, *t))
             .product()
     }
 
-    fn check_stab(&self, mov: &Move, user: usize) -> bool {
-        // TODO: handle moves without types (e.g Struggle)
-
-        self.has_type(user, mov.move_type)
+    pub fn check_stab(&self, mov: &Move, user: usize) -> bool {
+        !mov.flags.contains(&MoveFlag::Typeless) && self.has_type(user, mov.move_type)
     }
 
     pub fn has_type(&self, target: usize, tested_type: PokemonType) -> bool {
@@ -1144,17 +4191,35 @@ impl BattleBackend {
     }
 
     fn get_pokemon_current_types(&self, target: usize) -> impl Iterator<Item = &PokemonType> {
-        // TODO: handle Pokémon that currently have a different type than their
-        // original ones (e.g after Soak or Roost)
+        // TODO: handle Pokémon whose types were replaced (e.g after Soak)
+        let types = &self.get_species(target).types;
+
+        // Pokémon that used Roost lose their Flying type, with pure
+        // Flying-type Pokémon becoming Normal-type
+        let is_roosting = self.has_flag(target, "roost");
+        let is_pure_flying = types.iter().all(|t| *t == PokemonType::Flying);
 
-        self.get_species(target)
-            .types
+        types
             .iter()
+            .filter(move |t| !is_roosting || **t != PokemonType::Flying)
+            .chain(std::iter::once(&PokemonType::Normal).filter(move |_| {
+                is_roosting && is_pure_flying
+            }))
     }
 
     fn check_miss(&mut self, used_move: &UsedMove) -> bool {
         let mov = used_move.movement;
 
+        if self.has_ability(used_move.user, "NoGuard")
+            || self.has_ability(used_move.target, "NoGuard")
+        {
+            return false;
+        }
+
+        if used_move.user != used_move.target && self.is_semi_invulnerable(used_move.target) {
+            return true;
+        }
+
         let mut accuracy = match mov.accuracy_modifier {
             Some(modifier) => {
                 modifier(self, used_move.user, used_move.target, mov)
@@ -1178,14 +4243,31 @@ impl BattleBackend {
 
                 let adjusted_stages = {
                     let user_accuracy = self.get_stat_stage(used_move.user, Stat::Accuracy);
-                    let target_evasion = self.get_stat_stage(used_move.target, Stat::Evasion);
+                    let mut target_evasion = self.get_stat_stage(used_move.target, Stat::Evasion);
+
+                    // Keen Eye ignores evasion boosts
+                    if self.has_ability(used_move.user, "KeenEye") {
+                        target_evasion = target_evasion.min(0);
+                    }
 
                     self.get_accuracy_multiplier(user_accuracy - target_evasion)
                 };
 
-                let chance = accuracy * adjusted_stages;
+                let mut chance = accuracy * adjusted_stages;
 
-                self.rng.check_miss(chance as usize)
+                let user_ability = self.get_active_ability_effect(used_move.user);
+                let target_ability = self.get_active_ability_effect(used_move.target);
+
+                if let Some(modifier) = user_ability.on_accuracy_calculation {
+                    chance = modifier(self, used_move.user, used_move.target, mov, chance);
+                }
+
+                if let Some(modifier) = target_ability.on_evasion_calculation {
+                    chance = modifier(self, used_move.target, used_move.user, mov, chance);
+                }
+
+                // Boosted moves can't be more than certain to hit
+                self.rng.check_miss(chance.min(100.) as usize)
             },
         }
     }
@@ -1198,7 +4280,93 @@ impl BattleBackend {
         self.rng.check_freeze_thaw()
     }
 
+    pub fn check_ability_activation(&mut self, chance: usize) -> bool {
+        self.rng.check_ability_activation(chance)
+    }
+
+    pub fn get_effect_spore_condition(&mut self) -> SimpleStatusCondition {
+        self.rng.get_effect_spore_condition()
+    }
+
+    pub fn get_sleep_duration(&mut self) -> usize {
+        self.rng.get_sleep_duration()
+    }
+
     fn is_fainted(&self, pokemon: usize) -> bool {
         self.get_pokemon(pokemon).current_hp == 0
     }
 }
+
+/// Checks if a move calls other moves, e.g Metronome.
+fn is_calling_move(movement: &Move) -> bool {
+    match movement.primary_effect {
+        Some(SimpleEffect::CallMove(_)) => true,
+        _ => false,
+    }
+}
+
+/// Checks if a substitute stops an effect from reaching the Pokémon behind
+/// it. Effects that only concern the user of the move still apply.
+fn is_blocked_by_substitute(effect: &SimpleEffect) -> bool {
+    match effect {
+        SimpleEffect::Confusion
+        | SimpleEffect::Flinch
+        | SimpleEffect::StatusCondition(_)
+        | SimpleEffect::Trap
+        | SimpleEffect::LeechSeed
+        | SimpleEffect::Yawn => true,
+        SimpleEffect::StatChange { target, .. } => match target {
+            SimpleEffectTarget::MoveTarget => true,
+            SimpleEffectTarget::MoveUser => false,
+        },
+        _ => false,
+    }
+}
+
+/// Checks if a flag is handed over to the replacement of a Pokémon that
+/// uses Baton Pass.
+fn is_passed_by_baton_pass(flag_id: &str) -> bool {
+    match flag_id {
+        "confusion" | "curse" | "leech_seed" | "perish_count" | "stat_stages" | "substitute" => {
+            true
+        },
+        _ => false,
+    }
+}
+
+/// Checks if a move can be called by moves like Metronome. Struggle and
+/// moves that call other moves, protect the user or need to be charged
+/// can't.
+fn can_be_called(movement: &Move) -> bool {
+    let is_protection_move = match movement.primary_effect {
+        Some(SimpleEffect::Protection(_)) => true,
+        _ => false,
+    };
+
+    movement.id != "Struggle"
+        && !is_calling_move(movement)
+        && !is_protection_move
+        && movement.charge.is_none()
+}
+
+/// Returns the damage dealt by a move before any modifier is applied.
+fn get_base_damage(level: usize, power: usize, attack: usize, defense: usize) -> f32 {
+    let level_modifier = (2. * level as f32) / 5. + 2.;
+    let stat_ratio = (attack as f32) / (defense as f32);
+    let power_stat_ratio = (power as f32 * stat_ratio).floor();
+    let level_power_stat_ratio = ((level_modifier * power_stat_ratio) / 50.).floor();
+
+    level_power_stat_ratio + 2.
+}
+
+/// Returns the catch rate multiplier of a Pokéball, or `None` if the item
+/// isn't a known Pokéball.
+fn get_pokeball_modifier(pokeball: &str) -> Option<f64> {
+    match pokeball {
+        "PokeBall" => Some(1.),
+        "GreatBall" => Some(1.5),
+        "UltraBall" => Some(2.),
+        "MasterBall" => Some(255.),
+        _ => None,
+    }
+}
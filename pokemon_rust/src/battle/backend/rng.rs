@@ -1,12 +1,18 @@
 use rand::{
     seq::SliceRandom,
     thread_rng,
-    distributions::{Uniform, Distribution}
+    distributions::{Uniform, Distribution},
+    Error,
+    RngCore,
 };
 
+use serde::{Deserialize, Serialize};
+
 use std::{any::Any, fmt::Debug};
 
-use super::UsedMove;
+use crate::pokemon::SimpleStatusCondition;
+
+use super::PendingMove;
 
 pub trait Downcast: Any {
     fn as_any(&self) -> &dyn Any;
@@ -32,7 +38,7 @@ pub trait BattleRng: Debug + Downcast {
 
     /// Shuffles a list of moves. This ensures random move order if both the
     /// priority and speed are equal.
-    fn shuffle_moves<'a>(&mut self, moves: &mut Vec<UsedMove<'a>>);
+    fn shuffle_moves<'a>(&mut self, moves: &mut Vec<PendingMove<'a>>);
 
     /// Picks a number r in the range [1, 100] and returns r <= 100 - accuracy.
     fn check_miss(&mut self, accuracy: usize) -> bool;
@@ -51,34 +57,74 @@ pub trait BattleRng: Debug + Downcast {
     /// Returns the number of turns that a confusion will last.
     fn get_confusion_duration(&mut self) -> usize;
 
-    /// Tests for a confusion miss (50% chance).
-    fn check_confusion_miss(&mut self) -> bool;
+    /// Returns the number of turns that a move like Thrash lasts.
+    fn get_rampage_duration(&mut self) -> usize;
+
+    /// Returns the number of turns that a move like Wrap traps its target.
+    fn get_trap_duration(&mut self) -> usize;
+
+    /// Returns the number of turns that a Pokémon put to sleep keeps failing
+    /// to move.
+    fn get_sleep_duration(&mut self) -> usize;
+
+    /// Tests for a confused Pokémon hurting itself (50% chance).
+    fn check_confusion_self_hit(&mut self) -> bool;
 
     /// Tests for a paralysis move prevention (25% chance).
     fn check_paralysis_move_prevention(&mut self) -> bool;
 
     /// Tests for a freeze thawing (20% chance).
     fn check_freeze_thaw(&mut self) -> bool;
+
+    /// Picks a number r in the range [0, 255] and returns r < odds.
+    fn check_escape(&mut self, odds: usize) -> bool;
+
+    /// Performs one of the shake checks of a Pokéball. Picks a number r in
+    /// the range [0, 65535] and returns r < odds.
+    fn check_capture_shake(&mut self, odds: usize) -> bool;
+
+    /// Picks a number r in the range [1, 100] and returns r <= chance. Used
+    /// by abilities that only activate sometimes, e.g Static.
+    fn check_ability_activation(&mut self, chance: usize) -> bool;
+
+    /// Picks the status condition inflicted by Effect Spore.
+    fn get_effect_spore_condition(&mut self) -> SimpleStatusCondition;
+
+    /// Tests whether a protection move succeeds after `consecutive_uses`
+    /// successful uses in a row, with 1 / 2^consecutive_uses odds.
+    fn check_protection_success(&mut self, consecutive_uses: usize) -> bool;
+
+    /// Picks the move called by Metronome, returning its index in the list
+    /// of candidates.
+    fn get_metronome_move(&mut self, candidates: &[&str]) -> usize;
+
+    /// Picks the action of an AI that acts randomly, returning its index in
+    /// the list of `choice_count` actions it can take.
+    fn get_random_ai_choice(&mut self, choice_count: usize) -> usize;
 }
 
-#[derive(Clone, Debug, Default)]
-pub struct StandardBattleRng;
+/// The random number generation that the BattleRngs which aren't meant for
+/// tests are built upon. Every RandomSource is a BattleRng.
+pub trait RandomSource {
+    /// Returns a number r in the range [lowest, highest].
+    fn rand(&mut self, lowest: isize, highest: isize) -> isize;
 
-impl StandardBattleRng {
-    fn rand(&mut self, lowest: isize, highest: isize) -> isize {
-        Uniform::new(lowest, highest + 1).sample(&mut thread_rng())
-    }
+    /// Returns a number r in the range [lowest, highest].
+    fn rand_unsigned(&mut self, lowest: usize, highest: usize) -> usize;
 
-    fn rand_unsigned(&mut self, lowest: usize, highest: usize) -> usize {
-        Uniform::new(lowest, highest + 1).sample(&mut thread_rng())
-    }
+    /// Shuffles a list of values.
+    fn shuffle<T>(&mut self, values: &mut [T]);
 
+    /// Picks a number r in the range [1, 100] and returns r <= chance.
     fn roll(&mut self, chance: usize) -> bool {
         self.rand(1, 100) <= chance as isize
     }
 }
 
-impl BattleRng for StandardBattleRng {
+impl<R> BattleRng for R
+where
+    R: RandomSource + Clone + Debug + Sync + Send + 'static,
+{
     fn boxed_clone(&self) -> Box<dyn BattleRng + Sync + Send> {
         Box::new(self.clone())
     }
@@ -87,12 +133,12 @@ impl BattleRng for StandardBattleRng {
         self.rand(85, 100) as f32 / 100.
     }
 
-    fn shuffle_moves<'a>(&mut self, moves: &mut Vec<UsedMove<'a>>) {
-        moves.shuffle(&mut thread_rng());
+    fn shuffle_moves<'a>(&mut self, moves: &mut Vec<PendingMove<'a>>) {
+        self.shuffle(moves);
     }
 
     fn check_miss(&mut self, accuracy: usize) -> bool {
-        self.roll(100 - accuracy)
+        self.roll(100_usize.saturating_sub(accuracy))
     }
 
     fn check_secondary_effect(&mut self, chance: usize) -> bool {
@@ -111,7 +157,19 @@ impl BattleRng for StandardBattleRng {
         self.rand_unsigned(1, 4)
     }
 
-    fn check_confusion_miss(&mut self) -> bool {
+    fn get_rampage_duration(&mut self) -> usize {
+        self.rand_unsigned(2, 3)
+    }
+
+    fn get_trap_duration(&mut self) -> usize {
+        self.rand_unsigned(4, 5)
+    }
+
+    fn get_sleep_duration(&mut self) -> usize {
+        self.rand_unsigned(1, 3)
+    }
+
+    fn check_confusion_self_hit(&mut self) -> bool {
         self.roll(50)
     }
 
@@ -122,4 +180,123 @@ impl BattleRng for StandardBattleRng {
     fn check_freeze_thaw(&mut self) -> bool {
         self.roll(20)
     }
+
+    fn check_escape(&mut self, odds: usize) -> bool {
+        self.rand_unsigned(0, 255) < odds
+    }
+
+    fn check_capture_shake(&mut self, odds: usize) -> bool {
+        self.rand_unsigned(0, 65535) < odds
+    }
+
+    fn check_ability_activation(&mut self, chance: usize) -> bool {
+        self.roll(chance)
+    }
+
+    fn get_effect_spore_condition(&mut self) -> SimpleStatusCondition {
+        match self.rand_unsigned(0, 2) {
+            0 => SimpleStatusCondition::Poison,
+            1 => SimpleStatusCondition::Paralysis,
+            _ => SimpleStatusCondition::Sleep,
+        }
+    }
+
+    fn check_protection_success(&mut self, consecutive_uses: usize) -> bool {
+        let odds = 1 << consecutive_uses.min(16);
+
+        self.rand_unsigned(1, odds) == 1
+    }
+
+    fn get_metronome_move(&mut self, candidates: &[&str]) -> usize {
+        self.rand_unsigned(0, candidates.len() - 1)
+    }
+
+    fn get_random_ai_choice(&mut self, choice_count: usize) -> usize {
+        self.rand_unsigned(0, choice_count - 1)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct StandardBattleRng;
+
+impl RandomSource for StandardBattleRng {
+    fn rand(&mut self, lowest: isize, highest: isize) -> isize {
+        Uniform::new(lowest, highest + 1).sample(&mut thread_rng())
+    }
+
+    fn rand_unsigned(&mut self, lowest: usize, highest: usize) -> usize {
+        Uniform::new(lowest, highest + 1).sample(&mut thread_rng())
+    }
+
+    fn shuffle<T>(&mut self, values: &mut [T]) {
+        values.shuffle(&mut thread_rng());
+    }
+}
+
+/// A reproducible BattleRng: two battles whose RNGs were created from the same
+/// seed play out the same way, given the same actions. Its state can be
+/// serialized, e.g to be attached to a bug report.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SeededBattleRng {
+    seed: u64,
+    state: u64,
+}
+
+impl SeededBattleRng {
+    pub fn new(seed: u64) -> SeededBattleRng {
+        SeededBattleRng { seed, state: seed }
+    }
+
+    /// Creates a generator from a random seed.
+    pub fn from_random_seed() -> SeededBattleRng {
+        SeededBattleRng::new(thread_rng().next_u64())
+    }
+
+    /// Returns the seed that this generator was created from.
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+/// Numbers are generated using SplitMix64, which only needs a single `u64`
+/// of state.
+impl RngCore for SeededBattleRng {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        value ^ (value >> 31)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl RandomSource for SeededBattleRng {
+    fn rand(&mut self, lowest: isize, highest: isize) -> isize {
+        Uniform::new(lowest, highest + 1).sample(self)
+    }
+
+    fn rand_unsigned(&mut self, lowest: usize, highest: usize) -> usize {
+        Uniform::new(lowest, highest + 1).sample(self)
+    }
+
+    fn shuffle<T>(&mut self, values: &mut [T]) {
+        values.shuffle(self);
+    }
 }
@@ -7,8 +7,14 @@ use amethyst::{
 
 use crate::{
     audio::AudioSystem,
-    battle::frontend::BattleSystem,
+    battle::{
+        frontend::BattleSystem,
+        types::{Battle, BattleType, Party},
+    },
     constants::{BATTLE_CAMERA_POSITION, WINDOW_HEIGHT, WINDOW_WIDTH},
+    map::MapHandler,
+    overworld::entities::character::PlayerEntity,
+    pokemon::Pokemon,
 };
 
 use std::ops::Deref;
@@ -24,6 +30,47 @@ pub fn initialise_camera(world: &mut World) -> Entity {
         .build()
 }
 
+/// Replaces the party of the player by its state at the end of the battle,
+/// so that HP and PP spent in the battle stay spent.
+fn persist_player_party(world: &mut World, party: Party) {
+    let player_entity = world.read_resource::<PlayerEntity>().0;
+
+    world.register::<Party>();
+    world
+        .write_storage::<Party>()
+        .insert(player_entity, party)
+        .expect("Failed to attach Party");
+}
+
+/// Hands a Pokémon caught during the battle to the player, filling in the
+/// location where it was met.
+fn add_caught_pokemon_to_party(world: &mut World, mut pokemon: Pokemon) {
+    let player_entity = world.read_resource::<PlayerEntity>().0;
+
+    pokemon.met_at_location = {
+        let map_handler = world.read_resource::<MapHandler>();
+        let player_id = map_handler.get_character_id_by_entity(player_entity);
+
+        map_handler
+            .get_character_current_map(player_id)
+            .as_str()
+            .to_string()
+    };
+
+    world.register::<Party>();
+    let mut parties = world.write_storage::<Party>();
+
+    if let Some(party) = parties.get_mut(player_entity) {
+        party.pokemon.push_back(pokemon);
+    } else {
+        parties
+            .insert(player_entity, Party {
+                pokemon: vec![pokemon].into(),
+            })
+            .expect("Failed to attach Party");
+    }
+}
+
 /// The state that the game reaches when the player enters a battle.
 /// This state makes some assumptions about the state of the World:
 ///   * A resource representing the battle exists (`Battle`);
@@ -34,6 +81,8 @@ pub fn initialise_camera(world: &mut World) -> Entity {
 #[derive(Default)]
 pub struct BattleState<'a, 'b> {
     pub dispatcher: Option<Dispatcher<'a, 'b>>,
+    camera: Option<Entity>,
+    previous_camera: Option<Entity>,
 }
 
 impl SimpleState for BattleState<'_, '_> {
@@ -52,12 +101,45 @@ impl SimpleState for BattleState<'_, '_> {
         self.dispatcher = Some(dispatcher);
 
         let camera = initialise_camera(world);
-        world.write_resource::<ActiveCamera>().entity = Some(camera);
+        let mut active_camera = world.write_resource::<ActiveCamera>();
+        self.previous_camera = active_camera.entity.replace(camera);
+        self.camera = Some(camera);
+    }
+
+    fn on_stop(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        let world = data.world;
+
+        if let Some(camera) = self.camera.take() {
+            world
+                .delete_entity(camera)
+                .expect("Failed to delete battle camera");
+        }
+
+        world.write_resource::<ActiveCamera>().entity = self.previous_camera.take();
+
+        if let Some(battle) = world.remove::<Battle>() {
+            persist_player_party(world, battle.p1.party);
+
+            if let Some(pokemon) = battle.caught_pokemon {
+                add_caught_pokemon_to_party(world, pokemon);
+            }
+        }
     }
 
     fn update(&mut self, data: &mut StateData<'_, GameData<'_, '_>>) -> SimpleTrans {
         let StateData { world, .. } = data;
 
+        // The frontend can only gather the actions of a single Pokémon per
+        // team, so other battles are ended before they start
+        if let BattleType::Double = world.read_resource::<Battle>().battle_type {
+            eprintln!("Failed to start the battle: double battles are not supported yet");
+            return Trans::Pop;
+        }
+
+        if world.read_resource::<Battle>().outcome.is_some() {
+            return Trans::Pop;
+        }
+
         if let Some(dispatcher) = &mut self.dispatcher {
             dispatcher.dispatch(world);
         }
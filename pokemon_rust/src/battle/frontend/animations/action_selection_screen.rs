@@ -2,7 +2,13 @@ use amethyst::input::{InputEvent, StringBindings};
 
 use crate::{
     audio::Sound,
-    battle::backend::BattleBackend,
+    battle::backend::{
+        BattleBackend,
+        FieldPosition,
+        FrontendEvent,
+        FrontendEventKind,
+        Team,
+    },
     constants::AXIS_SENSITIVITY,
 };
 
@@ -12,7 +18,12 @@ use super::{MoveSelectionScreen, SelectionScreen};
 
 pub enum ActionSelectionScreen {
     PendingStart,
-    Started { selection_screen: SelectionScreen },
+    Started {
+        selection_screen: SelectionScreen,
+        /// Whether the active Pokémon has no move to choose from, in which
+        /// case the fight option uses Struggle right away.
+        must_use_struggle: bool,
+    },
 }
 
 impl ActionSelectionScreen {
@@ -21,6 +32,7 @@ impl ActionSelectionScreen {
             match selection_screen.get_focused_option() {
                 0 => self.select_fight_option(system_data),
                 1 => self.select_run_option(system_data),
+                2 => self.select_pokeball_option(system_data),
                 _ => unreachable!(),
             }
         } else {
@@ -29,36 +41,80 @@ impl ActionSelectionScreen {
     }
 
     fn select_fight_option(&mut self, system_data: &mut BattleSystemData) -> TickResult {
-        if let Self::Started { selection_screen } = self {
+        if let Self::Started {
+            selection_screen,
+            must_use_struggle,
+        } = self {
             selection_screen.remove(system_data);
+
+            if *must_use_struggle {
+                // The move index is ignored by the backend
+                return TickResult::emit(FrontendEvent {
+                    team: Team::P1,
+                    slot: 0,
+                    event: FrontendEventKind::UseMove {
+                        move_index: 0,
+                        target: None,
+                    },
+                });
+            }
         }
 
         TickResult::replace_by(vec![Box::new(MoveSelectionScreen::PendingStart)])
     }
 
-    fn select_run_option(&mut self, _system_data: &mut BattleSystemData) -> TickResult {
-        // TODO
-        println!("Selected option: run");
+    fn select_run_option(&mut self, system_data: &mut BattleSystemData) -> TickResult {
+        if let Self::Started { selection_screen, .. } = self {
+            selection_screen.remove(system_data);
+        }
 
-        TickResult::Incomplete
+        TickResult::emit(FrontendEvent {
+            team: Team::P1,
+            slot: 0,
+            event: FrontendEventKind::Run,
+        })
+    }
+
+    fn select_pokeball_option(&mut self, system_data: &mut BattleSystemData) -> TickResult {
+        if let Self::Started { selection_screen, .. } = self {
+            selection_screen.remove(system_data);
+        }
+
+        // TODO: let the player pick a Pokéball from the bag once items exist
+        TickResult::emit(FrontendEvent {
+            team: Team::P1,
+            slot: 0,
+            event: FrontendEventKind::ThrowPokeball("PokeBall".to_string()),
+        })
     }
 }
 
 impl FrontendAnimation for ActionSelectionScreen {
     fn start(
         &mut self,
-        _backend: &BattleBackend,
+        backend: &BattleBackend,
         system_data: &mut BattleSystemData,
     ) {
+        let mut buttons = vec![
+            system_data.resources.fight_button.clone(),
+            system_data.resources.run_button.clone(),
+        ];
+
+        if backend.can_capture(Team::P1) {
+            // TODO: use a dedicated sprite for the Pokéball option
+            buttons.push(system_data.resources.fight_button.clone());
+        }
+
+        let user = backend
+            .get_pokemon_at(FieldPosition {
+                team: Team::P1,
+                slot: 0,
+            })
+            .unwrap();
+
         *self = ActionSelectionScreen::Started {
-            selection_screen: SelectionScreen::new(
-                160.,
-                vec![
-                    system_data.resources.fight_button.clone(),
-                    system_data.resources.run_button.clone(),
-                ],
-                system_data,
-            ),
+            selection_screen: SelectionScreen::new(160., buttons, system_data),
+            must_use_struggle: backend.must_use_struggle(user),
         };
     }
 
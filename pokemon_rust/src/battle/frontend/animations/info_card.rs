@@ -146,6 +146,16 @@ impl InfoCard {
                 .text = content;
         }
     }
+
+    pub fn heal(
+        &mut self,
+        amount: usize,
+        pokemon: &Pokemon,
+        system_data: &mut BattleSystemData,
+    ) {
+        // The health bar always reflects the current HP of the Pokémon
+        self.damage(amount, pokemon, system_data);
+    }
 }
 
 impl InfoCard {
@@ -2,6 +2,7 @@ mod action_selection_screen;
 mod info_card;
 mod initial_switch_in_animation;
 mod move_selection_screen;
+mod party_selection_screen;
 mod selection_screen;
 mod text_animation;
 
@@ -9,5 +10,6 @@ pub use action_selection_screen::ActionSelectionScreen;
 pub use info_card::InfoCard;
 pub use initial_switch_in_animation::InitialSwitchInAnimation;
 pub use move_selection_screen::MoveSelectionScreen;
+pub use party_selection_screen::PartySelectionScreen;
 pub use selection_screen::SelectionScreen;
 pub use text_animation::TextAnimation;
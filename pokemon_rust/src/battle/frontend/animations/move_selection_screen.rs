@@ -4,11 +4,12 @@ use crate::{
     audio::Sound,
     battle::backend::{
         BattleBackend,
+        FieldPosition,
         FrontendEvent,
         FrontendEventKind,
         Team,
     },
-    constants::AXIS_SENSITIVITY,
+    constants::{AXIS_SENSITIVITY, MOVE_LIMIT},
 };
 
 use super::super::{BattleSystemData, FrontendAnimation, TickResult};
@@ -17,20 +18,36 @@ use super::SelectionScreen;
 
 pub enum MoveSelectionScreen {
     PendingStart,
-    Started { selection_screen: SelectionScreen },
+    Started {
+        selection_screen: SelectionScreen,
+        /// Whether each move can be chosen, e.g moves disabled by Disable
+        /// can't.
+        selectable_options: Vec<bool>,
+    },
 }
 
 impl MoveSelectionScreen {
     fn select_option(&mut self, system_data: &mut BattleSystemData) -> TickResult {
         if let Self::Started {
-            selection_screen, ..
+            selection_screen,
+            selectable_options,
         } = self {
             let move_index = selection_screen.get_focused_option();
+
+            if !selectable_options[usize::from(move_index)] {
+                return TickResult::Incomplete;
+            }
+
             selection_screen.remove(system_data);
 
+            // TODO: let the player choose the target in double battles
             TickResult::emit(FrontendEvent {
                 team: Team::P1,
-                event: FrontendEventKind::UseMove(move_index.into()),
+                slot: 0,
+                event: FrontendEventKind::UseMove {
+                    move_index: move_index.into(),
+                    target: None,
+                },
             })
         } else {
             TickResult::Incomplete
@@ -41,20 +58,40 @@ impl MoveSelectionScreen {
 impl FrontendAnimation for MoveSelectionScreen {
     fn start(
         &mut self,
-        _backend: &BattleBackend,
+        backend: &BattleBackend,
         system_data: &mut BattleSystemData,
     ) {
+        let user = backend
+            .get_pokemon_at(FieldPosition {
+                team: Team::P1,
+                slot: 0,
+            })
+            .unwrap();
+
+        let selectable_options: Vec<_> = (0..MOVE_LIMIT)
+            .map(|move_index| backend.get_move_restriction(user, move_index).is_none())
+            .collect();
+
+        let selection_screen = SelectionScreen::new(
+            160.,
+            vec![
+                system_data.resources.fight_button.clone(),
+                system_data.resources.fight_button.clone(),
+                system_data.resources.fight_button.clone(),
+                system_data.resources.fight_button.clone(),
+            ],
+            system_data,
+        );
+
+        for (index, is_selectable) in selectable_options.iter().enumerate() {
+            if !is_selectable {
+                selection_screen.grey_out_option(index, system_data);
+            }
+        }
+
         *self = MoveSelectionScreen::Started {
-            selection_screen: SelectionScreen::new(
-                160.,
-                vec![
-                    system_data.resources.fight_button.clone(),
-                    system_data.resources.fight_button.clone(),
-                    system_data.resources.fight_button.clone(),
-                    system_data.resources.fight_button.clone(),
-                ],
-                system_data,
-            ),
+            selection_screen,
+            selectable_options,
         };
     }
 
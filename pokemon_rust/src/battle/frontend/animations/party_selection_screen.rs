@@ -0,0 +1,117 @@
+use amethyst::input::{InputEvent, StringBindings};
+
+use crate::{
+    audio::Sound,
+    battle::backend::{
+        BattleBackend,
+        FrontendEvent,
+        FrontendEventKind,
+        Team,
+    },
+    constants::AXIS_SENSITIVITY,
+};
+
+use super::super::{BattleSystemData, FrontendAnimation, TickResult};
+
+use super::SelectionScreen;
+
+/// Lets the player choose which Pokémon of their party should be sent in.
+/// Only Pokémon that can be sent in are listed.
+pub enum PartySelectionScreen {
+    PendingStart,
+    Started {
+        selection_screen: SelectionScreen,
+        party_indices: Vec<usize>,
+        /// The empty active slot that the chosen Pokémon will occupy.
+        slot: usize,
+    },
+}
+
+impl PartySelectionScreen {
+    fn select_option(&mut self, system_data: &mut BattleSystemData) -> TickResult {
+        if let Self::Started {
+            selection_screen,
+            party_indices,
+            slot,
+        } = self {
+            let party_index = party_indices[selection_screen.get_focused_option() as usize];
+            selection_screen.remove(system_data);
+
+            TickResult::emit(FrontendEvent {
+                team: Team::P1,
+                slot: *slot,
+                event: FrontendEventKind::Switch(party_index),
+            })
+        } else {
+            TickResult::Incomplete
+        }
+    }
+}
+
+impl FrontendAnimation for PartySelectionScreen {
+    fn start(
+        &mut self,
+        backend: &BattleBackend,
+        system_data: &mut BattleSystemData,
+    ) {
+        let party_indices: Vec<usize> = backend
+            .get_party(Team::P1)
+            .enumerate()
+            .map(|(index, _)| index)
+            .filter(|index| backend.can_switch_in(Team::P1, *index))
+            .collect();
+
+        let buttons = party_indices
+            .iter()
+            .map(|_| system_data.resources.fight_button.clone())
+            .collect();
+
+        // TODO: let the player fill every empty slot in double battles
+        let slot = backend.get_empty_slots(Team::P1).next().unwrap();
+
+        *self = PartySelectionScreen::Started {
+            selection_screen: SelectionScreen::new(160., buttons, system_data),
+            party_indices,
+            slot,
+        };
+    }
+
+    fn tick(
+        &mut self,
+        input_events: Vec<InputEvent<StringBindings>>,
+        _backend: &BattleBackend,
+        system_data: &mut BattleSystemData,
+    ) -> TickResult {
+        for event in input_events {
+            let BattleSystemData { sound_kit, .. } = system_data;
+
+            if let Self::Started {
+                selection_screen, ..
+            } = self {
+                match event {
+                    InputEvent::ActionPressed(action) if action == "action" => {
+                        sound_kit.play_sound(Sound::SelectOption);
+                        return self.select_option(system_data);
+                    },
+                    InputEvent::AxisMoved { axis, value } if axis == "vertical" => {
+                        let offset = if value < -AXIS_SENSITIVITY {
+                            1
+                        } else if value > AXIS_SENSITIVITY {
+                            -1
+                        } else {
+                            return TickResult::Incomplete;
+                        };
+
+                        sound_kit.play_sound(Sound::SelectOption);
+                        selection_screen.move_selection(offset, system_data);
+                    },
+                    _ => {},
+                }
+            } else {
+                panic!("Called tick() before start()");
+            }
+        }
+
+        TickResult::Incomplete
+    }
+}
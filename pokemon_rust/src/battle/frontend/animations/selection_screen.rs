@@ -1,7 +1,7 @@
 use amethyst::{
     assets::Handle,
     ecs::Entity,
-    renderer::{SpriteRender, SpriteSheet},
+    renderer::{palette::Srgba, resources::Tint, SpriteRender, SpriteSheet},
     ui::{Anchor, UiImage, UiTransform},
 };
 
@@ -54,6 +54,14 @@ impl SelectionScreen {
         self.update_selection_arrow(system_data);
     }
 
+    /// Dims one of the buttons to show that it can't be chosen.
+    pub fn grey_out_option(&self, option_index: usize, system_data: &mut BattleSystemData) {
+        system_data
+            .tints
+            .insert(self.button_entities[option_index], Tint(Srgba::new(0.5, 0.5, 0.5, 1.0)))
+            .expect("Failed to insert Tint");
+    }
+
     pub fn remove(&mut self, system_data: &mut BattleSystemData) {
         let entities = &system_data.entities;
         entities
@@ -6,6 +6,7 @@ use amethyst::{
     core::{Time, Transform},
     ecs::{
         Entities,
+        Join,
         Read,
         ReadExpect,
         ReaderId,
@@ -21,40 +22,84 @@ use amethyst::{
     shred::ResourceId,
     shrev::EventChannel,
     ui::{UiImage, UiText, UiTransform},
+    utils::application_root_dir,
 };
 
 use crate::{
-    audio::SoundKit,
+    audio::{Sound, SoundKit},
     battle::{
+        ai::BattleAi,
         backend::{
             event::{
+                AbilityActivated,
+                BattleEnd,
+                CaptureAttempt,
+                CaptureRefused,
+                Charging,
                 Damage,
+                DestinyBond,
+                DisabledMove,
+                EscapeFailed,
+                Escaped,
                 ExpiredNonVolatileStatusCondition,
                 ExpiredVolatileStatusCondition,
                 Faint,
+                Heal,
                 InitialSwitchIn,
+                InvalidPokeball,
+                ItemActivated,
+                ItemConsumed,
                 Miss,
+                NoPpLeft,
                 NonVolatileStatusCondition,
+                PerishCount,
+                PpReduction,
+                Protected,
+                Recharging,
+                SideConditionEnd,
+                SideConditionStart,
                 StatChange,
+                StatStagesCopied,
+                StatStagesReset,
+                SubstituteBroken,
+                SubstituteCreated,
+                SubstituteDamaged,
+                SwitchIn,
+                SwitchOut,
                 UseMove,
                 VolatileStatusCondition,
+                WeatherDamage,
+                WeatherEnd,
+                WeatherStart,
             },
-            rng::StandardBattleRng,
+            rng::SeededBattleRng,
             BattleBackend,
+            BattleEndReason,
             BattleEvent,
+            CaptureRefusalCause,
             DamageCause,
+            EscapeFailureCause,
+            FieldPosition,
             Flag,
             FrontendEvent,
+            FrontendEventKind,
+            Protection,
+            SideCondition,
             StatChangeKind,
             Team,
             TypeEffectiveness,
+            Weather,
         },
+        replay::BattleRecorder,
         types::Battle,
     },
     common::CommonResources,
     config::GameConfig,
     pokemon::{
+        get_ability_display_name,
+        get_all_moves,
         get_all_pokemon_species,
+        get_item_display_name,
         get_pokemon_display_name,
         SimpleStatusCondition,
         Stat,
@@ -63,7 +108,13 @@ use crate::{
     text::TextBox,
 };
 
-use self::animations::{ActionSelectionScreen, InfoCard, InitialSwitchInAnimation, TextAnimation};
+use self::animations::{
+    ActionSelectionScreen,
+    InfoCard,
+    InitialSwitchInAnimation,
+    PartySelectionScreen,
+    TextAnimation,
+};
 
 use std::collections::VecDeque;
 
@@ -97,7 +148,8 @@ pub struct BattleSystemData<'a> {
 /// sending signals to the backend whenever an action is taken.
 pub struct BattleSystem {
     event_reader: ReaderId<InputEvent<StringBindings>>,
-    backend: Option<BattleBackend>,
+    backend: Option<BattleRecorder>,
+    opponent_ai: Option<Box<dyn BattleAi + Sync + Send>>,
     event_queue: VecDeque<BattleEvent>,
     active_animation_sequence: Option<AnimationSequence>,
     p1_info_card: Option<InfoCard>,
@@ -161,6 +213,7 @@ impl BattleSystem {
                 .write_resource::<EventChannel<InputEvent<StringBindings>>>()
                 .register_reader(),
             backend: None,
+            opponent_ai: None,
             event_queue: VecDeque::new(),
             active_animation_sequence: None,
             p1_info_card: None,
@@ -176,6 +229,12 @@ impl BattleSystem {
             BattleEvent::InitialSwitchIn(event_data) => {
                 self.handle_initial_switch_in(event_data);
             },
+            BattleEvent::SwitchOut(event_data) => {
+                self.handle_switch_out(event_data, system_data);
+            },
+            BattleEvent::SwitchIn(event_data) => {
+                self.handle_switch_in(event_data, system_data);
+            },
             BattleEvent::ChangeTurn(_) => { },
             BattleEvent::UseMove(event_data) => {
                 self.handle_use_move(event_data);
@@ -207,6 +266,90 @@ impl BattleSystem {
             BattleEvent::Faint(event_data) => {
                 self.handle_faint(event_data);
             },
+            BattleEvent::Escaped(event_data) => {
+                self.handle_escaped(event_data, system_data);
+            },
+            BattleEvent::EscapeFailed(event_data) => {
+                self.handle_escape_failed(event_data);
+            },
+            BattleEvent::CaptureAttempt(event_data) => {
+                self.handle_capture_attempt(event_data);
+            },
+            BattleEvent::InvalidPokeball(event_data) => {
+                self.handle_invalid_pokeball(event_data);
+            },
+            BattleEvent::CaptureRefused(event_data) => {
+                self.handle_capture_refused(event_data);
+            },
+            BattleEvent::WeatherStart(event_data) => {
+                self.handle_weather_start(event_data);
+            },
+            BattleEvent::WeatherEnd(event_data) => {
+                self.handle_weather_end(event_data);
+            },
+            BattleEvent::WeatherDamage(event_data) => {
+                self.handle_weather_damage(event_data);
+            },
+            BattleEvent::SideConditionStart(event_data) => {
+                self.handle_side_condition_start(event_data);
+            },
+            BattleEvent::SideConditionEnd(event_data) => {
+                self.handle_side_condition_end(event_data);
+            },
+            BattleEvent::AbilityActivated(event_data) => {
+                self.handle_ability_activated(event_data);
+            },
+            BattleEvent::Heal(event_data) => {
+                self.handle_heal(event_data, system_data);
+            },
+            BattleEvent::ItemActivated(event_data) => {
+                self.handle_item_activated(event_data);
+            },
+            BattleEvent::ItemConsumed(event_data) => {
+                self.handle_item_consumed(event_data);
+            },
+            BattleEvent::Protected(event_data) => {
+                self.handle_protected(event_data);
+            },
+            BattleEvent::Charging(event_data) => {
+                self.handle_charging(event_data);
+            },
+            BattleEvent::Recharging(event_data) => {
+                self.handle_recharging(event_data);
+            },
+            BattleEvent::DisabledMove(event_data) => {
+                self.handle_disabled_move(event_data);
+            },
+            BattleEvent::PpReduction(event_data) => {
+                self.handle_pp_reduction(event_data);
+            },
+            BattleEvent::NoPpLeft(event_data) => {
+                self.handle_no_pp_left(event_data);
+            },
+            BattleEvent::SubstituteCreated(event_data) => {
+                self.handle_substitute_created(event_data);
+            },
+            BattleEvent::SubstituteDamaged(event_data) => {
+                self.handle_substitute_damaged(event_data);
+            },
+            BattleEvent::SubstituteBroken(event_data) => {
+                self.handle_substitute_broken(event_data);
+            },
+            BattleEvent::PerishCount(event_data) => {
+                self.handle_perish_count(event_data);
+            },
+            BattleEvent::DestinyBond(event_data) => {
+                self.handle_destiny_bond(event_data);
+            },
+            BattleEvent::StatStagesReset(event_data) => {
+                self.handle_stat_stages_reset(event_data);
+            },
+            BattleEvent::StatStagesCopied(event_data) => {
+                self.handle_stat_stages_copied(event_data);
+            },
+            BattleEvent::BattleEnd(event_data) => {
+                self.handle_battle_end(event_data);
+            },
         }
 
         self.start_animation(system_data);
@@ -247,13 +390,7 @@ impl BattleSystem {
                         backend.push_frontend_event(event);
                     }
 
-                    // TODO: replace this by an AI call
-                    use crate::battle::backend::FrontendEventKind;
-                    backend.push_frontend_event(FrontendEvent {
-                        team: Team::P2,
-                        event: FrontendEventKind::UseMove(0),
-                    });
-
+                    backend.push_opponent_actions(self.opponent_ai.as_deref_mut().unwrap());
                     self.event_queue.extend(backend.tick());
                 }
 
@@ -273,6 +410,19 @@ impl BattleSystem {
 }
 
 impl BattleSystem {
+    /// Returns `true` if the player's Pokémon has to take a forced action
+    /// (e.g unleashing a charged move), which skips the action selection.
+    fn has_p1_forced_action(backend: &BattleBackend) -> bool {
+        let position = FieldPosition {
+            team: Team::P1,
+            slot: 0,
+        };
+
+        backend
+            .get_pokemon_at(position)
+            .map_or(false, |pokemon| backend.has_forced_action(pokemon))
+    }
+
     fn handle_initial_switch_in(&mut self, event_data: InitialSwitchIn) {
         let mut animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> = Vec::new();
 
@@ -310,6 +460,80 @@ impl BattleSystem {
         });
     }
 
+    fn handle_switch_out(&mut self, event_data: SwitchOut, system_data: &mut BattleSystemData<'_>) {
+        let pokedex = get_all_pokemon_species();
+        let backend = self.backend.as_mut().unwrap();
+        let pokemon = backend.get_pokemon(event_data.pokemon);
+        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
+
+        let (info_card, text) = match event_data.team {
+            Team::P1 => (
+                &mut self.p1_info_card,
+                format!("{}, come back!", display_name),
+            ),
+            Team::P2 => (
+                &mut self.p2_info_card,
+                format!("The opponent withdrew {}!", display_name),
+            ),
+        };
+
+        if let Some(mut info_card) = info_card.take() {
+            info_card.remove(system_data);
+        }
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart { text })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn handle_switch_in(&mut self, event_data: SwitchIn, system_data: &mut BattleSystemData<'_>) {
+        let pokedex = get_all_pokemon_species();
+        let backend = self.backend.as_mut().unwrap();
+        let pokemon = backend.get_pokemon(event_data.pokemon);
+        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
+
+        let sprite_sheet = match event_data.team {
+            Team::P1 => system_data.resources.gen1_back.clone(),
+            Team::P2 => system_data.resources.gen1_front.clone(),
+        };
+        let sprite_number = backend.get_species(event_data.pokemon).national_number - 1;
+
+        (&mut system_data.sprite_renders)
+            .join()
+            .filter(|sprite_render| sprite_render.sprite_sheet == sprite_sheet)
+            .for_each(|sprite_render| sprite_render.sprite_number = sprite_number);
+
+        let previous_info_card = match event_data.team {
+            Team::P1 => self.p1_info_card.take(),
+            Team::P2 => self.p2_info_card.take(),
+        };
+
+        if let Some(mut info_card) = previous_info_card {
+            info_card.remove(system_data);
+        }
+
+        let text = match event_data.team {
+            Team::P1 => {
+                self.p1_info_card = Some(InfoCard::new(pokemon, Team::P1, system_data));
+                format!("Go! {}!", display_name)
+            },
+            Team::P2 => {
+                self.p2_info_card = Some(InfoCard::new(pokemon, Team::P2, system_data));
+                format!("The opponent sent out {}!", display_name)
+            },
+        };
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart { text })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
     fn handle_use_move(&mut self, event_data: UseMove) {
         let pokedex = get_all_pokemon_species();
         let backend = self.backend.as_mut().unwrap();
@@ -367,6 +591,44 @@ impl BattleSystem {
                     text: format!("{} is hurt by its poison!", display_name),
                 }));
             },
+            // The weather message has already been shown
+            DamageCause::Weather => {},
+            // The ability message has already been shown
+            DamageCause::Ability => {},
+            DamageCause::StealthRock => {
+                animations.push(Box::new(TextAnimation::PendingStart {
+                    text: format!("Pointed stones dug into {}!", display_name),
+                }));
+            },
+            DamageCause::Recoil => {
+                animations.push(Box::new(TextAnimation::PendingStart {
+                    text: format!("{} is damaged by recoil!", display_name),
+                }));
+            },
+            DamageCause::Trap => {
+                animations.push(Box::new(TextAnimation::PendingStart {
+                    text: format!("{} is hurt by the trap!", display_name),
+                }));
+            },
+            // The substitute message has already been shown
+            DamageCause::Substitute => {},
+            DamageCause::LeechSeed => {
+                animations.push(Box::new(TextAnimation::PendingStart {
+                    text: format!("{}'s health is sapped by Leech Seed!", display_name),
+                }));
+            },
+            DamageCause::Curse => {
+                animations.push(Box::new(TextAnimation::PendingStart {
+                    text: format!("{} is afflicted by the curse!", display_name),
+                }));
+            },
+            // The perish count or Destiny Bond message has already been shown
+            DamageCause::PerishSong | DamageCause::DestinyBond => {},
+            DamageCause::Confusion => {
+                animations.push(Box::new(TextAnimation::PendingStart {
+                    text: "It hurt itself in its confusion!".to_string(),
+                }));
+            },
         }
 
         let info_card = match backend.get_pokemon_team(event_data.target) {
@@ -433,15 +695,9 @@ impl BattleSystem {
 
         let mut animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> = Vec::new();
 
-        if event_data.caused_by_confusion {
-            animations.push(Box::new(TextAnimation::PendingStart {
-                text: format!("But its confusion caused it to miss!"),
-            }));
-        } else {
-            animations.push(Box::new(TextAnimation::PendingStart {
-                text: format!("But {} avoided the attack!", display_name),
-            }));
-        }
+        animations.push(Box::new(TextAnimation::PendingStart {
+            text: format!("But {} avoided the attack!", display_name),
+        }));
 
         self.active_animation_sequence = Some(AnimationSequence {
             animations: animations.into(),
@@ -508,6 +764,71 @@ impl BattleSystem {
                     text: format!("{} flinched!", display_name),
                 }));
             },
+            Flag::Protection(Protection::Endure) => {
+                animations.push(Box::new(TextAnimation::PendingStart {
+                    text: format!("{} braced itself!", display_name),
+                }));
+            },
+            Flag::Protection(protection) => {
+                animations.push(Box::new(TextAnimation::PendingStart {
+                    text: get_protection_text(protection, &display_name),
+                }));
+            },
+            Flag::Trapped { trapper, move_id, .. } => {
+                let trapper = backend.get_pokemon(trapper);
+                let trapper_name = get_pokemon_display_name(&trapper, &pokedex);
+
+                animations.push(Box::new(TextAnimation::PendingStart {
+                    text: get_trap_text(&move_id, &display_name, &trapper_name),
+                }));
+            },
+            Flag::Disabled { move_id, .. } => {
+                let movement = get_all_moves().get_move(&move_id).unwrap();
+
+                animations.push(Box::new(TextAnimation::PendingStart {
+                    text: format!("{}'s {} was disabled!", display_name, movement.display_name),
+                }));
+            },
+            Flag::Encore { .. } => {
+                animations.push(Box::new(TextAnimation::PendingStart {
+                    text: format!("{} received an encore!", display_name),
+                }));
+            },
+            Flag::LeechSeed { .. } => {
+                animations.push(Box::new(TextAnimation::PendingStart {
+                    text: format!("{} was seeded!", display_name),
+                }));
+            },
+            Flag::Curse => {
+                animations.push(Box::new(TextAnimation::PendingStart {
+                    text: format!("{} was cursed!", display_name),
+                }));
+            },
+            Flag::PerishCount(count) => {
+                animations.push(Box::new(TextAnimation::PendingStart {
+                    text: format!("{} will faint in {} turns!", display_name, count),
+                }));
+            },
+            Flag::Drowsy { .. } => {
+                animations.push(Box::new(TextAnimation::PendingStart {
+                    text: format!("{} grew drowsy!", display_name),
+                }));
+            },
+            Flag::DestinyBond => {
+                animations.push(Box::new(TextAnimation::PendingStart {
+                    text: format!("{} is trying to take its foe down with it!", display_name),
+                }));
+            },
+            // These flags are never announced
+            Flag::Ability(_)
+            | Flag::ChoiceLock(_)
+            | Flag::FlashFire
+            | Flag::ForcedAction(_)
+            | Flag::ProtectionCounter(_)
+            | Flag::Roost
+            | Flag::Substitute { .. }
+            | Flag::Unburden
+            | Flag::UsedMoves(_) => unreachable!(),
             Flag::StatStages(_) => unreachable!(),
         }
 
@@ -533,6 +854,45 @@ impl BattleSystem {
                 }));
             },
             Flag::Flinch => unreachable!(),
+            Flag::Trapped { move_id, .. } => {
+                let movement = get_all_moves().get_move(&move_id).unwrap();
+
+                animations.push(Box::new(TextAnimation::PendingStart {
+                    text: format!("{} was freed from {}!", display_name, movement.display_name),
+                }));
+            },
+            Flag::Disabled { move_id, .. } => {
+                let movement = get_all_moves().get_move(&move_id).unwrap();
+
+                let text = format!(
+                    "{}'s {} is no longer disabled!",
+                    display_name,
+                    movement.display_name,
+                );
+
+                animations.push(Box::new(TextAnimation::PendingStart { text }));
+            },
+            Flag::Encore { .. } => {
+                animations.push(Box::new(TextAnimation::PendingStart {
+                    text: format!("{}'s encore ended!", display_name),
+                }));
+            },
+            // These flags expire silently
+            Flag::Ability(_)
+            | Flag::ChoiceLock(_)
+            | Flag::Curse
+            | Flag::DestinyBond
+            | Flag::Drowsy { .. }
+            | Flag::FlashFire
+            | Flag::ForcedAction(_)
+            | Flag::LeechSeed { .. }
+            | Flag::PerishCount(_)
+            | Flag::Protection(_)
+            | Flag::ProtectionCounter(_)
+            | Flag::Roost
+            | Flag::Substitute { .. }
+            | Flag::Unburden
+            | Flag::UsedMoves(_) => unreachable!(),
             Flag::StatStages(_) => unreachable!(),
         }
 
@@ -656,56 +1016,722 @@ impl BattleSystem {
         });
     }
 
-    fn push_action_selection_event(&mut self, system_data: &mut BattleSystemData<'_>) {
+    fn handle_escaped(&mut self, event_data: Escaped, system_data: &mut BattleSystemData<'_>) {
+        let text = match event_data.team {
+            Team::P1 => "Got away safely!",
+            Team::P2 => "The wild Pokémon fled!",
+        };
+
+        system_data.sound_kit.play_sound(Sound::BattleRun);
+
         let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
-            vec![Box::new(ActionSelectionScreen::PendingStart)];
+            vec![Box::new(TextAnimation::PendingStart {
+                text: text.to_string(),
+            })];
 
-        if self.p1_info_card.is_none() {
-            self.init_info_cards(system_data);
-        }
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn handle_escape_failed(&mut self, event_data: EscapeFailed) {
+        let text = match event_data.cause {
+            EscapeFailureCause::BadOdds => "Can't escape!",
+            EscapeFailureCause::TrainerBattle => "No! There's no running from a trainer battle!",
+            EscapeFailureCause::Trapped => "Can't escape!",
+        };
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart {
+                text: text.to_string(),
+            })];
 
         self.active_animation_sequence = Some(AnimationSequence {
             animations: animations.into(),
         });
     }
 
-    fn init_info_cards(&mut self, system_data: &mut BattleSystemData<'_>) {
-        let backend = self.backend.as_mut().unwrap();
-        let p1 = backend.get_active_pokemon(Team::P1).next().unwrap();
-        let p2 = backend.get_active_pokemon(Team::P2).next().unwrap();
+    fn handle_capture_attempt(&mut self, event_data: CaptureAttempt) {
+        let pokedex = get_all_pokemon_species();
+        let backend = self.backend.as_ref().unwrap();
+        let pokemon = backend.get_pokemon(event_data.target);
+        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
 
-        self.p1_info_card = Some(InfoCard::new(p1, Team::P1, system_data));
-        self.p2_info_card = Some(InfoCard::new(p2, Team::P2, system_data));
+        let result_text = if event_data.success {
+            format!("Gotcha! {} was caught!", display_name)
+        } else {
+            match event_data.shakes {
+                0 => "Oh no! The Pokémon broke free!",
+                1 => "Aww! It appeared to be caught!",
+                2 => "Aargh! Almost had it!",
+                _ => "Shoot! It was so close, too!",
+            }
+            .to_string()
+        };
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> = vec![
+            Box::new(TextAnimation::PendingStart {
+                text: "You threw a Poké Ball!".to_string(),
+            }),
+            Box::new(TextAnimation::PendingStart { text: result_text }),
+        ];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
     }
-}
 
-impl<'a> System<'a> for BattleSystem {
-    type SystemData = BattleSystemData<'a>;
+    fn handle_invalid_pokeball(&mut self, event_data: InvalidPokeball) {
+        let text = format!("The {} can't be used!", event_data.pokeball);
 
-    fn run(&mut self, mut system_data: Self::SystemData) {
-        if self.active_animation_sequence.is_none() {
-            if self.event_queue.is_empty() {
-                match self.backend.as_mut() {
-                    Some(_) => {
-                        self.push_action_selection_event(&mut system_data);
-                        self.start_animation(&mut system_data);
-                    },
-                    None => {
-                        let mut backend = BattleBackend::new(
-                            system_data.battle.clone(),
-                            Box::new(StandardBattleRng::default()),
-                        );
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart { text })];
 
-                        self.event_queue.extend(backend.tick());
-                        self.backend = Some(backend);
-                        self.handle_next_backend_event(&mut system_data);
-                    },
-                };
-            } else {
-                self.handle_next_backend_event(&mut system_data);
-            }
-        }
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
 
-        self.tick(&mut system_data);
+    fn handle_capture_refused(&mut self, event_data: CaptureRefused) {
+        let text = match event_data.cause {
+            CaptureRefusalCause::TrainerBattle => "The trainer blocked the ball! Don't be a thief!",
+            CaptureRefusalCause::MultipleTargets => "You can't aim at more than one Pokémon!",
+        };
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart {
+                text: text.to_string(),
+            })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn handle_weather_start(&mut self, event_data: WeatherStart) {
+        let text = match event_data.weather {
+            Weather::Rain => "It started to rain!",
+            Weather::Sun => "The sunlight turned harsh!",
+            Weather::Sandstorm => "A sandstorm kicked up!",
+            Weather::Hail => "It started to hail!",
+        };
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart {
+                text: text.to_string(),
+            })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn handle_weather_end(&mut self, event_data: WeatherEnd) {
+        let text = match event_data.weather {
+            Weather::Rain => "The rain stopped.",
+            Weather::Sun => "The harsh sunlight faded.",
+            Weather::Sandstorm => "The sandstorm subsided.",
+            Weather::Hail => "The hail stopped.",
+        };
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart {
+                text: text.to_string(),
+            })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn handle_weather_damage(&mut self, event_data: WeatherDamage) {
+        let pokedex = get_all_pokemon_species();
+        let backend = self.backend.as_ref().unwrap();
+        let pokemon = backend.get_pokemon(event_data.target);
+        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
+
+        let text = match event_data.weather {
+            Weather::Sandstorm => format!("{} is buffeted by the sandstorm!", display_name),
+            Weather::Hail => format!("{} is buffeted by the hail!", display_name),
+            Weather::Rain | Weather::Sun => unreachable!(),
+        };
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart { text })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn handle_side_condition_start(&mut self, event_data: SideConditionStart) {
+        let team_name = get_team_name(event_data.team);
+
+        let text = match event_data.condition {
+            SideCondition::Reflect => {
+                format!("Reflect made {} stronger against physical moves!", team_name)
+            },
+            SideCondition::LightScreen => {
+                format!("Light Screen made {} stronger against special moves!", team_name)
+            },
+            SideCondition::Safeguard => format!("{} cloaked itself in a mystical veil!", team_name),
+            SideCondition::Mist => format!("{} became shrouded in mist!", team_name),
+            SideCondition::Tailwind => format!("The Tailwind blew from behind {}!", team_name),
+            SideCondition::StealthRock => {
+                format!("Pointed stones float in the air around {}!", team_name)
+            },
+        };
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart {
+                text: capitalize(&text),
+            })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn handle_side_condition_end(&mut self, event_data: SideConditionEnd) {
+        let team_name = get_team_name(event_data.team);
+
+        let text = match event_data.condition {
+            SideCondition::Reflect => format!("{}'s Reflect wore off!", team_name),
+            SideCondition::LightScreen => format!("{}'s Light Screen wore off!", team_name),
+            SideCondition::Safeguard => {
+                format!("{} is no longer protected by Safeguard!", team_name)
+            },
+            SideCondition::Mist => format!("{} is no longer protected by mist!", team_name),
+            SideCondition::Tailwind => format!("{}'s Tailwind petered out!", team_name),
+            SideCondition::StealthRock => {
+                format!("The pointed stones disappeared from around {}!", team_name)
+            },
+        };
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart {
+                text: capitalize(&text),
+            })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn handle_ability_activated(&mut self, event_data: AbilityActivated) {
+        let pokedex = get_all_pokemon_species();
+        let backend = self.backend.as_ref().unwrap();
+        let pokemon = backend.get_pokemon(event_data.pokemon);
+        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
+
+        let text = format!(
+            "{}'s {}!",
+            display_name,
+            get_ability_display_name(&event_data.ability),
+        );
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart { text })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn handle_heal(&mut self, event_data: Heal, system_data: &mut BattleSystemData<'_>) {
+        let pokedex = get_all_pokemon_species();
+        let backend = self.backend.as_ref().unwrap();
+        let pokemon = backend.get_pokemon(event_data.target);
+        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
+
+        let info_card = match backend.get_pokemon_team(event_data.target) {
+            Team::P1 => self.p1_info_card.as_mut().unwrap(),
+            Team::P2 => self.p2_info_card.as_mut().unwrap(),
+        };
+
+        info_card.heal(event_data.amount, &pokemon, system_data);
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart {
+                text: format!("{} restored HP!", display_name),
+            })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn handle_item_activated(&mut self, event_data: ItemActivated) {
+        let pokedex = get_all_pokemon_species();
+        let backend = self.backend.as_ref().unwrap();
+        let pokemon = backend.get_pokemon(event_data.pokemon);
+        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
+
+        let text = format!(
+            "{}'s {}!",
+            display_name,
+            get_item_display_name(&event_data.item),
+        );
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart { text })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn handle_item_consumed(&mut self, event_data: ItemConsumed) {
+        let pokedex = get_all_pokemon_species();
+        let backend = self.backend.as_ref().unwrap();
+        let pokemon = backend.get_pokemon(event_data.pokemon);
+        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
+
+        let text = format!(
+            "{} used its {}!",
+            display_name,
+            get_item_display_name(&event_data.item),
+        );
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart { text })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn handle_protected(&mut self, event_data: Protected) {
+        let pokedex = get_all_pokemon_species();
+        let backend = self.backend.as_ref().unwrap();
+        let pokemon = backend.get_pokemon(event_data.target);
+        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
+
+        let text = get_protection_text(event_data.protection, &display_name);
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart { text })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn handle_charging(&mut self, event_data: Charging) {
+        let pokedex = get_all_pokemon_species();
+        let backend = self.backend.as_ref().unwrap();
+        let pokemon = backend.get_pokemon(event_data.pokemon);
+        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
+
+        let text = match event_data.move_id.as_str() {
+            "Dig" => format!("{} burrowed its way under the ground!", display_name),
+            "SkullBash" => format!("{} tucked in its head!", display_name),
+            "SolarBeam" => format!("{} absorbed light!", display_name),
+            _ => format!("{} is charging its move!", display_name),
+        };
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart { text })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn handle_recharging(&mut self, event_data: Recharging) {
+        let pokedex = get_all_pokemon_species();
+        let backend = self.backend.as_ref().unwrap();
+        let pokemon = backend.get_pokemon(event_data.pokemon);
+        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
+
+        let text = format!("{} must recharge!", display_name);
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart { text })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn handle_disabled_move(&mut self, event_data: DisabledMove) {
+        let pokedex = get_all_pokemon_species();
+        let backend = self.backend.as_ref().unwrap();
+        let pokemon = backend.get_pokemon(event_data.pokemon);
+        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
+        let movement = get_all_moves().get_move(&event_data.move_id).unwrap();
+
+        let text = format!("{}'s {} is disabled!", display_name, movement.display_name);
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart { text })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn handle_pp_reduction(&mut self, event_data: PpReduction) {
+        let pokedex = get_all_pokemon_species();
+        let backend = self.backend.as_ref().unwrap();
+        let pokemon = backend.get_pokemon(event_data.target);
+        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
+        let movement = get_all_moves().get_move(&event_data.move_id).unwrap();
+
+        let text = format!(
+            "It reduced the PP of {}'s {} by {}!",
+            display_name,
+            movement.display_name,
+            event_data.amount,
+        );
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart { text })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn handle_no_pp_left(&mut self, _event_data: NoPpLeft) {
+        let text = "There's no PP left for this move!".to_string();
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart { text })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn handle_substitute_created(&mut self, event_data: SubstituteCreated) {
+        let pokedex = get_all_pokemon_species();
+        let backend = self.backend.as_ref().unwrap();
+        let pokemon = backend.get_pokemon(event_data.pokemon);
+        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
+
+        let text = format!("{} put in a substitute!", display_name);
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart { text })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn handle_substitute_damaged(&mut self, event_data: SubstituteDamaged) {
+        let pokedex = get_all_pokemon_species();
+        let backend = self.backend.as_ref().unwrap();
+        let pokemon = backend.get_pokemon(event_data.pokemon);
+        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
+
+        let text = format!("The substitute took damage for {}!", display_name);
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart { text })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn handle_substitute_broken(&mut self, event_data: SubstituteBroken) {
+        let pokedex = get_all_pokemon_species();
+        let backend = self.backend.as_ref().unwrap();
+        let pokemon = backend.get_pokemon(event_data.pokemon);
+        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
+
+        let text = format!("{}'s substitute faded!", display_name);
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart { text })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn handle_perish_count(&mut self, event_data: PerishCount) {
+        let pokedex = get_all_pokemon_species();
+        let backend = self.backend.as_ref().unwrap();
+        let pokemon = backend.get_pokemon(event_data.pokemon);
+        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
+
+        let text = format!("{}'s perish count fell to {}!", display_name, event_data.count);
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart { text })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn handle_destiny_bond(&mut self, event_data: DestinyBond) {
+        let pokedex = get_all_pokemon_species();
+        let backend = self.backend.as_ref().unwrap();
+        let pokemon = backend.get_pokemon(event_data.pokemon);
+        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
+
+        let text = format!("{} took its attacker down with it!", display_name);
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart { text })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn handle_stat_stages_reset(&mut self, event_data: StatStagesReset) {
+        let pokedex = get_all_pokemon_species();
+        let backend = self.backend.as_ref().unwrap();
+        let pokemon = backend.get_pokemon(event_data.pokemon);
+        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
+
+        let text = format!("{}'s stat changes were eliminated!", display_name);
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart { text })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn handle_stat_stages_copied(&mut self, event_data: StatStagesCopied) {
+        let pokedex = get_all_pokemon_species();
+        let backend = self.backend.as_ref().unwrap();
+        let user = backend.get_pokemon(event_data.user);
+        let target = backend.get_pokemon(event_data.target);
+
+        let text = format!(
+            "{} copied {}'s stat changes!",
+            get_pokemon_display_name(&user, &pokedex),
+            get_pokemon_display_name(&target, &pokedex),
+        );
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart { text })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn handle_battle_end(&mut self, event_data: BattleEnd) {
+        let text = match (event_data.winner, event_data.reason) {
+            (Team::P1, BattleEndReason::AllPokemonFainted) => "You won the battle!",
+            (Team::P2, BattleEndReason::AllPokemonFainted) => {
+                "You have no more Pokémon that can fight! You blacked out!"
+            },
+            // The escape/capture messages have already been shown
+            (_, BattleEndReason::Escaped) | (_, BattleEndReason::PokemonCaught) => return,
+        };
+
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(TextAnimation::PendingStart {
+                text: text.to_string(),
+            })];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    /// Removes every entity created by this system and hands the outcome of
+    /// the battle back to the world, which allows the battle state to end.
+    fn finish_battle(&mut self, system_data: &mut BattleSystemData<'_>) {
+        let backend = self.backend.take().unwrap();
+        self.opponent_ai = None;
+
+        if system_data.game_config.record_battles {
+            Self::save_replay(&backend);
+        }
+
+        for info_card in self.p1_info_card.iter_mut().chain(self.p2_info_card.iter_mut()) {
+            info_card.remove(system_data);
+        }
+
+        self.p1_info_card = None;
+        self.p2_info_card = None;
+
+        let BattleSystemData {
+            entities,
+            sprite_renders,
+            resources,
+            battle,
+            ..
+        } = system_data;
+
+        (&**entities, &*sprite_renders)
+            .join()
+            .filter(|(_, sprite_render)| {
+                sprite_render.sprite_sheet == resources.gen1_back
+                    || sprite_render.sprite_sheet == resources.gen1_front
+            })
+            .for_each(|(entity, _)| {
+                entities
+                    .delete(entity)
+                    .expect("Failed to delete Pokémon sprite");
+            });
+
+        // The Pokémon keep the HP, PP and status conditions they ended the
+        // battle with
+        battle.p1.party.pokemon = backend.get_team_pokemon(Team::P1).cloned().collect();
+        battle.p2.party.pokemon = backend.get_team_pokemon(Team::P2).cloned().collect();
+        battle.outcome = backend.get_outcome().cloned();
+        battle.caught_pokemon = backend.get_caught_pokemon().cloned();
+    }
+
+    /// Writes the replay of a battle to the `replays` directory, naming it
+    /// after the seed of the battle.
+    fn save_replay(recorder: &BattleRecorder) {
+        let seed = recorder.get_replay().rng.get_seed();
+        let path = application_root_dir()
+            .expect("Failed to find the application root directory")
+            .join("replays")
+            .join(format!("{}.ron", seed));
+
+        if let Err(err) = recorder.save(&path) {
+            eprintln!("Failed to save the replay of the battle: {}", err);
+        }
+    }
+
+    fn push_party_selection_event(&mut self) {
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(PartySelectionScreen::PendingStart)];
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn push_action_selection_event(&mut self, system_data: &mut BattleSystemData<'_>) {
+        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
+            vec![Box::new(ActionSelectionScreen::PendingStart)];
+
+        if self.p1_info_card.is_none() {
+            self.init_info_cards(system_data);
+        }
+
+        self.active_animation_sequence = Some(AnimationSequence {
+            animations: animations.into(),
+        });
+    }
+
+    fn init_info_cards(&mut self, system_data: &mut BattleSystemData<'_>) {
+        let backend = self.backend.as_mut().unwrap();
+        let p1 = backend.get_active_pokemon(Team::P1).next().unwrap();
+        let p2 = backend.get_active_pokemon(Team::P2).next().unwrap();
+
+        self.p1_info_card = Some(InfoCard::new(p1, Team::P1, system_data));
+        self.p2_info_card = Some(InfoCard::new(p2, Team::P2, system_data));
+    }
+}
+
+impl<'a> System<'a> for BattleSystem {
+    type SystemData = BattleSystemData<'a>;
+
+    fn run(&mut self, mut system_data: Self::SystemData) {
+        if system_data.battle.outcome.is_some() {
+            return;
+        }
+
+        if self.active_animation_sequence.is_none() {
+            if self.event_queue.is_empty() {
+                match self.backend.as_mut() {
+                    Some(backend) if backend.get_outcome().is_some() => {
+                        self.finish_battle(&mut system_data);
+                    },
+                    Some(backend) if backend.requires_replacement(Team::P1) => {
+                        self.push_party_selection_event();
+                        self.start_animation(&mut system_data);
+                    },
+                    Some(backend) if backend.requires_replacement(Team::P2) => {
+                        let opponent_ai = self.opponent_ai.as_deref_mut().unwrap();
+                        backend.push_opponent_actions(opponent_ai);
+                        self.event_queue.extend(backend.tick());
+                        self.handle_next_backend_event(&mut system_data);
+                    },
+                    Some(backend) if Self::has_p1_forced_action(backend) => {
+                        // The move index is ignored by the backend
+                        backend.push_frontend_event(FrontendEvent {
+                            team: Team::P1,
+                            slot: 0,
+                            event: FrontendEventKind::UseMove {
+                                move_index: 0,
+                                target: None,
+                            },
+                        });
+                        let opponent_ai = self.opponent_ai.as_deref_mut().unwrap();
+                        backend.push_opponent_actions(opponent_ai);
+                        self.event_queue.extend(backend.tick());
+                        self.handle_next_backend_event(&mut system_data);
+                    },
+                    Some(_) => {
+                        self.push_action_selection_event(&mut system_data);
+                        self.start_animation(&mut system_data);
+                    },
+                    None => {
+                        let rng = SeededBattleRng::from_random_seed();
+                        let mut backend = BattleRecorder::new(system_data.battle.clone(), rng);
+
+                        let opponent_ai =
+                            system_data.battle.p2.ai.expect("The opposing team has no AI");
+                        self.opponent_ai = Some(opponent_ai.create());
+
+                        self.event_queue.extend(backend.tick());
+                        self.backend = Some(backend);
+                        self.handle_next_backend_event(&mut system_data);
+                    },
+                };
+            } else {
+                self.handle_next_backend_event(&mut system_data);
+            }
+        }
+
+        self.tick(&mut system_data);
+    }
+}
+
+/// Returns how a team is referred to in battle messages.
+fn get_team_name(team: Team) -> &'static str {
+    match team {
+        Team::P1 => "your team",
+        Team::P2 => "the opposing team",
+    }
+}
+
+/// Capitalizes the first letter of a battle message.
+fn capitalize(text: &str) -> String {
+    let mut characters = text.chars();
+
+    match characters.next() {
+        Some(first) => first.to_uppercase().chain(characters).collect(),
+        None => String::new(),
+    }
+}
+
+/// Returns the message shown when a protection starts or blocks a move.
+fn get_protection_text(protection: Protection, display_name: &str) -> String {
+    match protection {
+        Protection::Full => format!("{} protected itself!", display_name),
+        Protection::WideGuard => format!("Wide Guard protected {}!", display_name),
+        Protection::QuickGuard => format!("Quick Guard protected {}!", display_name),
+        Protection::Endure => format!("{} endured the hit!", display_name),
+    }
+}
+
+/// Returns the message shown when a Pokémon gets trapped by a move like Wrap.
+fn get_trap_text(move_id: &str, target_name: &str, trapper_name: &str) -> String {
+    match move_id {
+        "Bind" => format!("{} was squeezed by {}!", target_name, trapper_name),
+        "FireSpin" => format!("{} became trapped in the fiery vortex!", target_name),
+        "SandTomb" => format!("{} became trapped by the quicksand!", target_name),
+        "Whirlpool" => format!("{} became trapped in the vortex!", target_name),
+        "Wrap" => format!("{} was wrapped by {}!", target_name, trapper_name),
+        _ => format!("{} became trapped!", target_name),
     }
 }
@@ -1,6 +1,8 @@
+pub mod ai;
 pub mod backend;
 pub mod battle_state;
 pub mod frontend;
+pub mod replay;
 pub mod types;
 
 #[cfg(test)]
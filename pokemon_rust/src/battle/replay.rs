@@ -0,0 +1,190 @@
+//! Recording and playback of battles, used to reproduce bugs.
+
+use crate::battle::{
+    ai::{choose_team_actions, BattleAi, BattleAiKind},
+    backend::{rng::SeededBattleRng, BattleBackend, BattleEvent, FrontendEvent, Team},
+    types::Battle,
+};
+
+use ron::{
+    de::from_reader,
+    ser::{to_string_pretty, PrettyConfig},
+};
+
+use serde::{Deserialize, Serialize};
+
+use std::{
+    fmt::{Display, Error, Formatter},
+    fs::{self, File},
+    io,
+    mem,
+    ops::Deref,
+    path::Path,
+};
+
+/// Everything needed to play a battle again: the battle as it was before it
+/// started, the RNG it used and the actions that were taken, alongside the
+/// events that the backend emitted in response.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct Replay {
+    pub battle: Battle,
+    pub rng: SeededBattleRng,
+    pub turns: Vec<ReplayTurn>,
+}
+
+/// Corresponds to a single call to `BattleBackend::tick()`. This includes
+/// ticks that only send in replacements, so the index of a ReplayTurn doesn't
+/// always match the turn of the battle.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ReplayTurn {
+    /// The actions pushed to the backend since the previous tick, except the
+    /// ones chosen by the AI.
+    pub actions: Vec<FrontendEvent>,
+    /// The actions chosen by the AI of the opposing team before the tick, or
+    /// `None` if it wasn't asked to choose any. Since the AI draws from the
+    /// battle RNG, they're chosen again when the replay is played.
+    pub ai_actions: Option<Vec<FrontendEvent>>,
+    /// The events emitted by the backend during the tick.
+    pub events: Vec<BattleEvent>,
+}
+
+/// The first turn of a replay where the backend didn't emit the recorded
+/// events.
+#[derive(Debug)]
+pub struct ReplayDivergence {
+    /// The index of the turn in the replay.
+    pub turn: usize,
+    pub expected: Vec<BattleEvent>,
+    pub actual: Vec<BattleEvent>,
+}
+
+impl Display for ReplayDivergence {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        writeln!(f, "The replay diverged at turn {}.", self.turn)?;
+        writeln!(f, "Expected events: {:#?}", self.expected)?;
+        write!(f, "Actual events: {:#?}", self.actual)
+    }
+}
+
+impl Replay {
+    /// Reads a replay written by `BattleRecorder::save()`.
+    pub fn load(path: &Path) -> Replay {
+        let file = File::open(path).expect("Failed opening replay file");
+
+        from_reader(file).expect("Failed deserializing replay")
+    }
+
+    /// Feeds the recorded actions to a new backend, checking that it emits
+    /// the recorded events on every turn.
+    pub fn play(&self) -> Result<(), ReplayDivergence> {
+        let mut backend = BattleBackend::new(self.battle.clone(), Box::new(self.rng.clone()));
+        let mut ai = self.battle.p2.ai.map(BattleAiKind::create);
+
+        for (turn, replay_turn) in self.turns.iter().enumerate() {
+            let ReplayTurn { actions, ai_actions, events } = replay_turn;
+
+            for action in actions {
+                backend.push_frontend_event(action.clone());
+            }
+
+            if ai_actions.is_some() {
+                let ai = ai.as_deref_mut().expect("The opposing team has no AI");
+
+                for action in choose_team_actions(&mut backend, Team::P2, ai) {
+                    backend.push_frontend_event(action);
+                }
+            }
+
+            let actual: Vec<_> = backend.tick().collect();
+
+            if actual != *events {
+                return Err(ReplayDivergence {
+                    turn,
+                    expected: events.clone(),
+                    actual,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Wraps a BattleBackend, recording everything that is needed to replay the
+/// battle. Actions must be pushed and turns must be processed through the
+/// recorder, while the other methods of the backend can be used directly.
+pub struct BattleRecorder {
+    backend: BattleBackend,
+    replay: Replay,
+    pending_actions: Vec<FrontendEvent>,
+    pending_ai_actions: Option<Vec<FrontendEvent>>,
+}
+
+impl BattleRecorder {
+    pub fn new(battle: Battle, rng: SeededBattleRng) -> BattleRecorder {
+        BattleRecorder {
+            backend: BattleBackend::new(battle.clone(), Box::new(rng.clone())),
+            replay: Replay {
+                battle,
+                rng,
+                turns: Vec::new(),
+            },
+            pending_actions: Vec::new(),
+            pending_ai_actions: None,
+        }
+    }
+
+    pub fn push_frontend_event(&mut self, event: FrontendEvent) {
+        self.pending_actions.push(event.clone());
+        self.backend.push_frontend_event(event);
+    }
+
+    /// Lets the AI of the opposing team choose its actions for the next tick
+    /// and pushes them.
+    pub fn push_opponent_actions(&mut self, ai: &mut dyn BattleAi) {
+        let actions = choose_team_actions(&mut self.backend, Team::P2, ai);
+
+        for action in &actions {
+            self.backend.push_frontend_event(action.clone());
+        }
+
+        self.pending_ai_actions = Some(actions);
+    }
+
+    pub fn tick(&mut self) -> impl Iterator<Item = BattleEvent> {
+        let events: Vec<_> = self.backend.tick().collect();
+
+        self.replay.turns.push(ReplayTurn {
+            actions: mem::take(&mut self.pending_actions),
+            ai_actions: self.pending_ai_actions.take(),
+            events: events.clone(),
+        });
+
+        events.into_iter()
+    }
+
+    pub fn get_replay(&self) -> &Replay {
+        &self.replay
+    }
+
+    /// Writes the replay recorded so far to a RON file, creating its parent
+    /// directories if needed.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+
+        let contents = to_string_pretty(&self.replay, PrettyConfig::default())
+            .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+
+        fs::write(path, contents)
+    }
+}
+
+impl Deref for BattleRecorder {
+    type Target = BattleBackend;
+
+    fn deref(&self) -> &BattleBackend {
+        &self.backend
+    }
+}
@@ -0,0 +1,118 @@
+use crate::battle::{
+    ai::{choose_team_actions, BattleAi, GreedyAi, LookaheadAi, RandomAi},
+    backend::{BattleBackend, FieldPosition, FrontendEventKind, Team},
+};
+
+use super::{prelude::*, TestMethods};
+
+fn choose_action(
+    ai: &mut dyn BattleAi,
+    backend: &BattleBackend,
+    pokemon: usize,
+) -> FrontendEventKind {
+    let mut rng = backend.rng.boxed_clone();
+
+    ai.choose_action(backend, &mut *rng, pokemon)
+}
+
+fn get_chosen_move(event: FrontendEventKind) -> usize {
+    match event {
+        FrontendEventKind::UseMove { move_index, .. } => move_index,
+        _ => panic!("Pattern mismatch"),
+    }
+}
+
+#[test]
+fn random_ai_only_picks_moves_that_can_be_used() {
+    let mut backend = battle! {
+        "Rattata" 10 (moves ["Tackle"], max ivs, Serious)
+        vs
+        "Pidgey" 10 (moves ["Tackle", "Gust", "SandAttack"], max ivs, Serious)
+    };
+
+    let gust = backend.get_move_index(Team::P2, "Gust");
+    backend.get_pokemon_mut(1).pp[0] = 0;
+    backend.get_pokemon_mut(1).pp[2] = 0;
+
+    for _ in 0..20 {
+        assert_eq!(get_chosen_move(choose_action(&mut RandomAi, &backend, 1)), gust);
+    }
+}
+
+#[test]
+fn random_ai_draws_from_the_battle_rng() {
+    let mut backend = battle! {
+        "Rattata" 10 (moves ["Tackle"], max ivs, Serious)
+        vs
+        "Pidgey" 10 (moves ["Tackle", "Gust", "SandAttack"], max ivs, Serious)
+    };
+
+    let sand_attack = backend.get_move_index(Team::P2, "SandAttack");
+
+    test_rng_mut!(backend.rng).force_random_ai_choice(2);
+    let actions = choose_team_actions(&mut backend, Team::P2, &mut RandomAi);
+
+    assert_eq!(get_chosen_move(actions[0].event.clone()), sand_attack);
+}
+
+#[test]
+fn greedy_ai_prefers_super_effective_moves() {
+    let backend = battle! {
+        "Charmander" 10 (moves ["Scratch"], max ivs, Serious)
+        vs
+        "Squirtle" 10 (moves ["Tackle", "WaterGun"], max ivs, Serious)
+    };
+
+    let water_gun = backend.get_move_index(Team::P2, "WaterGun");
+
+    match choose_action(&mut GreedyAi, &backend, 1) {
+        FrontendEventKind::UseMove { move_index, target } => {
+            assert_eq!(move_index, water_gun);
+            assert_eq!(target, Some(FieldPosition { team: Team::P1, slot: 0 }));
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+}
+
+#[test]
+fn greedy_ai_takes_stab_into_account() {
+    let backend = battle! {
+        "Rattata" 30 (moves ["Tackle"], max ivs, Serious)
+        vs
+        "Squirtle" 30 (moves ["Bite", "WaterGun"], max ivs, Serious)
+    };
+
+    let water_gun = backend.get_move_index(Team::P2, "WaterGun");
+
+    assert_eq!(get_chosen_move(choose_action(&mut GreedyAi, &backend, 1)), water_gun);
+}
+
+#[test]
+fn lookahead_ai_finishes_off_faster_foes_with_priority_moves() {
+    let mut backend = battle! {
+        "Rattata" 20 (moves ["Tackle"], max ivs, Serious)
+        vs
+        "Rattata" 10 (moves ["Tackle", "QuickAttack"], max ivs, Serious)
+    };
+
+    let quick_attack = backend.get_move_index(Team::P2, "QuickAttack");
+    backend.get_pokemon_mut(0).current_hp = 1;
+
+    assert_eq!(get_chosen_move(choose_action(&mut GreedyAi, &backend, 1)), 0);
+    assert_eq!(get_chosen_move(choose_action(&mut LookaheadAi, &backend, 1)), quick_attack);
+}
+
+#[test]
+fn lookahead_ai_switches_out_pokemon_that_cannot_fight_back() {
+    let diglett = pokemon_setup!("Diglett" 20 (moves ["Dig"], max ivs, Serious));
+    let pikachu = pokemon_setup!("Pikachu" 20 (moves ["ThunderShock"], max ivs, Serious));
+    let pidgey = pokemon_setup!("Pidgey" 20 (moves ["Gust"], max ivs, Serious));
+
+    let mut backend = create_trainer_battle(vec![diglett], vec![pikachu, pidgey]);
+    let _ = backend.tick();
+
+    match choose_action(&mut LookaheadAi, &backend, 1) {
+        FrontendEventKind::Switch(0) => {},
+        _ => panic!("Pattern mismatch"),
+    }
+}
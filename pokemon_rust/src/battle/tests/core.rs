@@ -1,6 +1,19 @@
 use crate::{
-    battle::backend::{BattleEvent, StatChangeKind, Team, TypeEffectiveness},
-    pokemon::SimpleStatusCondition,
+    battle::backend::{
+        BattleEndReason,
+        BattleEvent,
+        CaptureRefusalCause,
+        DamageCause,
+        EscapeFailureCause,
+        FieldPosition,
+        FrontendEvent,
+        FrontendEventKind,
+        MoveRestriction,
+        StatChangeKind,
+        Team,
+        TypeEffectiveness,
+    },
+    pokemon::{SimpleStatusCondition, StatusCondition},
 };
 
 use super::{prelude::*, TestMethods};
@@ -161,19 +174,100 @@ fn considers_stat_stages_on_damage_calculation() {
 }
 
 #[test]
-fn causes_random_misses_when_confused() {
+fn makes_confused_pokemon_randomly_hurt_themselves() {
     let mut backend = battle! {
         "Butterfree" 4 (max ivs, Serious) vs "Caterpie" 4 (max ivs, Serious)
     };
 
-    test_rng_mut!(backend.rng).force_confusion_miss(2);
+    test_rng_mut!(backend.rng).force_confusion_self_hit(2);
     let turn1 = backend.process_turn("Supersonic", "Tackle");
     let turn2 = backend.process_turn("Harden", "Tackle");
     let turn3 = backend.process_turn("Harden", "Tackle");
 
-    assert_event!(turn1[3], Miss { target: 0, move_user: 1, caused_by_confusion: true, .. });
-    assert_event!(turn2[3], Miss { target: 0, move_user: 1, caused_by_confusion: true, .. });
-    assert_event!(turn3[3], Damage { target: 0, .. });
+    assert_event!(turn1[2], Damage { target: 1, cause: DamageCause::Confusion, .. });
+    assert_event!(turn2[2], Damage { target: 1, cause: DamageCause::Confusion, .. });
+    assert_event!(turn3[3], Damage { target: 0, cause: DamageCause::Move, .. });
+}
+
+#[test]
+fn confused_pokemon_that_hurt_themselves_do_not_use_their_move() {
+    let mut backend = battle! {
+        "Butterfree" 4 (moves ["Supersonic"], max ivs, Serious)
+        vs
+        "Caterpie" 4 (moves ["Tackle"], max ivs, Serious)
+    };
+
+    let tackle = backend.get_move_index(Team::P2, "Tackle");
+    let initial_pp = backend.get_pokemon(1).pp[tackle];
+
+    test_rng_mut!(backend.rng).force_confusion_self_hit(1);
+    let events = backend.process_turn("Supersonic", "Tackle");
+
+    assert_event!(events[2], Damage { target: 1, cause: DamageCause::Confusion, .. });
+    assert!(!events.iter().any(|event| match event {
+        BattleEvent::UseMove(UseMove { move_user: 1, .. }) => true,
+        _ => false,
+    }));
+    assert_eq!(backend.get_pokemon(1).pp[tackle], initial_pp);
+    assert!(backend.get_used_moves(1).is_empty());
+    assert!(backend.get_move_history().iter().all(|entry| entry.user != 1));
+}
+
+#[test]
+fn confusion_damage_ignores_held_items() {
+    let get_self_hit_damage = |item: Option<&str>| {
+        let mut backend = battle! {
+            "Butterfree" 4 (moves ["Supersonic"], max ivs, Serious)
+            vs
+            "Caterpie" 4 (moves ["Tackle"], max ivs, Serious)
+        };
+
+        backend.get_pokemon_mut(1).held_item = item.map(str::to_string);
+        test_rng_mut!(backend.rng).force_confusion_self_hit(1);
+        let events = backend.process_turn("Supersonic", "Tackle");
+
+        assert_eq!(backend.get_held_item(1), item);
+
+        match &events[2] {
+            BattleEvent::Damage(Damage { target: 1, amount, cause, .. })
+                if *cause == DamageCause::Confusion => *amount,
+            _ => panic!("Pattern mismatch"),
+        }
+    };
+
+    let damage = get_self_hit_damage(None);
+
+    assert_eq!(get_self_hit_damage(Some("ChilanBerry")), damage);
+    assert_eq!(get_self_hit_damage(Some("SilkScarf")), damage);
+}
+
+#[test]
+fn confusion_damage_is_not_prevented_by_magic_guard_or_substitute() {
+    let mut backend = battle! {
+        "Butterfree" 10 (moves ["Supersonic"], max ivs, Serious)
+        vs
+        "Clefairy" 10 (moves ["Substitute", "Pound"], ability "MagicGuard", max ivs, Serious)
+    };
+
+    backend.process_turn("Supersonic", "Substitute");
+    assert!(backend.has_flag(1, "substitute"));
+    let current_hp = backend.get_pokemon(1).current_hp;
+
+    test_rng_mut!(backend.rng).force_confusion_self_hit(1);
+    let events = backend.process_turn("Supersonic", "Pound");
+
+    let damage = events
+        .iter()
+        .find_map(|event| match event {
+            BattleEvent::Damage(Damage { target: 1, amount, cause, .. }) => {
+                if *cause == DamageCause::Confusion { Some(*amount) } else { None }
+            },
+            _ => None,
+        })
+        .unwrap();
+
+    assert!(damage > 0);
+    assert_eq!(backend.get_pokemon(1).current_hp, current_hp - damage);
 }
 
 #[test]
@@ -296,7 +390,7 @@ fn deals_escalating_damage_to_badly_poisoned_pokemon() {
 #[test]
 fn poison_types_cannot_be_badly_poisoned() {
     let mut backend = battle! {
-        "Koffing" 36 (max ivs, Serious) vs "Bulbasaur" 30 (max ivs, Serious)
+        "Koffing" 36 (max ivs, Serious) vs "Bulbasaur" 30 (moves ["RazorLeaf"], max ivs, Serious)
     };
 
     test_rng_mut!(backend.rng).force_secondary_effect(1);
@@ -399,3 +493,1151 @@ fn ice_types_cannot_be_frozen() {
     assert_event!(events[1], Damage { target: 1, .. });
     assert_eq!(backend.get_pokemon(1).status_condition, None);
 }
+
+#[test]
+fn processes_switches_before_moves() {
+    let p1 = pokemon_setup!("Rattata" 10 (max ivs, Serious));
+    let p1_replacement = pokemon_setup!("Pidgey" 10 (max ivs, Serious));
+    let p2 = pokemon_setup!("Caterpie" 10 (max ivs, Serious));
+    let mut backend = create_trainer_battle(vec![p1, p1_replacement], vec![p2]);
+    let _ = backend.tick();
+
+    backend.switch_p1(0);
+    let p2_move = backend.get_move_index(Team::P2, "Tackle");
+    backend.move_p2(p2_move);
+    let events: Vec<_> = backend.tick().collect();
+
+    assert_event!(events[0], SwitchOut { team: Team::P1, slot: 0, pokemon: 0 });
+    assert_event!(events[1], SwitchIn { team: Team::P1, slot: 0, pokemon: 1 });
+    assert_event!(events[2], UseMove { move_user: 2, .. });
+    assert_event!(events[3], Damage { target: 1, .. });
+    assert_eq!(backend.get_active_pokemon(Team::P1).next().unwrap().species_id, "Pidgey");
+    assert_eq!(backend.get_party(Team::P1).next().unwrap().species_id, "Rattata");
+}
+
+#[test]
+fn resets_stat_stages_and_volatile_conditions_when_switching_out() {
+    let p1 = pokemon_setup!("Rattata" 10 (max ivs, Serious));
+    let p1_replacement = pokemon_setup!("Pidgey" 10 (max ivs, Serious));
+    let p2 = pokemon_setup!("Butterfree" 10 (max ivs, Serious));
+    let mut backend = create_trainer_battle(vec![p1, p1_replacement], vec![p2]);
+    let _ = backend.tick();
+
+    let base_defense = backend.get_stat(0, Stat::Defense);
+    backend.process_turn("TailWhip", "Supersonic");
+    assert!(backend.has_flag(0, "confusion"));
+
+    backend.switch_p1(0);
+    let p2_move = backend.get_move_index(Team::P2, "Tackle");
+    backend.move_p2(p2_move);
+    let _ = backend.tick();
+
+    assert!(!backend.has_flag(0, "confusion"));
+    assert_eq!(backend.get_stat(0, Stat::Defense), base_defense);
+}
+
+#[test]
+fn uses_moves_against_the_newly_switched_in_pokemon() {
+    let p1 = pokemon_setup!("Rattata" 10 (max ivs, Serious));
+    let p2 = pokemon_setup!("Caterpie" 10 (max ivs, Serious));
+    let p2_replacement = pokemon_setup!("Pidgey" 10 (max ivs, Serious));
+    let mut backend = create_trainer_battle(vec![p1], vec![p2, p2_replacement]);
+    let _ = backend.tick();
+
+    let p1_move = backend.get_move_index(Team::P1, "Tackle");
+    backend.move_p1(p1_move);
+    backend.switch_p2(0);
+    let events: Vec<_> = backend.tick().collect();
+
+    assert_event!(events[0], SwitchOut { team: Team::P2, slot: 0, pokemon: 1 });
+    assert_event!(events[1], SwitchIn { team: Team::P2, slot: 0, pokemon: 2 });
+    assert_event!(events[3], Damage { target: 2, .. });
+}
+
+#[test]
+fn ends_the_battle_when_a_team_has_no_pokemon_left() {
+    let mut backend = battle! {
+        "Charmander" 30 (max ivs, Serious) vs "Caterpie" 2 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Ember", "Tackle");
+
+    assert_event!(events[2], Faint { target: 1 });
+    assert_event!(events[3], BattleEnd {
+        winner: Team::P1,
+        reason: BattleEndReason::AllPokemonFainted,
+    });
+    assert_eq!(events.len(), 4);
+    assert!(backend.get_outcome().is_some());
+}
+
+#[test]
+fn ends_the_battle_when_the_last_pokemon_faints_from_turn_end_damage() {
+    let mut backend = battle! {
+        "Koffing" 36 (max ivs, Serious) vs "Metapod" 36 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(1).current_hp = 1;
+    let events = backend.process_turn("Toxic", "Harden");
+
+    assert_event!(events[4], Damage { target: 1, .. });
+    assert_event!(events[5], Faint { target: 1 });
+    assert_event!(events[6], BattleEnd { winner: Team::P1, .. });
+}
+
+#[test]
+fn requires_fainted_pokemon_to_be_replaced() {
+    let p1 = pokemon_setup!("Charmander" 30 (max ivs, Serious));
+    let p2 = pokemon_setup!("Caterpie" 2 (max ivs, Serious));
+    let p2_replacement = pokemon_setup!("Pidgey" 10 (max ivs, Serious));
+    let mut backend = create_trainer_battle(vec![p1], vec![p2, p2_replacement]);
+    let _ = backend.tick();
+
+    let turn1 = backend.process_turn("Ember", "Tackle");
+    assert_event!(turn1[2], Faint { target: 1 });
+    assert_event!(turn1[3], ChangeTurn { .. });
+    assert!(!backend.requires_replacement(Team::P1));
+    assert!(backend.requires_replacement(Team::P2));
+
+    let replacement_index = backend
+        .get_party(Team::P2)
+        .position(|pokemon| pokemon.species_id == "Pidgey")
+        .unwrap();
+    backend.switch_p2(replacement_index);
+    let turn2: Vec<_> = backend.tick().collect();
+
+    assert_event!(turn2[0], SwitchIn { team: Team::P2, slot: 0, pokemon: 2 });
+    assert_eq!(turn2.len(), 1);
+    assert!(!backend.requires_replacement(Team::P2));
+}
+
+#[test]
+fn always_escapes_when_faster_than_the_opponent() {
+    let mut backend = battle! {
+        "Rattata" 10 (max ivs, Serious) vs "Metapod" 10 (max ivs, Serious)
+    };
+
+    test_rng_mut!(backend.rng).force_escape_failure(1);
+    backend.run_p1();
+    backend.move_p2(0);
+    let events: Vec<_> = backend.tick().collect();
+
+    assert_event!(events[0], Escaped { team: Team::P1 });
+    assert_event!(events[1], BattleEnd { winner: Team::P2, reason: BattleEndReason::Escaped });
+    assert_eq!(events.len(), 2);
+    assert_eq!(test_rng!(backend.rng).get_last_escape_check_odds(), None);
+}
+
+#[test]
+fn wastes_the_turn_when_failing_to_escape() {
+    let mut backend = battle! {
+        "Metapod" 10 (max ivs, Serious) vs "Rattata" 10 (max ivs, Serious)
+    };
+
+    test_rng_mut!(backend.rng).force_escape_failure(1);
+    backend.run_p1();
+    backend.move_p2(0);
+    let events: Vec<_> = backend.tick().collect();
+
+    assert_event!(events[0], EscapeFailed { team: Team::P1, cause: EscapeFailureCause::BadOdds });
+    assert_event!(events[1], UseMove { move_user: 1, .. });
+    assert!(backend.get_outcome().is_none());
+}
+
+#[test]
+fn increases_the_escape_odds_with_each_attempt() {
+    let mut backend = battle! {
+        "Metapod" 10 (max ivs, Serious) vs "Rattata" 10 (max ivs, Serious)
+    };
+
+    let metapod_speed = backend.get_stat(0, Stat::Speed);
+    let rattata_speed = backend.get_stat(1, Stat::Speed);
+    let base_odds = metapod_speed * 128 / rattata_speed;
+
+    test_rng_mut!(backend.rng).force_escape_failure(1);
+    backend.run_p1();
+    backend.move_p2(0);
+    let _ = backend.tick();
+    assert_eq!(test_rng!(backend.rng).get_last_escape_check_odds(), Some(base_odds + 30));
+
+    backend.run_p1();
+    backend.move_p2(0);
+    let events: Vec<_> = backend.tick().collect();
+    assert_eq!(test_rng!(backend.rng).get_last_escape_check_odds(), Some(base_odds + 60));
+    assert_event!(events[0], Escaped { team: Team::P1 });
+}
+
+#[test]
+fn cannot_escape_from_trainer_battles() {
+    let p1 = pokemon_setup!("Rattata" 10 (max ivs, Serious));
+    let p2 = pokemon_setup!("Metapod" 10 (max ivs, Serious));
+    let mut backend = create_simple_trainer_battle(p1, p2);
+    let _ = backend.tick();
+
+    backend.run_p1();
+    backend.move_p2(0);
+    let events: Vec<_> = backend.tick().collect();
+
+    assert_event!(events[0], EscapeFailed {
+        team: Team::P1,
+        cause: EscapeFailureCause::TrainerBattle,
+    });
+    assert_eq!(events.len(), 1);
+    assert!(!backend.can_escape(Team::P1));
+    assert!(backend.get_outcome().is_none());
+}
+
+#[test]
+fn catches_wild_pokemon_when_every_shake_check_passes() {
+    let mut backend = battle! {
+        "Rattata" 10 (max ivs, Serious) vs "Onix" 10 (max ivs, Serious)
+    };
+
+    backend.throw_pokeball_p1("PokeBall");
+    backend.move_p2(0);
+    let events: Vec<_> = backend.tick().collect();
+
+    assert_event!(events[0], CaptureAttempt { target: 1, shakes: 3, success: true, .. });
+    assert_event!(events[1], BattleEnd {
+        winner: Team::P1,
+        reason: BattleEndReason::PokemonCaught,
+    });
+    assert_eq!(events.len(), 2);
+
+    let caught_pokemon = backend.get_caught_pokemon().unwrap();
+    assert_eq!(caught_pokemon.species_id, "Onix");
+    assert_eq!(caught_pokemon.pokeball, Some("PokeBall".to_string()));
+    assert_eq!(caught_pokemon.met_at_level, 10);
+}
+
+#[test]
+fn wastes_the_turn_when_the_pokemon_breaks_free() {
+    let mut backend = battle! {
+        "Rattata" 10 (max ivs, Serious) vs "Onix" 10 (max ivs, Serious)
+    };
+
+    test_rng_mut!(backend.rng).force_capture_shakes(2);
+    backend.throw_pokeball_p1("PokeBall");
+    backend.move_p2(0);
+    let events: Vec<_> = backend.tick().collect();
+
+    assert_event!(events[0], CaptureAttempt { shakes: 2, success: false, .. });
+    assert_event!(events[1], UseMove { move_user: 1, .. });
+    assert!(backend.get_caught_pokemon().is_none());
+    assert!(backend.get_outcome().is_none());
+}
+
+#[test]
+fn increases_the_catch_rate_of_weakened_pokemon() {
+    let mut backend = battle! {
+        "Rattata" 10 (max ivs, Serious) vs "Onix" 10 (max ivs, Serious)
+    };
+
+    test_rng_mut!(backend.rng).force_capture_shakes(0);
+    backend.throw_pokeball_p1("PokeBall");
+    backend.move_p2(0);
+    let _ = backend.tick();
+    let healthy_odds = test_rng!(backend.rng).get_last_capture_shake_odds().unwrap();
+
+    backend.get_pokemon_mut(1).current_hp = 1;
+    test_rng_mut!(backend.rng).force_capture_shakes(0);
+    backend.throw_pokeball_p1("PokeBall");
+    backend.move_p2(0);
+    let _ = backend.tick();
+    let weakened_odds = test_rng!(backend.rng).get_last_capture_shake_odds().unwrap();
+
+    backend.get_pokemon_mut(1).status_condition = Some(StatusCondition::Sleep {
+        remaining_turns: 3,
+    });
+    test_rng_mut!(backend.rng).force_capture_shakes(0);
+    backend.throw_pokeball_p1("PokeBall");
+    backend.move_p2(0);
+    let _ = backend.tick();
+    let asleep_odds = test_rng!(backend.rng).get_last_capture_shake_odds().unwrap();
+
+    assert!(healthy_odds < weakened_odds);
+    assert!(weakened_odds < asleep_odds);
+}
+
+#[test]
+fn skips_the_shake_checks_when_the_capture_is_guaranteed() {
+    let mut backend = battle! {
+        "Rattata" 10 (max ivs, Serious) vs "Onix" 10 (max ivs, Serious)
+    };
+
+    test_rng_mut!(backend.rng).force_capture_shakes(0);
+    backend.throw_pokeball_p1("MasterBall");
+    backend.move_p2(0);
+    let events: Vec<_> = backend.tick().collect();
+
+    assert_event!(events[0], CaptureAttempt { shakes: 3, success: true, .. });
+    assert_eq!(test_rng!(backend.rng).get_last_capture_shake_odds(), None);
+}
+
+#[test]
+fn wastes_the_turn_when_throwing_an_unknown_pokeball() {
+    let mut backend = battle! {
+        "Rattata" 10 (max ivs, Serious) vs "Onix" 10 (max ivs, Serious)
+    };
+
+    backend.throw_pokeball_p1("Potion");
+    backend.move_p2(0);
+    let events: Vec<_> = backend.tick().collect();
+
+    assert_event!(events[0], InvalidPokeball { team: Team::P1, .. });
+    assert!(backend.get_outcome().is_none());
+    assert_eq!(test_rng!(backend.rng).get_last_capture_shake_odds(), None);
+}
+
+#[test]
+fn cannot_throw_pokeballs_in_trainer_battles() {
+    let p1 = pokemon_setup!("Rattata" 10 (max ivs, Serious));
+    let p2 = pokemon_setup!("Onix" 10 (max ivs, Serious));
+    let mut backend = create_simple_trainer_battle(p1, p2);
+    let _ = backend.tick();
+
+    backend.throw_pokeball_p1("PokeBall");
+    backend.move_p2(0);
+    let events: Vec<_> = backend.tick().collect();
+
+    assert_event!(events[0], CaptureRefused {
+        team: Team::P1,
+        cause: CaptureRefusalCause::TrainerBattle,
+    });
+    assert_eq!(events.len(), 1);
+    assert!(!backend.can_capture(Team::P1));
+    assert!(backend.get_outcome().is_none());
+}
+
+#[test]
+fn sends_out_two_pokemon_per_team_in_double_battles() {
+    let p1 = vec![pokemon_setup!("Rattata" 10), pokemon_setup!("Pidgey" 10)];
+    let p2 = vec![pokemon_setup!("Caterpie" 10), pokemon_setup!("Weedle" 10)];
+    let mut backend = create_double_battle(p1, p2);
+    let events: Vec<_> = backend.tick().collect();
+
+    assert_event!(events[0], InitialSwitchIn { team: Team::P2, slot: 0, pokemon: 2, .. });
+    assert_event!(events[1], InitialSwitchIn { team: Team::P2, slot: 1, pokemon: 3, .. });
+    assert_event!(events[2], InitialSwitchIn { team: Team::P1, slot: 0, pokemon: 0, .. });
+    assert_event!(events[3], InitialSwitchIn { team: Team::P1, slot: 1, pokemon: 1, .. });
+    assert_eq!(backend.get_active_positions().len(), 4);
+}
+
+#[test]
+fn reduces_the_damage_of_moves_that_hit_multiple_targets() {
+    let get_damage_amounts = |events: Vec<BattleEvent>| -> Vec<usize> {
+        events
+            .into_iter()
+            .filter_map(|event| match event {
+                BattleEvent::Damage(Damage { amount, .. }) => Some(amount),
+                _ => None,
+            })
+            .collect()
+    };
+
+    let mut single_battle = battle! {
+        "Squirtle" 13 (max ivs, Serious) vs "Rattata" 10 (max ivs, Serious)
+    };
+    let single_target_damage = get_damage_amounts(single_battle.process_turn("Bubble", "TailWhip"));
+
+    let p1 = vec![
+        pokemon_setup!("Squirtle" 13 (max ivs, Serious)),
+        pokemon_setup!("Rattata" 10 (max ivs, Serious)),
+    ];
+    let p2 = vec![
+        pokemon_setup!("Rattata" 10 (max ivs, Serious)),
+        pokemon_setup!("Rattata" 10 (max ivs, Serious)),
+    ];
+    let mut double_battle = create_double_battle(p1, p2);
+    let _ = double_battle.tick();
+
+    double_battle.use_move(position(Team::P1, 0), "Bubble", None);
+    double_battle.use_move(position(Team::P1, 1), "TailWhip", None);
+    double_battle.use_move(position(Team::P2, 0), "TailWhip", None);
+    double_battle.use_move(position(Team::P2, 1), "TailWhip", None);
+    let spread_damage = get_damage_amounts(double_battle.tick().collect());
+
+    assert_eq!(single_target_damage.len(), 1);
+    assert_eq!(spread_damage.len(), 2);
+    assert_eq!(spread_damage[0], (0.75 * single_target_damage[0] as f32) as usize);
+    assert_eq!(spread_damage[0], spread_damage[1]);
+}
+
+#[test]
+fn hits_the_chosen_target_in_double_battles() {
+    let p1 = vec![
+        pokemon_setup!("Rattata" 10 (max ivs, Serious)),
+        pokemon_setup!("Rattata" 10 (max ivs, Serious)),
+    ];
+    let p2 = vec![pokemon_setup!("Rattata" 10), pokemon_setup!("Rattata" 10)];
+    let mut backend = create_double_battle(p1, p2);
+    let _ = backend.tick();
+
+    backend.use_move(position(Team::P1, 0), "Tackle", Some(position(Team::P2, 1)));
+    backend.use_move(position(Team::P1, 1), "Tackle", Some(position(Team::P1, 0)));
+    backend.use_move(position(Team::P2, 0), "TailWhip", None);
+    backend.use_move(position(Team::P2, 1), "TailWhip", None);
+    let events: Vec<_> = backend.tick().collect();
+
+    let damaged_pokemon: Vec<_> = events
+        .iter()
+        .filter_map(|event| match event {
+            BattleEvent::Damage(Damage { target, .. }) => Some(*target),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(damaged_pokemon, vec![3, 0]);
+}
+
+#[test]
+fn redirects_moves_whose_target_has_fainted() {
+    let p1 = vec![
+        pokemon_setup!("Rattata" 12 (max ivs, Serious)),
+        pokemon_setup!("Rattata" 10 (max ivs, Serious)),
+    ];
+    let p2 = vec![
+        pokemon_setup!("Rattata" 10 (max ivs, Serious)),
+        pokemon_setup!("Rattata" 10 (max ivs, Serious)),
+    ];
+    let mut backend = create_double_battle(p1, p2);
+    let _ = backend.tick();
+
+    backend.get_pokemon_mut(2).current_hp = 1;
+    backend.use_move(position(Team::P1, 0), "Tackle", Some(position(Team::P2, 0)));
+    backend.use_move(position(Team::P1, 1), "Tackle", Some(position(Team::P2, 0)));
+    backend.use_move(position(Team::P2, 0), "TailWhip", None);
+    backend.use_move(position(Team::P2, 1), "TailWhip", None);
+    let events: Vec<_> = backend.tick().collect();
+
+    assert_event!(events[1], Damage { target: 2, .. });
+    assert_event!(events[2], Faint { target: 2 });
+    assert_event!(events[4], Damage { target: 3, .. });
+    assert!(!backend.requires_replacement(Team::P2));
+    assert_eq!(backend.get_active_positions().len(), 3);
+}
+
+#[test]
+fn replaces_fainted_pokemon_in_the_emptied_slot() {
+    let p1 = vec![
+        pokemon_setup!("Rattata" 12 (max ivs, Serious)),
+        pokemon_setup!("Rattata" 10 (max ivs, Serious)),
+    ];
+    let p2 = vec![
+        pokemon_setup!("Rattata" 10 (max ivs, Serious)),
+        pokemon_setup!("Rattata" 10 (max ivs, Serious)),
+        pokemon_setup!("Pidgey" 10 (max ivs, Serious)),
+    ];
+    let mut backend = create_double_battle(p1, p2);
+    let _ = backend.tick();
+
+    backend.get_pokemon_mut(2).current_hp = 1;
+    backend.use_move(position(Team::P1, 0), "Tackle", Some(position(Team::P2, 0)));
+    backend.use_move(position(Team::P1, 1), "TailWhip", None);
+    backend.use_move(position(Team::P2, 0), "TailWhip", None);
+    backend.use_move(position(Team::P2, 1), "TailWhip", None);
+    let _ = backend.tick();
+
+    assert!(backend.requires_replacement(Team::P2));
+
+    let replacement_index = backend
+        .get_party(Team::P2)
+        .position(|pokemon| pokemon.species_id == "Pidgey")
+        .unwrap();
+    backend.push_frontend_event(FrontendEvent {
+        team: Team::P2,
+        slot: 0,
+        event: FrontendEventKind::Switch(replacement_index),
+    });
+    let events: Vec<_> = backend.tick().collect();
+
+    assert_event!(events[0], SwitchIn { team: Team::P2, slot: 0, pokemon: 4 });
+    assert_eq!(backend.get_pokemon_at(position(Team::P2, 0)), Some(4));
+    assert_eq!(backend.get_pokemon_at(position(Team::P2, 1)), Some(3));
+}
+
+#[test]
+fn boosts_water_moves_and_weakens_fire_moves_in_rain() {
+    let mut backend = battle! {
+        "Pikachu" 30 (moves ["RainDance", "WaterGun", "Ember"], max ivs, Serious)
+        vs
+        "Metapod" 50 (max ivs, Serious)
+    };
+
+    let turn1 = backend.process_turn("WaterGun", "Harden");
+    let turn2 = backend.process_turn("Ember", "Harden");
+    backend.process_turn("RainDance", "Harden");
+    let turn4 = backend.process_turn("WaterGun", "Harden");
+    let turn5 = backend.process_turn("Ember", "Harden");
+
+    match (&turn1[1], &turn2[1], &turn4[1], &turn5[1]) {
+        (
+            BattleEvent::Damage(Damage { amount: water1, .. }),
+            BattleEvent::Damage(Damage { amount: fire1, .. }),
+            BattleEvent::Damage(Damage { amount: water2, .. }),
+            BattleEvent::Damage(Damage { amount: fire2, .. }),
+        ) => {
+            assert_eq!(*water2, (1.5 * *water1 as f32) as usize);
+            assert_eq!(*fire2, (0.5 * *fire1 as f32) as usize);
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+}
+
+#[test]
+fn ends_weather_after_five_turns() {
+    let mut backend = battle! {
+        "Pikachu" 30 (moves ["RainDance", "Growl"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    backend.process_turn("RainDance", "Harden");
+
+    for _ in 0..3 {
+        let events = backend.process_turn("Growl", "Harden");
+        assert_eq!(events.len(), 5);
+    }
+
+    assert_eq!(backend.get_weather(), Some(Weather::Rain));
+
+    let events = backend.process_turn("Growl", "Harden");
+
+    assert_event!(events[4], WeatherEnd { weather: Weather::Rain });
+    assert_event!(events[5], ChangeTurn { .. });
+    assert_eq!(backend.get_weather(), None);
+}
+
+#[test]
+fn hurts_pokemon_that_are_not_immune_to_sandstorm() {
+    let mut backend = battle! {
+        "Onix" 30 (moves ["Sandstorm"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Sandstorm", "Harden");
+    let max_hp = backend.get_pokemon(1).stats[Stat::HP as usize];
+
+    assert_event!(events[1], WeatherStart { weather: Weather::Sandstorm });
+    assert_event!(events[4], WeatherDamage { target: 1, weather: Weather::Sandstorm });
+    assert_event!(events[5], Damage { target: 1, cause: DamageCause::Weather, .. });
+
+    match &events[5] {
+        BattleEvent::Damage(Damage { amount, .. }) => {
+            assert_eq!(*amount, (max_hp as f32 / 16.).ceil() as usize);
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+    assert_event!(events[6], ChangeTurn { .. });
+}
+
+#[test]
+fn hurts_pokemon_that_are_not_immune_to_hail() {
+    let mut backend = battle! {
+        "Pikachu" 30 (moves ["Hail"], max ivs, Serious)
+        vs
+        "Jynx" 30 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Hail", "Lick");
+
+    assert_event!(events[3], WeatherStart { weather: Weather::Hail });
+    assert_event!(events[4], WeatherDamage { target: 0, weather: Weather::Hail });
+    assert_event!(events[5], Damage { target: 0, cause: DamageCause::Weather, .. });
+    assert_event!(events[6], ChangeTurn { .. });
+}
+
+#[test]
+fn raises_the_special_defense_of_rock_pokemon_in_sandstorm() {
+    let mut backend = battle! {
+        "Pikachu" 30 (moves ["Sandstorm"], max ivs, Serious)
+        vs
+        "Onix" 30 (moves ["Harden"], max ivs, Serious)
+    };
+
+    let special_defense = backend.get_stat(1, Stat::SpecialDefense);
+    backend.process_turn("Sandstorm", "Harden");
+
+    assert_eq!(backend.get_stat(1, Stat::SpecialDefense), special_defense * 3 / 2);
+}
+
+#[test]
+fn lowers_the_attack_of_foes_with_intimidate() {
+    let mut backend = battle_setup!("Gyarados" 20 vs "Pikachu" 20);
+    let attack = backend.get_stat(1, Stat::Attack);
+    let events: Vec<_> = backend.tick().collect();
+
+    assert_event!(events[2], AbilityActivated { pokemon: 0, .. });
+    assert_event!(events[3], StatChange { target: 1, stat: Stat::Attack, .. });
+    assert_eq!(backend.get_stat(1, Stat::Attack), attack * 2 / 3);
+}
+
+#[test]
+fn blocks_stat_drops_with_clear_body() {
+    let mut backend = battle! {
+        "Pikachu" 20 (moves ["Growl"], max ivs, Serious)
+        vs
+        "Tentacool" 20 (moves ["Supersonic"], max ivs, Serious)
+    };
+
+    let attack = backend.get_stat(1, Stat::Attack);
+    let events = backend.process_turn("Growl", "Supersonic");
+
+    assert_event!(events[1], AbilityActivated { pokemon: 1, .. });
+    assert_eq!(backend.get_stat(1, Stat::Attack), attack);
+}
+
+#[test]
+fn paralyzes_attackers_that_make_contact_with_static() {
+    let mut backend = battle! {
+        "Rattata" 20 (moves ["Tackle"], max ivs, Serious)
+        vs
+        "Pikachu" 20 (moves ["Growl"], max ivs, Serious)
+    };
+
+    test_rng_mut!(backend.rng).force_ability_activation(1);
+    let events = backend.process_turn("Tackle", "Growl");
+
+    assert_event!(events[3], Damage { target: 1, .. });
+    assert_event!(events[4], AbilityActivated { pokemon: 1, .. });
+    assert_event!(events[5], NonVolatileStatusCondition { target: 0, .. });
+    assert_eq!(backend.get_pokemon(0).status_condition, Some(StatusCondition::Paralysis));
+}
+
+#[test]
+fn inflicts_random_status_conditions_on_contact_with_effect_spore() {
+    let mut backend = battle! {
+        "Rattata" 20 (moves ["Tackle"], max ivs, Serious)
+        vs
+        "Paras" 20 (moves ["Growl"], max ivs, Serious)
+    };
+
+    test_rng_mut!(backend.rng).force_ability_activation(1);
+    test_rng_mut!(backend.rng).force_effect_spore_condition(SimpleStatusCondition::Sleep);
+    test_rng_mut!(backend.rng).force_sleep_duration(2);
+    backend.process_turn("Tackle", "Growl");
+
+    assert_eq!(
+        backend.get_pokemon(0).status_condition,
+        Some(StatusCondition::Sleep { remaining_turns: 2 })
+    );
+}
+
+#[test]
+fn grants_immunity_to_ground_moves_with_levitate() {
+    let mut backend = battle! {
+        "Koffing" 20 (moves ["Smog"], max ivs, Serious)
+        vs
+        "Diglett" 20 (moves ["Fissure"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Smog", "Fissure");
+
+    assert_event!(events[0], UseMove { move_user: 1, .. });
+    assert_event!(events[1], AbilityActivated { pokemon: 0, .. });
+    assert_event!(events[2], UseMove { move_user: 0, .. });
+    assert_ne!(backend.get_pokemon(0).current_hp, 0);
+}
+
+#[test]
+fn heals_when_hit_by_water_moves_with_water_absorb() {
+    let mut backend = battle! {
+        "Squirtle" 20 (moves ["WaterGun"], max ivs, Serious)
+        vs
+        "Vaporeon" 20 (moves ["Growl"], max ivs, Serious)
+    };
+
+    let max_hp = backend.get_stat(1, Stat::HP);
+    backend.get_pokemon_mut(1).current_hp = max_hp / 2;
+    let events = backend.process_turn("WaterGun", "Growl");
+
+    assert_event!(events[3], AbilityActivated { pokemon: 1, .. });
+    assert_event!(events[4], Heal { target: 1, .. });
+    assert_eq!(backend.get_pokemon(1).current_hp, max_hp / 2 + max_hp / 4);
+}
+
+#[test]
+fn survives_a_hit_at_full_hp_with_sturdy() {
+    let mut backend = battle! {
+        "Squirtle" 50 (moves ["WaterGun"], max ivs, Serious)
+        vs
+        "Onix" 5 (ability "Sturdy", moves ["Harden"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("WaterGun", "Harden");
+
+    assert_event!(events[1], Damage { target: 1, .. });
+    assert_event!(events[2], AbilityActivated { pokemon: 1, .. });
+    assert_eq!(backend.get_pokemon(1).current_hp, 1);
+}
+
+#[test]
+fn starts_weather_on_switch_in_with_drought() {
+    let backend = battle! {
+        "Vulpix" 20 (ability "Drought", max ivs, Serious)
+        vs
+        "Pikachu" 20 (max ivs, Serious)
+    };
+
+    assert_eq!(backend.get_weather(), Some(Weather::Sun));
+}
+
+#[test]
+fn always_escapes_with_run_away() {
+    let mut backend = battle! {
+        "Rattata" 3 (ability "RunAway", max ivs, Serious)
+        vs
+        "Pikachu" 50 (max ivs, Serious)
+    };
+
+    test_rng_mut!(backend.rng).force_escape_failure(1);
+    backend.run_p1();
+    backend.move_p2(0);
+    let events: Vec<_> = backend.tick().collect();
+
+    assert_event!(events[0], Escaped { team: Team::P1 });
+}
+
+#[test]
+fn restores_hp_at_the_end_of_the_turn_with_leftovers() {
+    let mut backend = battle! {
+        "Pikachu" 20 (moves ["Growl"], max ivs, Serious)
+        vs
+        "Slowpoke" 20 (item "Leftovers", moves ["Growl"], max ivs, Serious)
+    };
+
+    let max_hp = backend.get_stat(1, Stat::HP);
+    backend.get_pokemon_mut(1).current_hp = max_hp / 2;
+    let events = backend.process_turn("Growl", "Growl");
+
+    assert_event!(events[4], ItemActivated { pokemon: 1, .. });
+    assert_event!(events[5], Heal { target: 1, .. });
+    assert_event!(events[6], ChangeTurn { .. });
+    assert_eq!(backend.get_pokemon(1).current_hp, max_hp / 2 + max_hp / 16);
+    assert_eq!(backend.get_held_item(1), Some("Leftovers"));
+}
+
+#[test]
+fn boosts_moves_of_the_matching_type_with_type_enhancing_items() {
+    let mut plain = battle! {
+        "Charmander" 20 (moves ["Ember"], max ivs, Serious)
+        vs
+        "Slowpoke" 20 (moves ["Growl"], max ivs, Serious)
+    };
+    let mut boosted = battle! {
+        "Charmander" 20 (item "Charcoal", moves ["Ember"], max ivs, Serious)
+        vs
+        "Slowpoke" 20 (moves ["Growl"], max ivs, Serious)
+    };
+
+    let plain_events = plain.process_turn("Ember", "Growl");
+    let boosted_events = boosted.process_turn("Ember", "Growl");
+
+    match (&plain_events[1], &boosted_events[1]) {
+        (
+            BattleEvent::Damage(Damage { amount: plain_damage, .. }),
+            BattleEvent::Damage(Damage { amount: boosted_damage, .. }),
+        ) => {
+            assert!(boosted_damage > plain_damage);
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+}
+
+#[test]
+fn weakens_super_effective_moves_and_consumes_type_resist_berries() {
+    let mut plain = battle! {
+        "Charmander" 20 (moves ["Ember"], max ivs, Serious)
+        vs
+        "Bulbasaur" 20 (moves ["Growl"], max ivs, Serious)
+    };
+    let mut resisted = battle! {
+        "Charmander" 20 (moves ["Ember"], max ivs, Serious)
+        vs
+        "Bulbasaur" 20 (item "OccaBerry", moves ["Growl"], max ivs, Serious)
+    };
+
+    let plain_events = plain.process_turn("Ember", "Growl");
+    let resisted_events = resisted.process_turn("Ember", "Growl");
+
+    assert_event!(resisted_events[2], ItemConsumed { pokemon: 1, .. });
+
+    match (&plain_events[1], &resisted_events[1]) {
+        (
+            BattleEvent::Damage(Damage { amount: plain_damage, .. }),
+            BattleEvent::Damage(Damage { amount: resisted_damage, .. }),
+        ) => {
+            assert!(resisted_damage < plain_damage);
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+
+    assert_eq!(resisted.get_held_item(1), None);
+}
+
+#[test]
+fn does_not_consume_type_resist_berries_when_a_substitute_takes_the_hit() {
+    let mut backend = battle! {
+        "Kadabra" 30 (item "ChilanBerry", moves ["Substitute"], max ivs, Serious)
+        vs
+        "Rattata" 10 (moves ["Tackle"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Substitute", "Tackle");
+
+    assert_event!(events[4], SubstituteDamaged { pokemon: 0, .. });
+    assert_eq!(backend.get_held_item(0), Some("ChilanBerry"));
+}
+
+#[test]
+fn typeless_moves_are_not_affected_by_type_items() {
+    let mut plain = battle! {
+        "Rattata" 10 (moves ["Tackle"], max ivs, Serious)
+        vs
+        "Metapod" 10 (max ivs, Serious)
+    };
+    let mut boosted = battle! {
+        "Rattata" 10 (item "SilkScarf", moves ["Tackle"], max ivs, Serious)
+        vs
+        "Metapod" 10 (item "ChilanBerry", max ivs, Serious)
+    };
+
+    plain.get_pokemon_mut(0).pp[0] = 0;
+    boosted.get_pokemon_mut(0).pp[0] = 0;
+    let plain_events = plain.process_turn("Tackle", "Harden");
+    let boosted_events = boosted.process_turn("Tackle", "Harden");
+
+    match (&plain_events[1], &boosted_events[1]) {
+        (
+            BattleEvent::Damage(Damage { amount: plain_damage, .. }),
+            BattleEvent::Damage(Damage { amount: boosted_damage, .. }),
+        ) => {
+            assert_eq!(boosted_damage, plain_damage);
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+
+    assert_eq!(boosted.get_held_item(1), Some("ChilanBerry"));
+}
+
+#[test]
+fn cures_status_conditions_with_status_berries() {
+    let mut backend = battle! {
+        "Pikachu" 20 (moves ["ThunderWave"], max ivs, Serious)
+        vs
+        "Slowpoke" 20 (item "CheriBerry", moves ["Growl"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("ThunderWave", "Growl");
+
+    assert_event!(events[1], NonVolatileStatusCondition { target: 1, .. });
+    assert_event!(events[2], ItemConsumed { pokemon: 1, .. });
+    assert_event!(events[3], ExpiredNonVolatileStatusCondition { target: 1, .. });
+    assert_eq!(backend.get_pokemon(1).status_condition, None);
+    assert_eq!(backend.get_held_item(1), None);
+}
+
+#[test]
+fn locks_the_holder_of_choice_items_into_the_first_move_used() {
+    let mut backend = battle! {
+        "Charmander" 20 (item "ChoiceBand", moves ["Scratch", "Ember"], max ivs, Serious)
+        vs
+        "Slowpoke" 20 (moves ["Growl"], max ivs, Serious)
+    };
+
+    backend.process_turn("Scratch", "Growl");
+    let events = backend.process_turn("Ember", "Growl");
+
+    match &events[0] {
+        BattleEvent::UseMove(UseMove { move_user, move_name }) => {
+            assert_eq!(*move_user, 0);
+            assert_eq!(move_name, "Scratch");
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+}
+
+#[test]
+fn applies_primary_effects_without_rolling_for_them() {
+    let mut backend = battle! {
+        "Pikachu" 20 (moves ["Growl"], max ivs, Serious)
+        vs
+        "Rattata" 20 (moves ["Harden"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Growl", "Harden");
+
+    assert_event!(events[1], StatChange { target: 1, kind: StatChangeKind::Fell, .. });
+    assert_eq!(test_rng!(backend.rng).get_last_secondary_effect_check_chance(), None);
+}
+
+#[test]
+fn fails_status_moves_whose_primary_effect_would_do_nothing() {
+    let mut backend = battle! {
+        "Pikachu" 20 (moves ["PoisonPowder"], max ivs, Serious)
+        vs
+        "Koffing" 20 (moves ["Harden"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("PoisonPowder", "Harden");
+
+    assert_event!(events[0], UseMove { move_user: 0, .. });
+    assert_event!(events[1], FailedMove { move_user: 0 });
+    assert_eq!(backend.get_pokemon(1).status_condition, None);
+}
+
+#[test]
+fn hurts_draining_pokemon_with_liquid_ooze() {
+    let mut backend = battle! {
+        "Oddish" 20 (moves ["Absorb"], max ivs, Serious)
+        vs
+        "Tentacool" 20 (ability "LiquidOoze", moves ["Harden"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Absorb", "Harden");
+
+    assert_event!(events[3], Damage { target: 1, cause: DamageCause::Move, .. });
+    assert_event!(events[4], AbilityActivated { pokemon: 1, .. });
+    assert_event!(events[5], Damage { target: 0, cause: DamageCause::Ability, .. });
+}
+
+#[test]
+fn prevents_recoil_with_rock_head() {
+    let mut backend = battle! {
+        "Onix" 20 (moves ["DoubleEdge"], max ivs, Serious)
+        vs
+        "Slowpoke" 20 (moves ["Harden"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("DoubleEdge", "Harden");
+
+    assert_event!(events[1], Damage { target: 1, .. });
+    assert_event!(events[2], UseMove { move_user: 1, .. });
+    assert_eq!(backend.get_pokemon(0).current_hp, backend.get_stat(0, Stat::HP));
+}
+
+#[test]
+fn prevents_trapped_pokemon_from_switching_out() {
+    let p1 = pokemon_setup!("Metapod" 10 (max ivs, Serious));
+    let p1_replacement = pokemon_setup!("Pidgey" 10 (max ivs, Serious));
+    let p2 = pokemon_setup!("Ekans" 10 (moves ["Wrap"], max ivs, Serious));
+    let mut backend = create_trainer_battle(vec![p1, p1_replacement], vec![p2]);
+    let _ = backend.tick();
+
+    backend.process_turn("Harden", "Wrap");
+    backend.switch_p1(0);
+    backend.move_p2(0);
+    let events: Vec<_> = backend.tick().collect();
+
+    assert_event!(events[0], EscapeFailed { team: Team::P1, cause: EscapeFailureCause::Trapped });
+    assert_eq!(events.len(), 1);
+    assert_eq!(backend.get_pokemon_at(position(Team::P1, 0)), Some(0));
+}
+
+#[test]
+fn frees_trapped_pokemon_when_the_trapper_switches_out() {
+    let p1 = pokemon_setup!("Ekans" 10 (moves ["Wrap"], max ivs, Serious));
+    let p1_replacement = pokemon_setup!("Pidgey" 10 (max ivs, Serious));
+    let p2 = pokemon_setup!("Metapod" 10 (max ivs, Serious));
+    let mut backend = create_trainer_battle(vec![p1, p1_replacement], vec![p2]);
+    let _ = backend.tick();
+
+    backend.process_turn("Wrap", "Harden");
+    assert!(backend.is_trapped(2));
+
+    backend.switch_p1(0);
+    let p2_move = backend.get_move_index(Team::P2, "Harden");
+    backend.move_p2(p2_move);
+    let _ = backend.tick();
+
+    assert!(!backend.is_trapped(2));
+}
+
+#[test]
+fn arena_trap_prevents_grounded_foes_from_fleeing() {
+    let mut backend = battle! {
+        "Rattata" 10 (max ivs, Serious)
+        vs
+        "Diglett" 10 (ability "ArenaTrap", max ivs, Serious)
+    };
+
+    backend.run_p1();
+    backend.move_p2(0);
+    let events: Vec<_> = backend.tick().collect();
+
+    assert_event!(events[0], EscapeFailed { team: Team::P1, cause: EscapeFailureCause::Trapped });
+    assert!(backend.get_outcome().is_none());
+}
+
+#[test]
+fn arena_trap_does_not_affect_flying_pokemon() {
+    let backend = battle! {
+        "Pidgey" 10 (max ivs, Serious)
+        vs
+        "Diglett" 10 (ability "ArenaTrap", max ivs, Serious)
+    };
+
+    assert!(!backend.is_trapped(0));
+}
+
+#[test]
+fn magic_guard_prevents_trap_damage() {
+    let mut backend = battle! {
+        "Ekans" 10 (moves ["Wrap"], max ivs, Serious)
+        vs
+        "Clefairy" 10 (moves ["Growl"], ability "MagicGuard", max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Wrap", "Growl");
+
+    assert!(backend.is_trapped(1));
+    assert!(!events.iter().any(|event| match event {
+        BattleEvent::Damage(Damage { cause: DamageCause::Trap, .. }) => true,
+        _ => false,
+    }));
+}
+
+#[test]
+fn forgets_the_received_damage_at_the_end_of_the_turn() {
+    let mut backend = battle! {
+        "Rattata" 10 (moves ["Tackle"], max ivs, Serious)
+        vs
+        "Metapod" 10 (max ivs, Serious)
+    };
+
+    backend.process_turn("Tackle", "Harden");
+
+    assert!(backend.get_received_damage(1).is_none());
+}
+
+#[test]
+fn records_the_moves_used_in_the_battle() {
+    let mut backend = battle! {
+        "Pidgey" 10 (moves ["Tackle"], max ivs, Serious)
+        vs
+        "Metapod" 10 (max ivs, Serious)
+    };
+
+    backend.process_turn("Tackle", "Harden");
+
+    let history: Vec<_> = backend
+        .get_move_history()
+        .iter()
+        .map(|entry| (entry.user, entry.move_id.as_str()))
+        .collect();
+    assert_eq!(history, vec![(0, "Tackle"), (1, "Harden")]);
+    assert_eq!(backend.get_last_used_move(0).map(String::as_str), Some("Tackle"));
+}
+
+#[test]
+fn forgets_the_moves_used_by_a_pokemon_when_it_switches_out() {
+    let p1 = pokemon_setup!("Pidgey" 10 (moves ["Tackle"], max ivs, Serious));
+    let p1_replacement = pokemon_setup!("Rattata" 10 (max ivs, Serious));
+    let p2 = pokemon_setup!("Metapod" 10 (max ivs, Serious));
+    let mut backend = create_trainer_battle(vec![p1, p1_replacement], vec![p2]);
+    let _ = backend.tick();
+
+    backend.process_turn("Tackle", "Harden");
+    assert_eq!(backend.get_used_moves(0).len(), 1);
+
+    backend.switch_p1(0);
+    let p2_move = backend.get_move_index(Team::P2, "Harden");
+    backend.move_p2(p2_move);
+    let _ = backend.tick();
+
+    assert!(backend.get_used_moves(0).is_empty());
+    assert_eq!(backend.get_move_history().len(), 3);
+}
+
+#[test]
+fn spends_a_pp_every_time_a_move_is_used() {
+    let mut backend = battle! {
+        "Rattata" 10 (moves ["Tackle"], max ivs, Serious)
+        vs
+        "Metapod" 10 (max ivs, Serious)
+    };
+
+    let tackle = backend.get_move_index(Team::P1, "Tackle");
+    let initial_pp = backend.get_pokemon(0).pp[tackle];
+
+    backend.process_turn("Tackle", "Harden");
+    backend.process_turn("Tackle", "Harden");
+
+    assert_eq!(backend.get_pokemon(0).pp[tackle], initial_pp - 2);
+}
+
+#[test]
+fn spends_a_single_pp_for_charging_moves() {
+    let mut backend = battle! {
+        "Bulbasaur" 30 (moves ["SolarBeam"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    let solar_beam = backend.get_move_index(Team::P1, "SolarBeam");
+    let initial_pp = backend.get_pokemon(0).pp[solar_beam];
+
+    backend.process_turn("SolarBeam", "Harden");
+    backend.process_turn("SolarBeam", "Harden");
+
+    assert_eq!(backend.get_pokemon(0).pp[solar_beam], initial_pp - 1);
+}
+
+#[test]
+fn restricts_moves_without_pp_left() {
+    let mut backend = battle! {
+        "Rattata" 10 (moves ["Tackle", "TailWhip"], max ivs, Serious)
+        vs
+        "Metapod" 10 (max ivs, Serious)
+    };
+
+    let tackle = backend.get_move_index(Team::P1, "Tackle");
+    backend.get_pokemon_mut(0).pp[tackle] = 0;
+
+    assert_eq!(backend.get_move_restriction(0, tackle), Some(MoveRestriction::NoPp));
+    assert!(!backend.must_use_struggle(0));
+}
+
+#[test]
+fn cannot_use_moves_without_pp_left() {
+    let mut backend = battle! {
+        "Rattata" 10 (moves ["Tackle", "TailWhip"], max ivs, Serious)
+        vs
+        "Metapod" 10 (max ivs, Serious)
+    };
+
+    let tackle = backend.get_move_index(Team::P1, "Tackle");
+    backend.get_pokemon_mut(0).pp[tackle] = 0;
+
+    let events = backend.process_turn("Tackle", "Harden");
+
+    assert_event!(events[0], NoPpLeft { pokemon: 0, .. });
+    assert_eq!(events.len(), 1);
+}
+
+#[test]
+fn returns_the_pokemon_of_a_team_in_their_original_order() {
+    let p1 = pokemon_setup!("Rattata" 10 (moves ["Tackle"], max ivs, Serious));
+    let p1_replacement = pokemon_setup!("Pidgey" 10 (max ivs, Serious));
+    let p2 = pokemon_setup!("Metapod" 10 (max ivs, Serious));
+    let mut backend = create_trainer_battle(vec![p1, p1_replacement], vec![p2]);
+    let _ = backend.tick();
+
+    let initial_pp = backend.get_pokemon(0).pp[0];
+    backend.process_turn("Tackle", "Harden");
+    backend.switch_p1(0);
+    backend.move_p2(0);
+    let _ = backend.tick();
+
+    let team: Vec<_> = backend.get_team_pokemon(Team::P1).collect();
+
+    assert_eq!(team.len(), 2);
+    assert_eq!(team[0].species_id, "Rattata");
+    assert_eq!(team[0].pp[0], initial_pp - 1);
+    assert_eq!(team[1].species_id, "Pidgey");
+}
+
+fn position(team: Team, slot: usize) -> FieldPosition {
+    FieldPosition { team, slot }
+}
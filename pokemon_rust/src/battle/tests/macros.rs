@@ -14,6 +14,12 @@ macro_rules! assert_event {
     ($value:expr, InitialSwitchIn { $($args:tt)* }) => {
         assert_pattern!($value, BattleEvent::InitialSwitchIn(InitialSwitchIn { $($args)* }));
     };
+    ($value:expr, SwitchOut { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::SwitchOut(SwitchOut { $($args)* }));
+    };
+    ($value:expr, SwitchIn { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::SwitchIn(SwitchIn { $($args)* }));
+    };
     ($value:expr, ChangeTurn { $($args:tt)* }) => {
         assert_pattern!($value, BattleEvent::ChangeTurn(ChangeTurn { $($args)* }));
     };
@@ -63,6 +69,93 @@ macro_rules! assert_event {
     ($value:expr, Faint { $($args:tt)* }) => {
         assert_pattern!($value, BattleEvent::Faint(Faint { $($args)* }));
     };
+    ($value:expr, Escaped { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::Escaped(Escaped { $($args)* }));
+    };
+    ($value:expr, EscapeFailed { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::EscapeFailed(EscapeFailed { $($args)* }));
+    };
+    ($value:expr, CaptureAttempt { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::CaptureAttempt(CaptureAttempt { $($args)* }));
+    };
+    ($value:expr, InvalidPokeball { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::InvalidPokeball(InvalidPokeball { $($args)* }));
+    };
+    ($value:expr, CaptureRefused { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::CaptureRefused(CaptureRefused { $($args)* }));
+    };
+    ($value:expr, WeatherStart { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::WeatherStart(WeatherStart { $($args)* }));
+    };
+    ($value:expr, WeatherEnd { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::WeatherEnd(WeatherEnd { $($args)* }));
+    };
+    ($value:expr, WeatherDamage { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::WeatherDamage(WeatherDamage { $($args)* }));
+    };
+    ($value:expr, SideConditionStart { $($args:tt)* }) => {
+        assert_pattern!(
+            $value,
+            BattleEvent::SideConditionStart(SideConditionStart { $($args)* })
+        );
+    };
+    ($value:expr, SideConditionEnd { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::SideConditionEnd(SideConditionEnd { $($args)* }));
+    };
+    ($value:expr, AbilityActivated { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::AbilityActivated(AbilityActivated { $($args)* }));
+    };
+    ($value:expr, Heal { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::Heal(Heal { $($args)* }));
+    };
+    ($value:expr, ItemActivated { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::ItemActivated(ItemActivated { $($args)* }));
+    };
+    ($value:expr, ItemConsumed { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::ItemConsumed(ItemConsumed { $($args)* }));
+    };
+    ($value:expr, Protected { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::Protected(Protected { $($args)* }));
+    };
+    ($value:expr, Charging { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::Charging(Charging { $($args)* }));
+    };
+    ($value:expr, Recharging { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::Recharging(Recharging { $($args)* }));
+    };
+    ($value:expr, DisabledMove { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::DisabledMove(DisabledMove { $($args)* }));
+    };
+    ($value:expr, PpReduction { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::PpReduction(PpReduction { $($args)* }));
+    };
+    ($value:expr, NoPpLeft { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::NoPpLeft(NoPpLeft { $($args)* }));
+    };
+    ($value:expr, SubstituteCreated { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::SubstituteCreated(SubstituteCreated { $($args)* }));
+    };
+    ($value:expr, SubstituteDamaged { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::SubstituteDamaged(SubstituteDamaged { $($args)* }));
+    };
+    ($value:expr, SubstituteBroken { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::SubstituteBroken(SubstituteBroken { $($args)* }));
+    };
+    ($value:expr, PerishCount { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::PerishCount(PerishCount { $($args)* }));
+    };
+    ($value:expr, DestinyBond { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::DestinyBond(DestinyBond { $($args)* }));
+    };
+    ($value:expr, StatStagesReset { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::StatStagesReset(StatStagesReset { $($args)* }));
+    };
+    ($value:expr, StatStagesCopied { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::StatStagesCopied(StatStagesCopied { $($args)* }));
+    };
+    ($value:expr, BattleEnd { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::BattleEnd(BattleEnd { $($args)* }));
+    };
 }
 
 macro_rules! assert_pattern {
@@ -106,22 +199,47 @@ macro_rules! battle_setup {
 macro_rules! pokemon_setup {
     ($p1_species:literal $p1_level:literal $(($($p1_data:tt)*))?) => {
         {
-            let p1_builder = PokemonBuilder::default();
-            $(let p1_builder = constrain_pokemon!(p1_builder, $($p1_data)*);)?
-
             let pokedex = get_all_pokemon_species();
             let movedex = get_all_moves();
+            let species = pokedex.get_species($p1_species).unwrap();
+
+            // Abilities are deterministic in tests unless stated otherwise
+            let p1_builder = PokemonBuilder::default().with_ability(species.abilities[0].clone());
+            $(let p1_builder = constrain_pokemon!(p1_builder, $($p1_data)*);)?
 
-            p1_builder.build(
-                &pokedex.get_species($p1_species).unwrap(),
-                &movedex,
-                $p1_level,
-            )
+            p1_builder.build(species, &movedex, $p1_level)
         }
     }
 }
 
 macro_rules! constrain_pokemon {
+    ($builder:ident, moves [$($movement:literal),*], $($data:tt)*) => {
+        {
+            let mut moves: [Option<String>; 4] = Default::default();
+
+            for (i, movement) in [$($movement),*].iter().enumerate() {
+                moves[i] = Some(movement.to_string());
+            }
+
+            let $builder = $builder.with_moves(moves);
+            constrain_pokemon!($builder, $($data)*)
+        }
+    };
+
+    ($builder:ident, ability $ability:literal, $($data:tt)*) => {
+        {
+            let $builder = $builder.with_ability($ability.to_string());
+            constrain_pokemon!($builder, $($data)*)
+        }
+    };
+
+    ($builder:ident, item $item:literal, $($data:tt)*) => {
+        {
+            let $builder = $builder.with_held_item($item.to_string());
+            constrain_pokemon!($builder, $($data)*)
+        }
+    };
+
     ($builder:ident, max ivs$(, $($data:tt)*)*) => {
         {
             let $builder = $builder.with_ivs([31; 6]);
@@ -1,19 +1,26 @@
-use crate::battle::backend::{
-    rng::BattleRng,
-    BattleBackend,
-    BattleEvent,
-    FrontendEvent,
-    FrontendEventKind,
-    Team,
-    UsedMove,
+use crate::{
+    battle::backend::{
+        rng::BattleRng,
+        BattleBackend,
+        BattleEvent,
+        FieldPosition,
+        FrontendEvent,
+        FrontendEventKind,
+        Team,
+        PendingMove,
+    },
+    pokemon::SimpleStatusCondition,
 };
 
 // Must come first
 #[macro_use]
 mod macros;
 
+mod ai;
 mod core;
 mod moves;
+mod replay;
+mod rng;
 
 pub mod prelude {
     // Modules required by the test macros
@@ -21,21 +28,53 @@ pub mod prelude {
         battle::{
             backend::{
                 event::{
+                    AbilityActivated,
+                    BattleEnd,
+                    CaptureAttempt,
+                    CaptureRefused,
                     ChangeTurn,
+                    Charging,
                     Damage,
+                    DestinyBond,
+                    DisabledMove,
+                    EscapeFailed,
+                    Escaped,
                     ExpiredNonVolatileStatusCondition,
                     ExpiredVolatileStatusCondition,
                     FailedMove,
                     Faint,
+                    Heal,
                     InitialSwitchIn,
+                    InvalidPokeball,
+                    ItemActivated,
+                    ItemConsumed,
                     Miss,
+                    NoPpLeft,
                     NonVolatileStatusCondition,
+                    PerishCount,
+                    PpReduction,
+                    Protected,
+                    Recharging,
+                    SideConditionEnd,
+                    SideConditionStart,
                     StatChange,
+                    StatStagesCopied,
+                    StatStagesReset,
+                    SubstituteBroken,
+                    SubstituteCreated,
+                    SubstituteDamaged,
+                    SwitchIn,
+                    SwitchOut,
                     UseMove,
                     VolatileStatusCondition,
+                    WeatherDamage,
+                    WeatherEnd,
+                    WeatherStart,
                 },
                 BattleBackend,
                 Flag,
+                SideCondition,
+                Weather,
             },
             tests::TestRng,
             types::{Battle, BattleCharacterTeam, BattleType, Party},
@@ -50,50 +89,72 @@ pub mod prelude {
     };
 
     use crate::{
+        battle::ai::BattleAiKind,
         overworld::entities::character::CharacterId,
         pokemon::Pokemon,
     };
 
     pub fn create_simple_wild_battle(p1: Pokemon, p2: Pokemon) -> BattleBackend {
-        BattleBackend::new(
-            Battle::new(
-                BattleType::Single,
-                BattleCharacterTeam {
-                    active_pokemon: None,
-                    party: Party {
-                        pokemon: vec![p1].into(),
-                    },
-                    character_id: Some(CharacterId(1)),
+        BattleBackend::new(create_simple_wild_battle_data(p1, p2), Box::new(TestRng::default()))
+    }
+
+    pub fn create_simple_wild_battle_data(p1: Pokemon, p2: Pokemon) -> Battle {
+        Battle::new(
+            BattleType::Single,
+            BattleCharacterTeam {
+                active_pokemon: None,
+                party: Party {
+                    pokemon: vec![p1].into(),
                 },
-                BattleCharacterTeam {
-                    active_pokemon: None,
-                    party: Party {
-                        pokemon: vec![p2].into(),
-                    },
-                    character_id: None,
+                character_id: Some(CharacterId(1)),
+                ai: None,
+            },
+            BattleCharacterTeam {
+                active_pokemon: None,
+                party: Party {
+                    pokemon: vec![p2].into(),
                 },
-            ),
-            Box::new(TestRng::default()),
+                character_id: None,
+                ai: Some(BattleAiKind::Random),
+            },
         )
     }
 
     pub fn create_simple_trainer_battle(p1: Pokemon, p2: Pokemon) -> BattleBackend {
+        create_trainer_battle(vec![p1], vec![p2])
+    }
+
+    pub fn create_trainer_battle(p1_party: Vec<Pokemon>, p2_party: Vec<Pokemon>) -> BattleBackend {
+        create_battle(BattleType::Single, p1_party, p2_party)
+    }
+
+    pub fn create_double_battle(p1_party: Vec<Pokemon>, p2_party: Vec<Pokemon>) -> BattleBackend {
+        create_battle(BattleType::Double, p1_party, p2_party)
+    }
+
+    fn create_battle(
+        battle_type: BattleType,
+        p1_party: Vec<Pokemon>,
+        p2_party: Vec<Pokemon>,
+    ) -> BattleBackend {
         BattleBackend::new(
             Battle::new(
-                BattleType::Single,
+                battle_type,
                 BattleCharacterTeam {
                     active_pokemon: None,
                     party: Party {
-                        pokemon: vec![p1].into(),
+                        pokemon: p1_party.into(),
                     },
                     character_id: Some(CharacterId(1)),
+                    ai: None,
                 },
                 BattleCharacterTeam {
                     active_pokemon: None,
                     party: Party {
-                        pokemon: vec![p2].into(),
+                        pokemon: p2_party.into(),
                     },
                     character_id: Some(CharacterId(2)),
+                    ai: Some(BattleAiKind::Greedy),
                 },
             ),
             Box::new(TestRng::default()),
@@ -104,6 +165,13 @@ pub mod prelude {
 trait TestMethods {
     fn move_p1(&mut self, index: usize);
     fn move_p2(&mut self, index: usize);
+    fn switch_p1(&mut self, party_index: usize);
+    fn switch_p2(&mut self, party_index: usize);
+    fn run_p1(&mut self);
+    fn throw_pokeball_p1(&mut self, pokeball: &str);
+    fn use_move(&mut self, user: FieldPosition, movement: &str, target: Option<FieldPosition>);
+    fn get_move_index(&self, team: Team, movement: &str) -> usize;
+    fn get_move_index_at(&self, position: FieldPosition, movement: &str) -> usize;
     fn process_turn(&mut self, p1_move: &str, p2_move: &str) -> Vec<BattleEvent>;
 }
 
@@ -111,34 +179,75 @@ impl TestMethods for BattleBackend {
     fn move_p1(&mut self, index: usize) {
         self.push_frontend_event(FrontendEvent {
             team: Team::P1,
-            event: FrontendEventKind::UseMove(index),
+            slot: 0,
+            event: FrontendEventKind::UseMove {
+                move_index: index,
+                target: None,
+            },
         });
     }
 
     fn move_p2(&mut self, index: usize) {
         self.push_frontend_event(FrontendEvent {
             team: Team::P2,
-            event: FrontendEventKind::UseMove(index),
+            slot: 0,
+            event: FrontendEventKind::UseMove {
+                move_index: index,
+                target: None,
+            },
         });
     }
 
-    fn process_turn(&mut self, p1_move: &str, p2_move: &str) -> Vec<BattleEvent> {
-        let p1_index = self.p1.active_pokemon.unwrap();
-        let p2_index = self.p2.active_pokemon.unwrap();
+    fn switch_p1(&mut self, party_index: usize) {
+        self.push_frontend_event(FrontendEvent {
+            team: Team::P1,
+            slot: 0,
+            event: FrontendEventKind::Switch(party_index),
+        });
+    }
 
-        let p1_move_index = self.pokemon_repository[&p1_index]
-            .moves
-            .iter()
-            .enumerate()
-            .filter_map(|(i, mov)| match mov {
-                Some(mov) => Some((i, mov)),
-                None => None,
-            })
-            .find(|(_, mov)| mov.as_str() == p1_move)
-            .map(|(i, _)| i)
-            .unwrap_or_else(|| panic!("Move \"{}\" not found for player 1", p1_move));
+    fn switch_p2(&mut self, party_index: usize) {
+        self.push_frontend_event(FrontendEvent {
+            team: Team::P2,
+            slot: 0,
+            event: FrontendEventKind::Switch(party_index),
+        });
+    }
+
+    fn run_p1(&mut self) {
+        self.push_frontend_event(FrontendEvent {
+            team: Team::P1,
+            slot: 0,
+            event: FrontendEventKind::Run,
+        });
+    }
+
+    fn throw_pokeball_p1(&mut self, pokeball: &str) {
+        self.push_frontend_event(FrontendEvent {
+            team: Team::P1,
+            slot: 0,
+            event: FrontendEventKind::ThrowPokeball(pokeball.to_string()),
+        });
+    }
+
+    fn use_move(&mut self, user: FieldPosition, movement: &str, target: Option<FieldPosition>) {
+        let move_index = self.get_move_index_at(user, movement);
+
+        self.push_frontend_event(FrontendEvent {
+            team: user.team,
+            slot: user.slot,
+            event: FrontendEventKind::UseMove { move_index, target },
+        });
+    }
+
+    fn get_move_index(&self, team: Team, movement: &str) -> usize {
+        self.get_move_index_at(FieldPosition { team, slot: 0 }, movement)
+    }
+
+    fn get_move_index_at(&self, position: FieldPosition, movement: &str) -> usize {
+        let pokemon = self.get_pokemon_at(position).unwrap();
 
-        let p2_move_index = self.pokemon_repository[&p2_index]
+        self.pokemon_repository[&pokemon]
             .moves
             .iter()
             .enumerate()
@@ -146,9 +255,14 @@ impl TestMethods for BattleBackend {
                 Some(mov) => Some((i, mov)),
                 None => None,
             })
-            .find(|(_, mov)| mov.as_str() == p2_move)
+            .find(|(_, mov)| mov.as_str() == movement)
             .map(|(i, _)| i)
-            .unwrap_or_else(|| panic!("Move \"{}\" not found for player 2", p2_move));
+            .unwrap_or_else(|| panic!("Move \"{}\" not found for {:?}", movement, position))
+    }
+
+    fn process_turn(&mut self, p1_move: &str, p2_move: &str) -> Vec<BattleEvent> {
+        let p1_move_index = self.get_move_index(Team::P1, p1_move);
+        let p2_move_index = self.get_move_index(Team::P2, p2_move);
 
         self.move_p1(p1_move_index);
         self.move_p2(p2_move_index);
@@ -166,9 +280,22 @@ pub struct TestRng {
     uniform_multi_hit_value: Option<usize>,
     custom_multi_hit_value: Option<isize>,
     confusion_duration: Option<usize>,
-    confusion_miss_counter: usize,
+    confusion_self_hit_counter: usize,
+    rampage_duration: Option<usize>,
+    trap_duration: Option<usize>,
+    sleep_duration: Option<usize>,
     paralysis_move_prevention_counter: usize,
     freeze_duration: usize,
+    escape_failure_counter: usize,
+    last_escape_check_odds: Option<usize>,
+    capture_shake_counter: Option<usize>,
+    last_capture_shake_odds: Option<usize>,
+    ability_activation_counter: usize,
+    effect_spore_condition: Option<SimpleStatusCondition>,
+    protection_failure_counter: usize,
+    last_protection_check_uses: Option<usize>,
+    metronome_move: Option<String>,
+    random_ai_choice: usize,
 }
 
 impl TestRng {
@@ -200,8 +327,20 @@ impl TestRng {
         self.confusion_duration = Some(duration);
     }
 
-    pub fn force_confusion_miss(&mut self, times: usize) {
-        self.confusion_miss_counter = times;
+    pub fn force_confusion_self_hit(&mut self, times: usize) {
+        self.confusion_self_hit_counter = times;
+    }
+
+    pub fn force_rampage_duration(&mut self, duration: usize) {
+        self.rampage_duration = Some(duration);
+    }
+
+    pub fn force_trap_duration(&mut self, duration: usize) {
+        self.trap_duration = Some(duration);
+    }
+
+    pub fn force_sleep_duration(&mut self, duration: usize) {
+        self.sleep_duration = Some(duration);
     }
 
     pub fn force_paralysis_move_prevention(&mut self, times: usize) {
@@ -211,6 +350,47 @@ impl TestRng {
     pub fn force_freeze_duration(&mut self, duration: usize) {
         self.freeze_duration = duration;
     }
+
+    pub fn force_escape_failure(&mut self, times: usize) {
+        self.escape_failure_counter = times;
+    }
+
+    pub fn get_last_escape_check_odds(&self) -> Option<usize> {
+        self.last_escape_check_odds
+    }
+
+    /// Makes the next capture attempt pass exactly `shakes` shake checks.
+    pub fn force_capture_shakes(&mut self, shakes: usize) {
+        self.capture_shake_counter = Some(shakes);
+    }
+
+    pub fn get_last_capture_shake_odds(&self) -> Option<usize> {
+        self.last_capture_shake_odds
+    }
+
+    pub fn force_ability_activation(&mut self, times: usize) {
+        self.ability_activation_counter = times;
+    }
+
+    pub fn force_effect_spore_condition(&mut self, condition: SimpleStatusCondition) {
+        self.effect_spore_condition = Some(condition);
+    }
+
+    pub fn force_protection_failure(&mut self, times: usize) {
+        self.protection_failure_counter = times;
+    }
+
+    pub fn get_last_protection_check_uses(&self) -> Option<usize> {
+        self.last_protection_check_uses
+    }
+
+    pub fn force_metronome_move(&mut self, move_id: &str) {
+        self.metronome_move = Some(move_id.to_string());
+    }
+
+    pub fn force_random_ai_choice(&mut self, choice: usize) {
+        self.random_ai_choice = choice;
+    }
 }
 
 impl BattleRng for TestRng {
@@ -222,7 +402,7 @@ impl BattleRng for TestRng {
         1.
     }
 
-    fn shuffle_moves<'a>(&mut self, _moves: &mut Vec<UsedMove<'a>>) {}
+    fn shuffle_moves<'a>(&mut self, _moves: &mut Vec<PendingMove<'a>>) {}
 
     fn check_miss(&mut self, chance: usize) -> bool {
         self.last_miss_check_chance = Some(chance);
@@ -264,9 +444,21 @@ impl BattleRng for TestRng {
         self.confusion_duration.unwrap_or(4)
     }
 
-    fn check_confusion_miss(&mut self) -> bool {
-        if self.confusion_miss_counter > 0 {
-            self.confusion_miss_counter -= 1;
+    fn get_rampage_duration(&mut self) -> usize {
+        self.rampage_duration.unwrap_or(3)
+    }
+
+    fn get_trap_duration(&mut self) -> usize {
+        self.trap_duration.unwrap_or(5)
+    }
+
+    fn get_sleep_duration(&mut self) -> usize {
+        self.sleep_duration.unwrap_or(3)
+    }
+
+    fn check_confusion_self_hit(&mut self) -> bool {
+        if self.confusion_self_hit_counter > 0 {
+            self.confusion_self_hit_counter -= 1;
             true
         } else {
             false
@@ -290,4 +482,66 @@ impl BattleRng for TestRng {
             true
         }
     }
+
+    fn check_escape(&mut self, odds: usize) -> bool {
+        self.last_escape_check_odds = Some(odds);
+
+        if self.escape_failure_counter > 0 {
+            self.escape_failure_counter -= 1;
+            false
+        } else {
+            true
+        }
+    }
+
+    fn check_capture_shake(&mut self, odds: usize) -> bool {
+        self.last_capture_shake_odds = Some(odds);
+
+        match self.capture_shake_counter {
+            Some(0) => {
+                self.capture_shake_counter = None;
+                false
+            },
+            Some(counter) => {
+                self.capture_shake_counter = Some(counter - 1);
+                true
+            },
+            None => true,
+        }
+    }
+
+    fn check_ability_activation(&mut self, chance: usize) -> bool {
+        if self.ability_activation_counter > 0 {
+            self.ability_activation_counter -= 1;
+            true
+        } else {
+            chance == 100
+        }
+    }
+
+    fn get_effect_spore_condition(&mut self) -> SimpleStatusCondition {
+        self.effect_spore_condition.clone().unwrap_or(SimpleStatusCondition::Poison)
+    }
+
+    fn check_protection_success(&mut self, consecutive_uses: usize) -> bool {
+        self.last_protection_check_uses = Some(consecutive_uses);
+
+        if self.protection_failure_counter > 0 {
+            self.protection_failure_counter -= 1;
+            false
+        } else {
+            true
+        }
+    }
+
+    fn get_metronome_move(&mut self, candidates: &[&str]) -> usize {
+        self.metronome_move
+            .as_ref()
+            .and_then(|move_id| candidates.iter().position(|candidate| candidate == move_id))
+            .unwrap_or(0)
+    }
+
+    fn get_random_ai_choice(&mut self, choice_count: usize) -> usize {
+        self.random_ai_choice.min(choice_count - 1)
+    }
 }
@@ -5,7 +5,7 @@ use super::super::{prelude::*, TestMethods};
 #[test]
 fn amnesia_sharply_raises_special_defense() {
     let mut backend = battle! {
-        "Slowpoke" 27 (max ivs, Serious) vs "Clefairy" 27 (max ivs, Serious)
+        "Slowpoke" 27 (max ivs, Serious) vs "Clefairy" 27 (moves ["Pound"], max ivs, Serious)
     };
     backend.get_pokemon_mut(1).stats[5] = 0;
 
@@ -5,7 +5,7 @@ use super::super::{prelude::*, TestMethods};
 #[test]
 fn barrier_sharply_raises_defense() {
     let mut backend = battle! {
-        "Tentacool" 31 (max ivs, Serious) vs "Clefairy" 31 (max ivs, Serious)
+        "Tentacool" 31 (max ivs, Serious) vs "Clefairy" 31 (moves ["Pound"], max ivs, Serious)
     };
 
     let events = backend.process_turn("Barrier", "Pound");
@@ -0,0 +1,63 @@
+use crate::{
+    battle::backend::{BattleEvent, Team},
+    pokemon::Stat,
+};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn baton_pass_switches_the_user_out() {
+    let p1 = pokemon_setup!("Kadabra" 30 (moves ["BatonPass"], max ivs, Serious));
+    let p1_replacement = pokemon_setup!("Rattata" 30 (max ivs, Serious));
+    let p2 = pokemon_setup!("Metapod" 30 (max ivs, Serious));
+    let mut backend = create_trainer_battle(vec![p1, p1_replacement], vec![p2]);
+    let _ = backend.tick();
+
+    let events = backend.process_turn("BatonPass", "Harden");
+
+    assert_event!(events[1], SwitchOut { team: Team::P1, pokemon: 0, .. });
+    assert!(backend.requires_replacement(Team::P1));
+    // The user can't be chosen as its own replacement
+    assert!(!backend.can_switch_in(Team::P1, 1));
+
+    backend.switch_p1(0);
+    let events: Vec<_> = backend.tick().collect();
+
+    assert_event!(events[0], SwitchIn { team: Team::P1, pokemon: 1, .. });
+}
+
+#[test]
+fn baton_pass_passes_stat_stages_and_substitutes() {
+    let p1 = pokemon_setup!(
+        "Kadabra" 30 (moves ["BatonPass", "Agility", "Substitute"], max ivs, Serious)
+    );
+    let p1_replacement = pokemon_setup!("Rattata" 30 (max ivs, Serious));
+    let p2 = pokemon_setup!("Metapod" 30 (max ivs, Serious));
+    let mut backend = create_trainer_battle(vec![p1, p1_replacement], vec![p2]);
+    let _ = backend.tick();
+
+    let speed = backend.get_stat(1, Stat::Speed);
+
+    backend.process_turn("Agility", "Harden");
+    backend.process_turn("Substitute", "Harden");
+    backend.process_turn("BatonPass", "Harden");
+    backend.switch_p1(0);
+    let _ = backend.tick();
+
+    assert_eq!(backend.get_stat(1, Stat::Speed), speed * 2);
+    assert!(backend.has_flag(1, "substitute"));
+    assert!(!backend.has_flag(0, "stat_stages"));
+}
+
+#[test]
+fn baton_pass_fails_without_a_replacement() {
+    let mut backend = battle! {
+        "Kadabra" 30 (moves ["BatonPass"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("BatonPass", "Harden");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+}
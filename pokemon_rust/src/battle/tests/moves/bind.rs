@@ -0,0 +1,20 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn bind_traps_the_target() {
+    let mut backend = battle! {
+        "Onix" 20 (moves ["Bind"], max ivs, Serious)
+        vs
+        "Metapod" 10 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Bind", "Harden");
+
+    assert_event!(events[2], VolatileStatusCondition {
+        target: 1,
+        added_flag: Flag::Trapped { trapper: 0, .. },
+    });
+    assert!(backend.is_trapped(1));
+}
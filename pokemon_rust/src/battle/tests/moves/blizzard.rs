@@ -0,0 +1,34 @@
+use crate::{
+    battle::backend::BattleEvent,
+    pokemon::StatusCondition,
+};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn blizzard_deals_damage_and_might_freeze() {
+    let mut backend = battle! {
+        "Jynx" 45 (max ivs, Serious) vs "Slowpoke" 50 (moves ["Harden"], max ivs, Serious)
+    };
+
+    test_rng_mut!(backend.rng).force_secondary_effect(1);
+    let events = backend.process_turn("Blizzard", "Harden");
+
+    assert_event!(events[1], Damage { target: 1, is_critical_hit: false, .. });
+    assert_event!(events[2], NonVolatileStatusCondition { target: 1, condition: StatusCondition::Freeze });
+}
+
+#[test]
+fn blizzard_never_misses_in_hail() {
+    let mut backend = battle! {
+        "Jynx" 45 (moves ["Blizzard", "Hail"], max ivs, Serious)
+        vs
+        "Slowpoke" 50 (moves ["Harden"], max ivs, Serious)
+    };
+
+    backend.process_turn("Hail", "Harden");
+    test_rng_mut!(backend.rng).force_miss(1);
+    let events = backend.process_turn("Blizzard", "Harden");
+
+    assert_event!(events[1], Damage { target: 1, .. });
+}
@@ -0,0 +1,35 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn copycat_uses_the_last_move_used_in_the_battle() {
+    let mut backend = battle! {
+        "Clefairy" 10 (moves ["Copycat"], max ivs, Serious)
+        vs
+        "Rattata" 20 (moves ["Tackle"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Copycat", "Tackle");
+
+    match &events[3] {
+        BattleEvent::UseMove(UseMove { move_user: 0, move_name }) => {
+            assert_eq!(move_name, "Tackle");
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+    assert_event!(events[4], Damage { target: 1, .. });
+}
+
+#[test]
+fn copycat_fails_if_no_move_was_used_yet() {
+    let mut backend = battle! {
+        "Clefairy" 30 (moves ["Copycat"], max ivs, Serious)
+        vs
+        "Rattata" 10 (moves ["Tackle"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Copycat", "Tackle");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+}
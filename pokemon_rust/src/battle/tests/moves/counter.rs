@@ -0,0 +1,38 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn counter_returns_double_the_physical_damage_received() {
+    let mut backend = battle! {
+        "Machop" 20 (moves ["Counter"], max ivs, Serious)
+        vs
+        "Rattata" 20 (moves ["Tackle"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Counter", "Tackle");
+
+    match (&events[1], &events[3]) {
+        (
+            BattleEvent::Damage(Damage { target: 0, amount: received, .. }),
+            BattleEvent::Damage(Damage { target: 1, amount: dealt, .. }),
+        ) => {
+            assert_eq!(*dealt, *received * 2);
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+}
+
+#[test]
+fn counter_fails_without_receiving_physical_damage() {
+    let mut backend = battle! {
+        "Machop" 20 (moves ["Counter"], max ivs, Serious)
+        vs
+        "Metapod" 20 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Counter", "Harden");
+
+    assert_event!(events[2], UseMove { move_user: 0, .. });
+    assert_event!(events[3], FailedMove { move_user: 0 });
+}
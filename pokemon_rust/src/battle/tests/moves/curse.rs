@@ -0,0 +1,31 @@
+use crate::{
+    battle::backend::{BattleEvent, StatChangeKind},
+    pokemon::Stat,
+};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn curse_trades_speed_for_attack_and_defense_when_used_by_non_ghost_types() {
+    let mut backend = battle! {
+        "Slowpoke" 30 (moves ["Curse"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Curse", "Harden");
+
+    assert_event!(
+        events[3],
+        StatChange { target: 0, kind: StatChangeKind::Fell, stat: Stat::Speed, .. }
+    );
+    assert_event!(
+        events[4],
+        StatChange { target: 0, kind: StatChangeKind::Rose, stat: Stat::Attack, .. }
+    );
+    assert_event!(
+        events[5],
+        StatChange { target: 0, kind: StatChangeKind::Rose, stat: Stat::Defense, .. }
+    );
+    assert!(!backend.has_flag(1, "curse"));
+}
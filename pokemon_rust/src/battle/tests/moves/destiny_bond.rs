@@ -0,0 +1,43 @@
+use crate::battle::backend::{BattleEvent, DamageCause};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn destiny_bond_faints_the_attacker_that_knocks_out_the_user() {
+    let mut backend = battle! {
+        "Kadabra" 5 (moves ["DestinyBond"], max ivs, Serious)
+        vs
+        "Rattata" 5 (moves ["Tackle"], max ivs, Serious)
+    };
+
+    let mut events = vec![];
+
+    while backend.get_pokemon(0).current_hp > 0 {
+        events = backend.process_turn("DestinyBond", "Tackle");
+    }
+
+    assert!(events.iter().any(|event| match event {
+        BattleEvent::DestinyBond(DestinyBond { pokemon: 0, target: 1 }) => true,
+        _ => false,
+    }));
+    assert!(events.iter().any(|event| match event {
+        BattleEvent::Damage(Damage { target: 1, cause: DamageCause::DestinyBond, .. }) => true,
+        _ => false,
+    }));
+    assert_eq!(backend.get_pokemon(1).current_hp, 0);
+}
+
+#[test]
+fn destiny_bond_wears_off_when_the_user_moves_again() {
+    let mut backend = battle! {
+        "Kadabra" 30 (moves ["DestinyBond", "Splash"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    backend.process_turn("DestinyBond", "Harden");
+    assert!(backend.has_flag(0, "destiny_bond"));
+
+    backend.process_turn("Splash", "Harden");
+    assert!(!backend.has_flag(0, "destiny_bond"));
+}
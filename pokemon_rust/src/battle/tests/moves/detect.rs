@@ -0,0 +1,20 @@
+use crate::battle::backend::{BattleEvent, Protection};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn detect_blocks_moves() {
+    let mut backend = battle! {
+        "Hitmonchan" 20 (moves ["Detect"], max ivs, Serious)
+        vs
+        "Rattata" 20 (moves ["Tackle"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Detect", "Tackle");
+
+    assert_event!(
+        events[1],
+        VolatileStatusCondition { target: 0, added_flag: Flag::Protection(Protection::Full) }
+    );
+    assert_event!(events[3], Protected { target: 0, protection: Protection::Full });
+}
@@ -0,0 +1,47 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn dig_hits_in_the_second_turn() {
+    let mut backend = battle! {
+        "Diglett" 20 (moves ["Dig"], max ivs, Serious)
+        vs
+        "Metapod" 20 (max ivs, Serious)
+    };
+
+    let turn1 = backend.process_turn("Dig", "Harden");
+    let turn2 = backend.process_turn("Dig", "Harden");
+
+    assert_event!(turn1[0], Charging { pokemon: 0, .. });
+    assert_event!(turn2[0], UseMove { move_user: 0, .. });
+    assert_event!(turn2[1], Damage { target: 1, .. });
+}
+
+#[test]
+fn dig_makes_the_user_avoid_moves() {
+    let mut backend = battle! {
+        "Diglett" 20 (moves ["Dig"], max ivs, Serious)
+        vs
+        "Rattata" 20 (moves ["Tackle"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Dig", "Tackle");
+
+    assert_event!(events[0], Charging { pokemon: 0, .. });
+    assert_event!(events[1], UseMove { move_user: 1, .. });
+    assert_event!(events[2], Miss { target: 0, move_user: 1 });
+}
+
+#[test]
+fn dig_does_not_avoid_moves_against_no_guard() {
+    let mut backend = battle! {
+        "Diglett" 20 (moves ["Dig"], max ivs, Serious)
+        vs
+        "Rattata" 20 (moves ["Tackle"], ability "NoGuard", max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Dig", "Tackle");
+
+    assert_event!(events[2], Damage { target: 0, .. });
+}
@@ -0,0 +1,63 @@
+use crate::battle::backend::{BattleEvent, MoveRestriction, Team};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn disable_prevents_the_target_from_using_its_last_move() {
+    let mut backend = battle! {
+        "Vulpix" 10 (moves ["Disable", "Ember"], max ivs, Serious)
+        vs
+        "Rattata" 20 (moves ["Tackle", "TailWhip"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Disable", "Tackle");
+
+    assert_event!(events[3], VolatileStatusCondition {
+        target: 1,
+        added_flag: Flag::Disabled { .. },
+    });
+
+    let tackle = backend.get_move_index(Team::P2, "Tackle");
+    assert_eq!(backend.get_move_restriction(1, tackle), Some(MoveRestriction::Disabled));
+
+    let events = backend.process_turn("Ember", "Tackle");
+
+    match &events[0] {
+        BattleEvent::DisabledMove(DisabledMove { pokemon: 1, move_id }) => {
+            assert_eq!(move_id, "Tackle");
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+}
+
+#[test]
+fn disable_fails_if_the_target_has_not_moved_yet() {
+    let mut backend = battle! {
+        "Vulpix" 30 (moves ["Disable"], max ivs, Serious)
+        vs
+        "Rattata" 10 (moves ["Tackle"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Disable", "Tackle");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+}
+
+#[test]
+fn disable_wears_off_after_four_turns() {
+    let mut backend = battle! {
+        "Vulpix" 10 (moves ["Disable", "Ember"], max ivs, Serious)
+        vs
+        "Rattata" 20 (moves ["Tackle", "TailWhip"], max ivs, Serious)
+    };
+
+    backend.process_turn("Disable", "Tackle");
+
+    for _ in 0..2 {
+        backend.process_turn("Ember", "TailWhip");
+    }
+    assert!(backend.is_move_disabled(1, "Tackle"));
+
+    backend.process_turn("Ember", "TailWhip");
+    assert!(!backend.is_move_disabled(1, "Tackle"));
+}
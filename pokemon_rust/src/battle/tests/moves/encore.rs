@@ -0,0 +1,61 @@
+use crate::battle::backend::{BattleEvent, MoveRestriction, Team};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn encore_forces_the_target_to_repeat_its_last_move() {
+    let mut backend = battle! {
+        "Clefairy" 10 (moves ["Encore", "Pound"], max ivs, Serious)
+        vs
+        "Rattata" 20 (moves ["Tackle", "TailWhip"], max ivs, Serious)
+    };
+
+    backend.process_turn("Encore", "TailWhip");
+
+    let tackle = backend.get_move_index(Team::P2, "Tackle");
+    assert_eq!(
+        backend.get_move_restriction(1, tackle),
+        Some(MoveRestriction::LockedIntoOtherMove),
+    );
+
+    let events = backend.process_turn("Pound", "Tackle");
+
+    match &events[0] {
+        BattleEvent::UseMove(UseMove { move_user: 1, move_name }) => {
+            assert_eq!(move_name, "Tail Whip");
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+}
+
+#[test]
+fn encore_ends_after_three_turns() {
+    let mut backend = battle! {
+        "Clefairy" 10 (moves ["Encore", "Pound"], max ivs, Serious)
+        vs
+        "Rattata" 20 (moves ["Tackle", "TailWhip"], max ivs, Serious)
+    };
+
+    backend.process_turn("Encore", "TailWhip");
+    backend.process_turn("Pound", "TailWhip");
+    let events = backend.process_turn("Pound", "TailWhip");
+
+    assert_event!(events[4], ExpiredVolatileStatusCondition {
+        target: 1,
+        flag: Flag::Encore { .. },
+    });
+    assert!(backend.get_locked_move(1).is_none());
+}
+
+#[test]
+fn encore_fails_if_the_target_has_not_moved_yet() {
+    let mut backend = battle! {
+        "Clefairy" 30 (moves ["Encore"], max ivs, Serious)
+        vs
+        "Rattata" 10 (moves ["Tackle"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Encore", "Tackle");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+}
@@ -0,0 +1,34 @@
+use crate::battle::backend::{BattleEvent, Protection};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn endure_lets_the_user_survive_with_1_hp() {
+    let mut backend = battle! {
+        "Rattata" 20 (moves ["Endure"], max ivs, Serious)
+        vs
+        "Machop" 20 (moves ["KarateChop"], max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).current_hp = 5;
+    let events = backend.process_turn("Endure", "KarateChop");
+
+    assert_event!(events[3], Damage { target: 0, amount: 4, .. });
+    assert_event!(events[4], Protected { target: 0, protection: Protection::Endure });
+    assert_eq!(backend.get_pokemon(0).current_hp, 1);
+}
+
+#[test]
+fn endure_only_lasts_until_the_end_of_the_turn() {
+    let mut backend = battle! {
+        "Rattata" 20 (moves ["Endure", "TailWhip"], max ivs, Serious)
+        vs
+        "Machop" 20 (moves ["KarateChop"], max ivs, Serious)
+    };
+
+    backend.process_turn("Endure", "KarateChop");
+    backend.get_pokemon_mut(0).current_hp = 5;
+    backend.process_turn("TailWhip", "KarateChop");
+
+    assert_eq!(backend.get_pokemon(0).current_hp, 0);
+}
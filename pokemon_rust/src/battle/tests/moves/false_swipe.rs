@@ -0,0 +1,15 @@
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn false_swipe_leaves_the_target_with_1_hp() {
+    let mut backend = battle! {
+        "Rattata" 20 (moves ["FalseSwipe"], max ivs, Serious)
+        vs
+        "Metapod" 20 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(1).current_hp = 3;
+    backend.process_turn("FalseSwipe", "Harden");
+
+    assert_eq!(backend.get_pokemon(1).current_hp, 1);
+}
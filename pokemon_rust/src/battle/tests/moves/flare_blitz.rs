@@ -0,0 +1,28 @@
+use crate::battle::backend::{BattleEvent, DamageCause};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn flare_blitz_deals_damage_with_recoil() {
+    let mut backend = battle! {
+        "Charmander" 20 (moves ["FlareBlitz"], max ivs, Serious)
+        vs
+        "Slowpoke" 20 (moves ["Harden"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("FlareBlitz", "Harden");
+
+    assert_event!(events[1], Damage { target: 1, cause: DamageCause::Move, .. });
+    assert_event!(events[2], Damage { target: 0, cause: DamageCause::Recoil, .. });
+
+    // The user takes 1/3 of the damage dealt as recoil
+    match (&events[1], &events[2]) {
+        (
+            BattleEvent::Damage(Damage { amount: damage, .. }),
+            BattleEvent::Damage(Damage { amount: recoil, .. }),
+        ) => {
+            assert_eq!(*recoil, (*damage as f32 / 3.).round() as usize);
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+}
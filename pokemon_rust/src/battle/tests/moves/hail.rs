@@ -0,0 +1,17 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn hail_starts_a_hailstorm() {
+    let mut backend = battle! {
+        "Lapras" 30 (moves ["Hail"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Hail", "Harden");
+
+    assert_event!(events[1], WeatherStart { weather: Weather::Hail });
+    assert_eq!(backend.get_weather(), Some(Weather::Hail));
+}
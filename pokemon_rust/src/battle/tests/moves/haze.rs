@@ -0,0 +1,38 @@
+use crate::{battle::backend::BattleEvent, pokemon::Stat};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn haze_resets_the_stat_stages_of_every_pokemon() {
+    let mut backend = battle! {
+        "Kadabra" 30 (moves ["Haze", "Agility"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    let speed = backend.get_stat(0, Stat::Speed);
+    let defense = backend.get_stat(1, Stat::Defense);
+
+    backend.process_turn("Agility", "Harden");
+    assert_ne!(backend.get_stat(0, Stat::Speed), speed);
+    assert_ne!(backend.get_stat(1, Stat::Defense), defense);
+
+    let events = backend.process_turn("Haze", "Harden");
+
+    assert_event!(events[1], StatStagesReset { pokemon: 0 });
+    assert_event!(events[2], StatStagesReset { pokemon: 1 });
+    assert_eq!(backend.get_stat(0, Stat::Speed), speed);
+}
+
+#[test]
+fn haze_skips_pokemon_without_stat_changes() {
+    let mut backend = battle! {
+        "Kadabra" 30 (moves ["Haze"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Haze", "Harden");
+
+    assert_event!(events[1], UseMove { move_user: 1, .. });
+}
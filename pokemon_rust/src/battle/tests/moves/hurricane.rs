@@ -0,0 +1,29 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn hurricane_deals_damage_and_might_confuse() {
+    let mut backend = battle! {
+        "Gyarados" 44 (max ivs, Serious) vs "Onix" 44 (moves ["Harden"], max ivs, Serious)
+    };
+
+    test_rng_mut!(backend.rng).force_secondary_effect(1);
+    let events = backend.process_turn("Hurricane", "Harden");
+
+    assert_event!(events[1], Damage { target: 1, is_critical_hit: false, .. });
+    assert_event!(events[2], VolatileStatusCondition { target: 1, added_flag: Flag::Confusion { .. } });
+}
+
+#[test]
+fn hurricane_never_misses_in_rain() {
+    let mut backend = battle! {
+        "Gyarados" 44 (max ivs, Serious) vs "Onix" 44 (moves ["Harden"], max ivs, Serious)
+    };
+
+    backend.process_turn("RainDance", "Harden");
+    test_rng_mut!(backend.rng).force_miss(1);
+    let events = backend.process_turn("Hurricane", "Harden");
+
+    assert_event!(events[1], Damage { target: 1, .. });
+}
@@ -0,0 +1,21 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn hyper_beam_makes_the_user_recharge() {
+    let mut backend = battle! {
+        "Rattata" 40 (moves ["HyperBeam", "Tackle"], max ivs, Serious)
+        vs
+        "Onix" 30 (moves ["Harden"], max ivs, Serious)
+    };
+
+    let turn1 = backend.process_turn("HyperBeam", "Harden");
+    assert!(backend.has_forced_action(0));
+    let turn2 = backend.process_turn("Tackle", "Harden");
+
+    assert_event!(turn1[1], Damage { target: 1, .. });
+    assert_event!(turn2[0], Recharging { pokemon: 0 });
+    assert_event!(turn2[1], UseMove { move_user: 1, .. });
+    assert!(!backend.has_forced_action(0));
+}
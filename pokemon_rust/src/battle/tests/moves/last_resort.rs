@@ -0,0 +1,34 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn last_resort_fails_until_every_other_move_was_used() {
+    let mut backend = battle! {
+        "Eevee" 30 (moves ["LastResort", "Tackle"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("LastResort", "Harden");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+
+    backend.process_turn("Tackle", "Harden");
+    let events = backend.process_turn("LastResort", "Harden");
+
+    assert_event!(events[1], Damage { target: 1, .. });
+}
+
+#[test]
+fn last_resort_fails_if_it_is_the_only_move_of_the_user() {
+    let mut backend = battle! {
+        "Eevee" 30 (moves ["LastResort"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("LastResort", "Harden");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+}
@@ -0,0 +1,59 @@
+use crate::{
+    battle::backend::{BattleEvent, DamageCause},
+    pokemon::Stat,
+};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn leech_seed_drains_the_target_every_turn() {
+    let mut backend = battle! {
+        "Kadabra" 30 (moves ["LeechSeed"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    let max_hp = backend.get_stat(1, Stat::HP);
+    let events = backend.process_turn("LeechSeed", "Harden");
+
+    assert_event!(events[1], VolatileStatusCondition { target: 1, .. });
+    match &events[4] {
+        BattleEvent::Damage(Damage { target: 1, amount, cause: DamageCause::LeechSeed, .. }) => {
+            assert_eq!(*amount, max_hp / 8);
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+    assert_eq!(backend.get_pokemon(1).current_hp, max_hp - max_hp / 8);
+}
+
+#[test]
+fn leech_seed_heals_the_seeder() {
+    let mut backend = battle! {
+        "Kadabra" 30 (moves ["LeechSeed"], max ivs, Serious)
+        vs
+        "Rattata" 10 (moves ["Tackle"], max ivs, Serious)
+    };
+
+    let seed_damage = backend.get_stat(1, Stat::HP) / 8;
+    let events = backend.process_turn("LeechSeed", "Tackle");
+
+    assert_event!(events[4], Damage { target: 1, cause: DamageCause::LeechSeed, .. });
+    match &events[5] {
+        BattleEvent::Heal(Heal { target: 0, amount }) => assert_eq!(*amount, seed_damage),
+        _ => panic!("Pattern mismatch"),
+    }
+}
+
+#[test]
+fn leech_seed_does_not_affect_grass_type_pokemon() {
+    let mut backend = battle! {
+        "Kadabra" 30 (moves ["LeechSeed"], max ivs, Serious)
+        vs
+        "Bulbasaur" 30 (moves ["Growl"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("LeechSeed", "Growl");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+    assert!(!backend.has_flag(1, "leech_seed"));
+}
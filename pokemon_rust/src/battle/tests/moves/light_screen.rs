@@ -0,0 +1,54 @@
+use crate::battle::backend::{BattleEvent, Team};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn light_screen_halves_the_damage_of_special_moves() {
+    let mut plain = battle! {
+        "Pikachu" 20 (moves ["LightScreen", "Splash"], max ivs, Serious)
+        vs
+        "Charmander" 20 (moves ["Ember"], max ivs, Serious)
+    };
+    let mut screened = battle! {
+        "Pikachu" 20 (moves ["LightScreen", "Splash"], max ivs, Serious)
+        vs
+        "Charmander" 20 (moves ["Ember"], max ivs, Serious)
+    };
+
+    let plain_events = plain.process_turn("Splash", "Ember");
+    let screened_events = screened.process_turn("LightScreen", "Ember");
+
+    assert_event!(
+        screened_events[1],
+        SideConditionStart { team: Team::P1, condition: SideCondition::LightScreen }
+    );
+
+    match (&plain_events[2], &screened_events[3]) {
+        (
+            BattleEvent::Damage(Damage { amount: plain_damage, .. }),
+            BattleEvent::Damage(Damage { amount: screened_damage, .. }),
+        ) => {
+            assert_eq!(*screened_damage, *plain_damage / 2);
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+}
+
+#[test]
+fn light_screen_does_not_weaken_physical_moves() {
+    let mut plain = battle! {
+        "Pikachu" 20 (moves ["LightScreen", "Splash"], max ivs, Serious)
+        vs
+        "Rattata" 20 (moves ["Tackle"], max ivs, Serious)
+    };
+    let mut screened = battle! {
+        "Pikachu" 20 (moves ["LightScreen", "Splash"], max ivs, Serious)
+        vs
+        "Rattata" 20 (moves ["Tackle"], max ivs, Serious)
+    };
+
+    let plain_events = plain.process_turn("Splash", "Tackle");
+    let screened_events = screened.process_turn("LightScreen", "Tackle");
+
+    assert_eq!(plain_events[2], screened_events[3]);
+}
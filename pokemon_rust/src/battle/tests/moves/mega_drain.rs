@@ -0,0 +1,28 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn mega_drain_restores_half_of_the_damage_dealt() {
+    let mut backend = battle! {
+        "Oddish" 20 (moves ["MegaDrain"], max ivs, Serious)
+        vs
+        "Slowpoke" 20 (moves ["Harden"], max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).current_hp = 1;
+    let events = backend.process_turn("MegaDrain", "Harden");
+
+    assert_event!(events[1], Damage { target: 1, .. });
+    assert_event!(events[2], Heal { target: 0, .. });
+
+    match (&events[1], &events[2]) {
+        (
+            BattleEvent::Damage(Damage { amount: damage, .. }),
+            BattleEvent::Heal(Heal { amount: healed, .. }),
+        ) => {
+            assert_eq!(*healed, (*damage as f32 / 2.).round() as usize);
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+}
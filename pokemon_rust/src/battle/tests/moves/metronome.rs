@@ -0,0 +1,44 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn metronome_uses_a_random_move() {
+    let mut backend = battle! {
+        "Clefairy" 30 (moves ["Metronome"], max ivs, Serious)
+        vs
+        "Rattata" 10 (moves ["TailWhip"], max ivs, Serious)
+    };
+
+    test_rng_mut!(backend.rng).force_metronome_move("Tackle");
+
+    let events = backend.process_turn("Metronome", "TailWhip");
+
+    match &events[1] {
+        BattleEvent::UseMove(UseMove { move_user: 0, move_name }) => {
+            assert_eq!(move_name, "Tackle");
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+    assert_event!(events[2], Damage { target: 1, .. });
+}
+
+#[test]
+fn metronome_cannot_call_itself() {
+    let mut backend = battle! {
+        "Clefairy" 30 (moves ["Metronome"], max ivs, Serious)
+        vs
+        "Rattata" 10 (moves ["TailWhip"], max ivs, Serious)
+    };
+
+    test_rng_mut!(backend.rng).force_metronome_move("Metronome");
+
+    let events = backend.process_turn("Metronome", "TailWhip");
+
+    match &events[1] {
+        BattleEvent::UseMove(UseMove { move_user: 0, move_name }) => {
+            assert_ne!(move_name, "Metronome");
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+}
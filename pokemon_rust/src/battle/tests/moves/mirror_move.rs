@@ -0,0 +1,35 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn mirror_move_uses_the_last_move_of_the_target() {
+    let mut backend = battle! {
+        "Pidgey" 10 (moves ["MirrorMove"], max ivs, Serious)
+        vs
+        "Rattata" 20 (moves ["Tackle"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("MirrorMove", "Tackle");
+
+    match &events[3] {
+        BattleEvent::UseMove(UseMove { move_user: 0, move_name }) => {
+            assert_eq!(move_name, "Tackle");
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+    assert_event!(events[4], Damage { target: 1, .. });
+}
+
+#[test]
+fn mirror_move_fails_if_the_target_has_not_moved_yet() {
+    let mut backend = battle! {
+        "Pidgey" 30 (moves ["MirrorMove"], max ivs, Serious)
+        vs
+        "Rattata" 10 (moves ["Tackle"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("MirrorMove", "Tackle");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+}
@@ -0,0 +1,19 @@
+use crate::battle::backend::{BattleEvent, Team};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn mist_prevents_stat_drops_caused_by_foes() {
+    let mut backend = battle! {
+        "Pikachu" 20 (moves ["Mist"], max ivs, Serious)
+        vs
+        "Slowpoke" 20 (moves ["Growl"], max ivs, Serious)
+    };
+
+    let attack = backend.get_stat(0, Stat::Attack);
+    let events = backend.process_turn("Mist", "Growl");
+
+    assert_event!(events[1], SideConditionStart { team: Team::P1, condition: SideCondition::Mist });
+    assert_event!(events[3], FailedMove { move_user: 1 });
+    assert_eq!(backend.get_stat(0, Stat::Attack), attack);
+}
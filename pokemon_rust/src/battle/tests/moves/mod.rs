@@ -1,87 +1,146 @@
+mod absorb;
 mod acid;
 mod acid_armor;
 mod agility;
 mod amnesia;
 mod aurora_beam;
 mod barrier;
+mod baton_pass;
+mod bind;
 mod bite;
+mod blizzard;
 mod bone_club;
+mod brave_bird;
 mod bubble;
 mod bubble_beam;
 mod comet_punch;
 mod confuse_ray;
 mod confusion;
 mod constrict;
+mod copycat;
+mod counter;
 mod crab_hammer;
+mod curse;
 mod cut;
+mod destiny_bond;
+mod detect;
+mod dig;
+mod disable;
 mod dizzy_punch;
+mod double_edge;
 mod double_kick;
 mod double_slap;
 mod double_team;
+mod drain_punch;
 mod drill_peck;
 mod egg_bomb;
 mod ember;
+mod encore;
+mod endure;
+mod false_swipe;
 mod fire_blast;
 mod fire_punch;
+mod fire_spin;
 mod fissure;
 mod flamethrower;
+mod flare_blitz;
 mod flash;
 mod fury_attack;
+mod giga_drain;
 mod glare;
 mod growl;
 mod guillotine;
 mod gust;
+mod hail;
 mod harden;
+mod haze;
 mod headbutt;
 mod horn_attack;
 mod horn_drill;
+mod hurricane;
 mod hydro_pump;
+mod hyper_beam;
 mod hyper_fang;
 mod hypnosis;
 mod ice_beam;
 mod ice_punch;
 mod karate_chop;
 mod kinesis;
+mod last_resort;
+mod leech_life;
+mod leech_seed;
 mod leer;
 mod lick;
+mod light_screen;
 mod lovely_kiss;
 mod mach_punch;
 mod meditate;
+mod mega_drain;
 mod mega_kick;
 mod mega_punch;
+mod metronome;
+mod mirror_move;
+mod mist;
+mod night_shade;
 mod peck;
+mod perish_song;
+mod petal_dance;
 mod poison_gas;
 mod poison_powder;
 mod poison_sting;
 mod pound;
+mod protect;
 mod psychic;
 mod psybeam;
+mod psych_up;
 mod quick_attack;
+mod quick_guard;
+mod rain_dance;
 mod razor_leaf;
+mod recover;
+mod reflect;
 mod rock_slide;
 mod rock_throw;
 mod rolling_kick;
+mod roost;
+mod safeguard;
 mod sand_attack;
+mod sandstorm;
+mod sand_tomb;
 mod scratch;
 mod screech;
+mod seismic_toss;
 mod sharpen;
 mod sing;
+mod skull_bash;
 mod slam;
 mod slash;
 mod sleep_powder;
 mod sludge;
 mod smog;
 mod smokescreen;
+mod solar_beam;
+mod spite;
 mod splash;
 mod spore;
+mod stealth_rock;
 mod strength;
 mod string_shot;
+mod struggle;
 mod stun_spore;
+mod substitute;
+mod sunny_day;
+mod super_fang;
 mod supersonic;
 mod swift;
 mod swords_dance;
+mod synthesis;
 mod tackle;
 mod tailwhip;
+mod tailwind;
+mod take_down;
+mod thrash;
+mod thunder;
 mod thunderbolt;
 mod thunder_punch;
 mod thunder_shock;
@@ -91,5 +150,9 @@ mod vine_whip;
 mod vise_grip;
 mod waterfall;
 mod water_gun;
+mod whirlpool;
+mod wide_guard;
 mod wing_attack;
 mod withdraw;
+mod wrap;
+mod yawn;
@@ -0,0 +1,39 @@
+use crate::battle::backend::{BattleEvent, TypeEffectiveness};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn night_shade_deals_damage_equal_to_the_user_level() {
+    let mut backend = battle! {
+        "Kadabra" 25 (moves ["NightShade"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("NightShade", "Harden");
+
+    assert_event!(events[1], Damage {
+        target: 1,
+        amount: 25,
+        effectiveness: TypeEffectiveness::Normal,
+        ..
+    });
+}
+
+#[test]
+fn night_shade_does_not_affect_normal_types() {
+    let mut backend = battle! {
+        "Kadabra" 25 (moves ["NightShade"], max ivs, Serious)
+        vs
+        "Rattata" 30 (moves ["TailWhip"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("NightShade", "TailWhip");
+
+    assert_event!(events[1], Damage {
+        target: 1,
+        amount: 0,
+        effectiveness: TypeEffectiveness::Immune,
+        ..
+    });
+}
@@ -0,0 +1,67 @@
+use crate::battle::backend::{BattleEvent, DamageCause};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn perish_song_faints_every_pokemon_on_the_field_after_three_turns() {
+    let mut backend = battle! {
+        "Kadabra" 30 (moves ["PerishSong", "Splash"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    backend.process_turn("PerishSong", "Harden");
+    backend.process_turn("Splash", "Harden");
+    backend.process_turn("Splash", "Harden");
+    assert!(backend.get_pokemon(0).current_hp > 0);
+    assert!(backend.get_pokemon(1).current_hp > 0);
+
+    let events = backend.process_turn("Splash", "Harden");
+
+    assert!(events.iter().any(|event| match event {
+        BattleEvent::PerishCount(PerishCount { pokemon: 0, count: 0 }) => true,
+        _ => false,
+    }));
+    assert!(events.iter().any(|event| match event {
+        BattleEvent::Damage(Damage { target: 1, cause: DamageCause::PerishSong, .. }) => true,
+        _ => false,
+    }));
+    assert_eq!(backend.get_pokemon(0).current_hp, 0);
+    assert_eq!(backend.get_pokemon(1).current_hp, 0);
+}
+
+#[test]
+fn perish_count_goes_down_every_turn() {
+    let mut backend = battle! {
+        "Kadabra" 30 (moves ["PerishSong"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("PerishSong", "Harden");
+
+    assert!(events.iter().any(|event| match event {
+        BattleEvent::PerishCount(PerishCount { pokemon: 1, count: 3 }) => true,
+        _ => false,
+    }));
+    assert!(backend.get_pokemon(1).current_hp > 0);
+}
+
+#[test]
+fn switching_out_removes_the_perish_count() {
+    let p1 = pokemon_setup!("Metapod" 30 (max ivs, Serious));
+    let p1_replacement = pokemon_setup!("Rattata" 30 (max ivs, Serious));
+    let p2 = pokemon_setup!("Kadabra" 30 (moves ["PerishSong"], max ivs, Serious));
+    let mut backend = create_trainer_battle(vec![p1, p1_replacement], vec![p2]);
+    let _ = backend.tick();
+
+    backend.process_turn("Harden", "PerishSong");
+    assert!(backend.has_flag(0, "perish_count"));
+
+    backend.switch_p1(0);
+    backend.move_p2(0);
+    let _ = backend.tick();
+
+    assert!(!backend.has_flag(0, "perish_count"));
+    assert!(backend.has_flag(2, "perish_count"));
+}
@@ -0,0 +1,21 @@
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn petal_dance_lasts_for_the_rolled_number_of_turns() {
+    let mut backend = battle! {
+        "Oddish" 15 (moves ["PetalDance"], max ivs, Serious)
+        vs
+        "Charmander" 30 (moves ["Growl"], max ivs, Serious)
+    };
+
+    test_rng_mut!(backend.rng).force_rampage_duration(3);
+
+    backend.process_turn("PetalDance", "Growl");
+    backend.process_turn("PetalDance", "Growl");
+    assert!(backend.has_forced_action(0));
+    assert!(!backend.has_flag(0, "confusion"));
+    backend.process_turn("PetalDance", "Growl");
+
+    assert!(!backend.has_forced_action(0));
+    assert!(backend.has_flag(0, "confusion"));
+}
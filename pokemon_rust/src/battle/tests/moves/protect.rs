@@ -0,0 +1,69 @@
+use crate::battle::backend::{BattleEvent, Protection};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn protect_blocks_moves_until_the_end_of_the_turn() {
+    let mut backend = battle! {
+        "Pikachu" 20 (moves ["Protect", "Splash"], max ivs, Serious)
+        vs
+        "Rattata" 20 (moves ["Tackle"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Protect", "Tackle");
+
+    assert_event!(
+        events[1],
+        VolatileStatusCondition { target: 0, added_flag: Flag::Protection(Protection::Full) }
+    );
+    assert_event!(events[2], UseMove { move_user: 1, .. });
+    assert_event!(events[3], Protected { target: 0, protection: Protection::Full });
+    assert_eq!(backend.get_pokemon(0).current_hp, backend.get_stat(0, Stat::HP));
+
+    let events = backend.process_turn("Splash", "Tackle");
+
+    assert_event!(events[1], UseMove { move_user: 1, .. });
+    assert_event!(events[2], Damage { target: 0, .. });
+}
+
+#[test]
+fn protect_is_less_likely_to_succeed_when_used_consecutively() {
+    let mut backend = battle! {
+        "Pikachu" 20 (moves ["Protect", "Splash"], max ivs, Serious)
+        vs
+        "Rattata" 20 (moves ["Tackle"], max ivs, Serious)
+    };
+
+    backend.process_turn("Protect", "Tackle");
+    assert_eq!(test_rng!(backend.rng).get_last_protection_check_uses(), Some(0));
+
+    test_rng_mut!(backend.rng).force_protection_failure(1);
+    let events = backend.process_turn("Protect", "Tackle");
+
+    assert_eq!(test_rng!(backend.rng).get_last_protection_check_uses(), Some(1));
+    assert_event!(events[1], FailedMove { move_user: 0 });
+    assert_event!(events[3], Damage { target: 0, .. });
+
+    // A failed attempt resets the odds
+    backend.process_turn("Protect", "Tackle");
+    assert_eq!(test_rng!(backend.rng).get_last_protection_check_uses(), Some(0));
+}
+
+#[test]
+fn protect_odds_are_reset_after_using_another_move() {
+    let mut backend = battle! {
+        "Pikachu" 20 (moves ["Protect", "Splash"], max ivs, Serious)
+        vs
+        "Rattata" 20 (moves ["Tackle"], max ivs, Serious)
+    };
+
+    backend.process_turn("Protect", "Tackle");
+    backend.process_turn("Splash", "Tackle");
+    let events = backend.process_turn("Protect", "Tackle");
+
+    assert_eq!(test_rng!(backend.rng).get_last_protection_check_uses(), Some(0));
+    assert!(events.iter().any(|event| match event {
+        BattleEvent::Protected(_) => true,
+        _ => false,
+    }));
+}
@@ -0,0 +1,23 @@
+use crate::{battle::backend::BattleEvent, pokemon::Stat};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn psych_up_copies_the_stat_stages_of_the_target() {
+    let mut backend = battle! {
+        "Kadabra" 30 (moves ["PsychUp", "Agility"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    let speed = backend.get_stat(0, Stat::Speed);
+    let defense = backend.get_stat(0, Stat::Defense);
+
+    backend.process_turn("Agility", "Harden");
+    let events = backend.process_turn("PsychUp", "Harden");
+
+    assert_event!(events[1], StatStagesCopied { user: 0, target: 1 });
+    assert_eq!(backend.get_stat(0, Stat::Defense), defense * 3 / 2);
+    // The stat stages of the user are replaced, not combined
+    assert_eq!(backend.get_stat(0, Stat::Speed), speed);
+}
@@ -0,0 +1,31 @@
+use crate::battle::backend::{BattleEvent, Protection};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn quick_guard_blocks_moves_with_increased_priority() {
+    let mut backend = battle! {
+        "Hitmonchan" 20 (moves ["QuickGuard"], max ivs, Serious)
+        vs
+        "Rattata" 20 (moves ["QuickAttack"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("QuickGuard", "QuickAttack");
+
+    assert_event!(events[2], UseMove { move_user: 1, .. });
+    assert_event!(events[3], Protected { target: 0, protection: Protection::QuickGuard });
+}
+
+#[test]
+fn quick_guard_does_not_block_regular_moves() {
+    let mut backend = battle! {
+        "Hitmonchan" 20 (moves ["QuickGuard"], max ivs, Serious)
+        vs
+        "Rattata" 20 (moves ["Tackle"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("QuickGuard", "Tackle");
+
+    assert_event!(events[2], UseMove { move_user: 1, .. });
+    assert_event!(events[3], Damage { target: 0, .. });
+}
@@ -0,0 +1,27 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn rain_dance_starts_rain() {
+    let mut backend = battle! {
+        "Lapras" 50 (max ivs, Serious) vs "Metapod" 50 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("RainDance", "Harden");
+
+    assert_event!(events[1], WeatherStart { weather: Weather::Rain });
+    assert_eq!(backend.get_weather(), Some(Weather::Rain));
+}
+
+#[test]
+fn rain_dance_fails_if_it_is_already_raining() {
+    let mut backend = battle! {
+        "Lapras" 50 (max ivs, Serious) vs "Metapod" 50 (max ivs, Serious)
+    };
+
+    backend.process_turn("RainDance", "Harden");
+    let events = backend.process_turn("RainDance", "Harden");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+}
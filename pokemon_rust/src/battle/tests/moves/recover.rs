@@ -0,0 +1,48 @@
+use crate::{battle::backend::BattleEvent, pokemon::Stat};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn recover_restores_half_the_maximum_hp() {
+    let mut backend = battle! {
+        "Kadabra" 30 (moves ["Recover"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    let max_hp = backend.get_stat(0, Stat::HP);
+    backend.get_pokemon_mut(0).current_hp = 1;
+    let events = backend.process_turn("Recover", "Harden");
+
+    assert_event!(events[1], Heal { target: 0, .. });
+    assert_eq!(backend.get_pokemon(0).current_hp, 1 + max_hp / 2);
+}
+
+#[test]
+fn recover_does_not_exceed_the_maximum_hp() {
+    let mut backend = battle! {
+        "Kadabra" 30 (moves ["Recover"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    let max_hp = backend.get_stat(0, Stat::HP);
+    backend.get_pokemon_mut(0).current_hp = max_hp - 1;
+    let events = backend.process_turn("Recover", "Harden");
+
+    assert_event!(events[1], Heal { target: 0, amount: 1 });
+    assert_eq!(backend.get_pokemon(0).current_hp, max_hp);
+}
+
+#[test]
+fn recover_fails_when_the_user_has_full_hp() {
+    let mut backend = battle! {
+        "Kadabra" 30 (moves ["Recover"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Recover", "Harden");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+}
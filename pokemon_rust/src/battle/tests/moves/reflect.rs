@@ -0,0 +1,75 @@
+use crate::battle::backend::{BattleEvent, Team};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn reflect_halves_the_damage_of_physical_moves() {
+    let mut plain = battle! {
+        "Pikachu" 20 (moves ["Reflect", "Splash"], max ivs, Serious)
+        vs
+        "Rattata" 20 (moves ["Tackle"], max ivs, Serious)
+    };
+    let mut reflected = battle! {
+        "Pikachu" 20 (moves ["Reflect", "Splash"], max ivs, Serious)
+        vs
+        "Rattata" 20 (moves ["Tackle"], max ivs, Serious)
+    };
+
+    let plain_events = plain.process_turn("Splash", "Tackle");
+    let reflected_events = reflected.process_turn("Reflect", "Tackle");
+
+    assert_event!(
+        reflected_events[1],
+        SideConditionStart { team: Team::P1, condition: SideCondition::Reflect }
+    );
+
+    match (&plain_events[2], &reflected_events[3]) {
+        (
+            BattleEvent::Damage(Damage { amount: plain_damage, .. }),
+            BattleEvent::Damage(Damage { amount: reflected_damage, .. }),
+        ) => {
+            assert_eq!(*reflected_damage, *plain_damage / 2);
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+}
+
+#[test]
+fn reflect_ends_after_five_turns() {
+    let mut backend = battle! {
+        "Pikachu" 20 (moves ["Reflect", "Splash"], max ivs, Serious)
+        vs
+        "Magikarp" 20 (moves ["Splash"], max ivs, Serious)
+    };
+
+    backend.process_turn("Reflect", "Splash");
+
+    for _ in 0..3 {
+        let events = backend.process_turn("Splash", "Splash");
+        assert_eq!(events.len(), 3);
+    }
+
+    assert!(backend.has_side_condition(Team::P1, SideCondition::Reflect));
+
+    let events = backend.process_turn("Splash", "Splash");
+
+    assert_event!(
+        events[2],
+        SideConditionEnd { team: Team::P1, condition: SideCondition::Reflect }
+    );
+    assert!(!backend.has_side_condition(Team::P1, SideCondition::Reflect));
+}
+
+#[test]
+fn reflect_fails_if_it_is_already_active() {
+    let mut backend = battle! {
+        "Pikachu" 20 (moves ["Reflect"], max ivs, Serious)
+        vs
+        "Magikarp" 20 (moves ["Splash"], max ivs, Serious)
+    };
+
+    backend.process_turn("Reflect", "Splash");
+    let events = backend.process_turn("Reflect", "Splash");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+}
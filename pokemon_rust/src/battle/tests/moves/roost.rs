@@ -0,0 +1,40 @@
+use crate::{
+    battle::backend::{BattleEvent, TypeEffectiveness},
+    pokemon::{PokemonType, Stat},
+};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn roost_restores_half_the_maximum_hp() {
+    let mut backend = battle! {
+        "Pidgey" 30 (moves ["Roost"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    let max_hp = backend.get_stat(0, Stat::HP);
+    backend.get_pokemon_mut(0).current_hp = 1;
+    backend.process_turn("Roost", "Harden");
+
+    assert_eq!(backend.get_pokemon(0).current_hp, 1 + max_hp / 2);
+}
+
+#[test]
+fn roost_removes_the_flying_type_until_the_end_of_the_turn() {
+    let mut backend = battle! {
+        "Pidgey" 30 (moves ["Roost"], max ivs, Serious)
+        vs
+        "Pikachu" 10 (moves ["ThunderShock"], max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).current_hp = 1;
+    let events = backend.process_turn("Roost", "ThunderShock");
+
+    assert_event!(
+        events[3],
+        Damage { target: 0, effectiveness: TypeEffectiveness::Normal, .. }
+    );
+    assert!(!backend.has_flag(0, "roost"));
+    assert!(backend.has_type(0, PokemonType::Flying));
+}
@@ -0,0 +1,37 @@
+use crate::battle::backend::{BattleEvent, Team};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn safeguard_prevents_status_conditions() {
+    let mut backend = battle! {
+        "Pidgey" 20 (moves ["Safeguard"], max ivs, Serious)
+        vs
+        "Slowpoke" 20 (moves ["ThunderWave"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Safeguard", "ThunderWave");
+
+    assert_event!(
+        events[1],
+        SideConditionStart { team: Team::P1, condition: SideCondition::Safeguard }
+    );
+    assert_event!(events[2], UseMove { move_user: 1, .. });
+    assert_event!(events[3], FailedMove { move_user: 1 });
+    assert_event!(events[4], ChangeTurn { .. });
+    assert_eq!(backend.get_pokemon(0).status_condition, None);
+}
+
+#[test]
+fn safeguard_prevents_confusion() {
+    let mut backend = battle! {
+        "Pidgey" 20 (moves ["Safeguard"], max ivs, Serious)
+        vs
+        "Slowpoke" 20 (moves ["Supersonic"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Safeguard", "Supersonic");
+
+    assert_event!(events[3], FailedMove { move_user: 1 });
+    assert!(!backend.has_flag(0, "confusion"));
+}
@@ -0,0 +1,15 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn sandstorm_starts_a_sandstorm() {
+    let mut backend = battle! {
+        "Diglett" 30 (max ivs, Serious) vs "Metapod" 30 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Sandstorm", "Harden");
+
+    assert_event!(events[1], WeatherStart { weather: Weather::Sandstorm });
+    assert_eq!(backend.get_weather(), Some(Weather::Sandstorm));
+}
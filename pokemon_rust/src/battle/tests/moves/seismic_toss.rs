@@ -0,0 +1,16 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn seismic_toss_deals_damage_equal_to_the_user_level() {
+    let mut backend = battle! {
+        "Machop" 20 (moves ["SeismicToss"], max ivs, Serious)
+        vs
+        "Onix" 30 (moves ["Harden"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("SeismicToss", "Harden");
+
+    assert_event!(events[3], Damage { target: 1, amount: 20, .. });
+}
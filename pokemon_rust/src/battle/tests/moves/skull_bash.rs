@@ -0,0 +1,23 @@
+use crate::battle::backend::{BattleEvent, StatChangeKind};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn skull_bash_raises_defense_while_charging() {
+    let mut backend = battle! {
+        "Squirtle" 20 (moves ["SkullBash"], max ivs, Serious)
+        vs
+        "Metapod" 20 (max ivs, Serious)
+    };
+
+    let turn1 = backend.process_turn("SkullBash", "Harden");
+    let turn2 = backend.process_turn("SkullBash", "Harden");
+
+    assert_event!(turn1[0], Charging { pokemon: 0, .. });
+    assert_event!(turn1[1], StatChange {
+        target: 0,
+        kind: StatChangeKind::Rose,
+        stat: Stat::Defense,
+    });
+    assert_event!(turn2[1], Damage { target: 1, .. });
+}
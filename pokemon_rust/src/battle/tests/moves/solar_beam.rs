@@ -0,0 +1,62 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn solar_beam_charges_for_a_turn() {
+    let mut backend = battle! {
+        "Bulbasaur" 20 (moves ["SolarBeam"], max ivs, Serious)
+        vs
+        "Metapod" 20 (max ivs, Serious)
+    };
+
+    let turn1 = backend.process_turn("SolarBeam", "Harden");
+    let turn2 = backend.process_turn("SolarBeam", "Harden");
+
+    assert_event!(turn1[0], Charging { pokemon: 0, .. });
+    assert_event!(turn1[1], UseMove { move_user: 1, .. });
+    assert_event!(turn2[0], UseMove { move_user: 0, .. });
+    assert_event!(turn2[1], Damage { target: 1, .. });
+}
+
+#[test]
+fn solar_beam_does_not_charge_in_harsh_sunlight() {
+    let mut backend = battle! {
+        "Bulbasaur" 20 (moves ["SolarBeam", "SunnyDay"], max ivs, Serious)
+        vs
+        "Metapod" 20 (max ivs, Serious)
+    };
+
+    backend.process_turn("SunnyDay", "Harden");
+    let events = backend.process_turn("SolarBeam", "Harden");
+
+    assert_event!(events[0], Charging { pokemon: 0, .. });
+    assert_event!(events[1], UseMove { move_user: 0, .. });
+    assert_event!(events[2], Damage { target: 1, .. });
+    assert!(!backend.has_forced_action(0));
+}
+
+#[test]
+fn solar_beam_deals_less_damage_in_rain() {
+    let mut backend = battle! {
+        "Bulbasaur" 36 (moves ["SolarBeam", "RainDance"], max ivs, Serious)
+        vs
+        "Charmander" 50 (moves ["Growl"], max ivs, Serious)
+    };
+
+    backend.process_turn("SolarBeam", "Growl");
+    let turn2 = backend.process_turn("SolarBeam", "Growl");
+    backend.process_turn("RainDance", "Growl");
+    backend.process_turn("SolarBeam", "Growl");
+    let turn5 = backend.process_turn("SolarBeam", "Growl");
+
+    match (&turn2[3], &turn5[3]) {
+        (
+            BattleEvent::Damage(Damage { amount: a1, .. }),
+            BattleEvent::Damage(Damage { amount: a2, .. }),
+        ) => {
+            assert!(*a2 < *a1);
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+}
@@ -0,0 +1,39 @@
+use crate::battle::backend::{BattleEvent, Team};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn spite_lowers_the_pp_of_the_last_move_of_the_target() {
+    let mut backend = battle! {
+        "Vulpix" 10 (moves ["Spite"], max ivs, Serious)
+        vs
+        "Rattata" 20 (moves ["Tackle"], max ivs, Serious)
+    };
+
+    let tackle = backend.get_move_index(Team::P2, "Tackle");
+    let initial_pp = backend.get_pokemon(1).pp[tackle];
+
+    let events = backend.process_turn("Spite", "Tackle");
+
+    match &events[3] {
+        BattleEvent::PpReduction(PpReduction { target: 1, move_id, amount: 4 }) => {
+            assert_eq!(move_id, "Tackle");
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+    // Using Tackle cost a PP as well
+    assert_eq!(backend.get_pokemon(1).pp[tackle], initial_pp - 5);
+}
+
+#[test]
+fn spite_fails_if_the_target_has_not_moved_yet() {
+    let mut backend = battle! {
+        "Vulpix" 30 (moves ["Spite"], max ivs, Serious)
+        vs
+        "Rattata" 10 (moves ["Tackle"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Spite", "Tackle");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+}
@@ -0,0 +1,48 @@
+use crate::battle::backend::{BattleEvent, DamageCause, Team};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn stealth_rock_hurts_foes_when_they_switch_in() {
+    let p1 = pokemon_setup!("Rattata" 20 (moves ["Tackle"], max ivs, Serious));
+    let p1_replacement = pokemon_setup!("Pidgey" 20 (max ivs, Serious));
+    let p2 = pokemon_setup!("Onix" 20 (moves ["StealthRock", "Harden"], max ivs, Serious));
+    let mut backend = create_trainer_battle(vec![p1, p1_replacement], vec![p2]);
+    let _ = backend.tick();
+
+    let events = backend.process_turn("Tackle", "StealthRock");
+
+    assert_event!(
+        events[3],
+        SideConditionStart { team: Team::P1, condition: SideCondition::StealthRock }
+    );
+
+    backend.switch_p1(0);
+    let p2_move = backend.get_move_index(Team::P2, "Harden");
+    backend.move_p2(p2_move);
+    let events: Vec<_> = backend.tick().collect();
+    let max_hp = backend.get_stat(1, Stat::HP);
+
+    assert_event!(events[1], SwitchIn { pokemon: 1, .. });
+    assert_event!(events[2], Damage { target: 1, cause: DamageCause::StealthRock, .. });
+
+    // Pidgey is weak to Rock-type moves
+    match &events[2] {
+        BattleEvent::Damage(Damage { amount, .. }) => assert_eq!(*amount, max_hp / 4),
+        _ => panic!("Pattern mismatch"),
+    }
+}
+
+#[test]
+fn stealth_rock_fails_if_it_is_already_active() {
+    let mut backend = battle! {
+        "Onix" 20 (moves ["StealthRock"], max ivs, Serious)
+        vs
+        "Slowpoke" 20 (moves ["Splash"], max ivs, Serious)
+    };
+
+    backend.process_turn("StealthRock", "Splash");
+    let events = backend.process_turn("StealthRock", "Splash");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+}
@@ -0,0 +1,72 @@
+use crate::{
+    battle::backend::{BattleEvent, DamageCause, TypeEffectiveness},
+    pokemon::Stat,
+};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn struggle_is_used_when_no_move_can_be_chosen() {
+    let mut backend = battle! {
+        "Rattata" 10 (moves ["Tackle"], max ivs, Serious)
+        vs
+        "Metapod" 10 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).pp[0] = 0;
+    assert!(backend.must_use_struggle(0));
+
+    // The chosen move is ignored
+    backend.move_p1(0);
+    backend.move_p2(0);
+    let events: Vec<_> = backend.tick().collect();
+
+    match &events[0] {
+        BattleEvent::UseMove(UseMove { move_user: 0, move_name }) => {
+            assert_eq!(move_name, "Struggle");
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+    assert_eq!(backend.get_pokemon(0).pp[0], 0);
+}
+
+#[test]
+fn struggle_hurts_the_user_by_a_quarter_of_its_maximum_hp() {
+    let mut backend = battle! {
+        "Rattata" 10 (moves ["Tackle"], ability "RockHead", max ivs, Serious)
+        vs
+        "Metapod" 10 (max ivs, Serious)
+    };
+
+    let max_hp = backend.get_stat(0, Stat::HP);
+    backend.get_pokemon_mut(0).pp[0] = 0;
+    backend.move_p1(0);
+    backend.move_p2(0);
+    let events: Vec<_> = backend.tick().collect();
+
+    match &events[2] {
+        BattleEvent::Damage(Damage { target: 0, amount, cause: DamageCause::Recoil, .. }) => {
+            assert_eq!(*amount, max_hp / 4);
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+}
+
+#[test]
+fn struggle_has_no_type() {
+    let mut backend = battle! {
+        "Rattata" 10 (moves ["Tackle"], max ivs, Serious)
+        vs
+        "Onix" 10 (moves ["Harden"], max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).pp[0] = 0;
+    backend.move_p1(0);
+    backend.move_p2(0);
+    let events: Vec<_> = backend.tick().collect();
+
+    assert_event!(
+        events[1],
+        Damage { target: 1, effectiveness: TypeEffectiveness::Normal, .. }
+    );
+}
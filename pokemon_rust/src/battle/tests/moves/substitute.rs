@@ -0,0 +1,123 @@
+use crate::{
+    battle::backend::{BattleEvent, DamageCause, StatChangeKind},
+    pokemon::Stat,
+};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn substitute_costs_a_quarter_of_the_maximum_hp() {
+    let mut backend = battle! {
+        "Kadabra" 30 (moves ["Substitute"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    let max_hp = backend.get_stat(0, Stat::HP);
+    let events = backend.process_turn("Substitute", "Harden");
+
+    assert_event!(events[1], SubstituteCreated { pokemon: 0 });
+    match &events[2] {
+        BattleEvent::Damage(Damage { target: 0, amount, cause: DamageCause::Substitute, .. }) => {
+            assert_eq!(*amount, max_hp / 4);
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+    assert_eq!(backend.get_pokemon(0).current_hp, max_hp - max_hp / 4);
+}
+
+#[test]
+fn substitute_takes_hits_in_place_of_the_user() {
+    let mut backend = battle! {
+        "Kadabra" 30 (moves ["Substitute"], max ivs, Serious)
+        vs
+        "Rattata" 10 (moves ["Tackle"], max ivs, Serious)
+    };
+
+    let max_hp = backend.get_stat(0, Stat::HP);
+    let events = backend.process_turn("Substitute", "Tackle");
+
+    assert_event!(events[4], SubstituteDamaged { pokemon: 0, .. });
+    assert_eq!(backend.get_pokemon(0).current_hp, max_hp - max_hp / 4);
+}
+
+#[test]
+fn substitute_breaks_when_it_runs_out_of_hp() {
+    let mut backend = battle! {
+        "Kadabra" 30 (moves ["Substitute"], max ivs, Serious)
+        vs
+        "Rattata" 30 (moves ["Tackle"], max ivs, Serious)
+    };
+
+    let max_hp = backend.get_stat(0, Stat::HP);
+    let events = backend.process_turn("Substitute", "Tackle");
+
+    match &events[4] {
+        BattleEvent::SubstituteDamaged(SubstituteDamaged { pokemon: 0, amount }) => {
+            assert_eq!(*amount, max_hp / 4);
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+    assert_event!(events[5], SubstituteBroken { pokemon: 0 });
+    assert!(!backend.has_flag(0, "substitute"));
+    assert_eq!(backend.get_pokemon(0).current_hp, max_hp - max_hp / 4);
+}
+
+#[test]
+fn substitute_blocks_status_moves() {
+    let mut backend = battle! {
+        "Kadabra" 30 (moves ["Substitute"], max ivs, Serious)
+        vs
+        "Rattata" 10 (moves ["TailWhip"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Substitute", "TailWhip");
+
+    assert_event!(events[4], FailedMove { move_user: 1 });
+}
+
+#[test]
+fn sound_based_moves_go_through_substitutes() {
+    let mut backend = battle! {
+        "Kadabra" 30 (moves ["Substitute"], max ivs, Serious)
+        vs
+        "Pidgey" 10 (moves ["Growl"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Substitute", "Growl");
+
+    assert_event!(events[4], StatChange {
+        target: 0,
+        kind: StatChangeKind::Fell,
+        stat: Stat::Attack,
+    });
+}
+
+#[test]
+fn substitute_fails_if_the_user_cannot_pay_for_it() {
+    let mut backend = battle! {
+        "Kadabra" 30 (moves ["Substitute"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).current_hp = backend.get_stat(0, Stat::HP) / 4;
+    let events = backend.process_turn("Substitute", "Harden");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+}
+
+#[test]
+fn substitute_blocks_secondary_effects() {
+    let mut backend = battle! {
+        "Kadabra" 30 (moves ["Substitute"], max ivs, Serious)
+        vs
+        "Charmander" 10 (moves ["Ember"], max ivs, Serious)
+    };
+
+    test_rng_mut!(backend.rng).force_secondary_effect(1);
+
+    backend.process_turn("Substitute", "Ember");
+
+    assert!(!backend.has_non_volatile_status_condition(0));
+}
@@ -0,0 +1,40 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn sunny_day_starts_harsh_sunlight() {
+    let mut backend = battle! {
+        "Charmander" 20 (moves ["SunnyDay"], max ivs, Serious)
+        vs
+        "Metapod" 20 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("SunnyDay", "Harden");
+
+    assert_event!(events[1], WeatherStart { weather: Weather::Sun });
+    assert_eq!(backend.get_weather(), Some(Weather::Sun));
+}
+
+#[test]
+fn sunny_day_boosts_fire_moves() {
+    let mut backend = battle! {
+        "Charmander" 20 (moves ["SunnyDay", "Ember"], max ivs, Serious)
+        vs
+        "Metapod" 20 (max ivs, Serious)
+    };
+
+    let turn1 = backend.process_turn("Ember", "Harden");
+    backend.process_turn("SunnyDay", "Harden");
+    let turn3 = backend.process_turn("Ember", "Harden");
+
+    match (&turn1[1], &turn3[1]) {
+        (
+            BattleEvent::Damage(Damage { amount: a1, .. }),
+            BattleEvent::Damage(Damage { amount: a2, .. }),
+        ) => {
+            assert!(*a2 > *a1);
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+}
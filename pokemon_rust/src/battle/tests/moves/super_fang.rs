@@ -0,0 +1,32 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn super_fang_halves_the_current_hp_of_the_target() {
+    let mut backend = battle! {
+        "Rattata" 20 (moves ["SuperFang"], max ivs, Serious)
+        vs
+        "Metapod" 20 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(1).current_hp = 41;
+    let events = backend.process_turn("SuperFang", "Harden");
+
+    assert_event!(events[1], Damage { target: 1, amount: 20, .. });
+    assert_eq!(backend.get_pokemon(1).current_hp, 21);
+}
+
+#[test]
+fn super_fang_deals_at_least_1_damage() {
+    let mut backend = battle! {
+        "Rattata" 20 (moves ["SuperFang"], max ivs, Serious)
+        vs
+        "Metapod" 20 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(1).current_hp = 1;
+    backend.process_turn("SuperFang", "Harden");
+
+    assert_eq!(backend.get_pokemon(1).current_hp, 0);
+}
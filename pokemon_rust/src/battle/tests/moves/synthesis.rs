@@ -0,0 +1,50 @@
+use crate::pokemon::Stat;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn synthesis_restores_half_the_maximum_hp_without_weather() {
+    let mut backend = battle! {
+        "Bulbasaur" 30 (moves ["Synthesis"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    let max_hp = backend.get_stat(0, Stat::HP);
+    backend.get_pokemon_mut(0).current_hp = 1;
+    backend.process_turn("Synthesis", "Harden");
+
+    assert_eq!(backend.get_pokemon(0).current_hp, 1 + max_hp / 2);
+}
+
+#[test]
+fn synthesis_restores_more_hp_under_harsh_sunlight() {
+    let mut backend = battle! {
+        "Bulbasaur" 30 (moves ["Synthesis"], max ivs, Serious)
+        vs
+        "Vulpix" 30 (moves ["SunnyDay"], max ivs, Serious)
+    };
+
+    let max_hp = backend.get_stat(0, Stat::HP);
+    backend.process_turn("Synthesis", "SunnyDay");
+    backend.get_pokemon_mut(0).current_hp = 1;
+    backend.process_turn("Synthesis", "SunnyDay");
+
+    assert_eq!(backend.get_pokemon(0).current_hp, 1 + max_hp * 2 / 3);
+}
+
+#[test]
+fn synthesis_restores_less_hp_under_other_weather() {
+    let mut backend = battle! {
+        "Bulbasaur" 30 (moves ["Synthesis"], max ivs, Serious)
+        vs
+        "Vulpix" 30 (moves ["RainDance"], max ivs, Serious)
+    };
+
+    let max_hp = backend.get_stat(0, Stat::HP);
+    backend.process_turn("Synthesis", "RainDance");
+    backend.get_pokemon_mut(0).current_hp = 1;
+    backend.process_turn("Synthesis", "RainDance");
+
+    assert_eq!(backend.get_pokemon(0).current_hp, 1 + max_hp / 4);
+}
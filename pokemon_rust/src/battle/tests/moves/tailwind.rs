@@ -0,0 +1,40 @@
+use crate::battle::backend::{BattleEvent, Team};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn tailwind_doubles_the_speed_of_the_team() {
+    let mut backend = battle! {
+        "Pidgey" 20 (moves ["Tailwind"], max ivs, Serious)
+        vs
+        "Slowpoke" 20 (moves ["Splash"], max ivs, Serious)
+    };
+
+    let speed = backend.get_stat(0, Stat::Speed);
+    let events = backend.process_turn("Tailwind", "Splash");
+
+    assert_event!(
+        events[1],
+        SideConditionStart { team: Team::P1, condition: SideCondition::Tailwind }
+    );
+    assert_eq!(backend.get_stat(0, Stat::Speed), speed * 2);
+}
+
+#[test]
+fn tailwind_ends_after_four_turns() {
+    let mut backend = battle! {
+        "Pidgey" 20 (moves ["Tailwind", "Splash"], max ivs, Serious)
+        vs
+        "Slowpoke" 20 (moves ["Splash"], max ivs, Serious)
+    };
+
+    backend.process_turn("Tailwind", "Splash");
+    backend.process_turn("Splash", "Splash");
+    backend.process_turn("Splash", "Splash");
+    let events = backend.process_turn("Splash", "Splash");
+
+    assert_event!(
+        events[2],
+        SideConditionEnd { team: Team::P1, condition: SideCondition::Tailwind }
+    );
+}
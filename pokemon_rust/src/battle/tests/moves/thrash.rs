@@ -0,0 +1,44 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn thrash_locks_the_user_into_the_move() {
+    let mut backend = battle! {
+        "Rattata" 40 (moves ["Thrash", "TailWhip"], max ivs, Serious)
+        vs
+        "Onix" 30 (moves ["Harden"], max ivs, Serious)
+    };
+
+    backend.process_turn("Thrash", "Harden");
+    let events = backend.process_turn("TailWhip", "Harden");
+
+    match &events[0] {
+        BattleEvent::UseMove(UseMove { move_user: 0, move_name }) => {
+            assert_eq!(move_name, "Thrash");
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+    assert_event!(events[1], Damage { target: 1, .. });
+}
+
+#[test]
+fn thrash_confuses_the_user_when_it_ends() {
+    let mut backend = battle! {
+        "Rattata" 40 (moves ["Thrash", "TailWhip"], max ivs, Serious)
+        vs
+        "Onix" 30 (moves ["Harden"], max ivs, Serious)
+    };
+
+    test_rng_mut!(backend.rng).force_rampage_duration(2);
+
+    backend.process_turn("Thrash", "Harden");
+    assert!(backend.has_forced_action(0));
+    let events = backend.process_turn("Thrash", "Harden");
+
+    assert_event!(events[2], VolatileStatusCondition {
+        target: 0,
+        added_flag: Flag::Confusion { .. },
+    });
+    assert!(!backend.has_forced_action(0));
+}
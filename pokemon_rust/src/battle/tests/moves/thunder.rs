@@ -0,0 +1,48 @@
+use crate::{
+    battle::backend::BattleEvent,
+    pokemon::StatusCondition,
+};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn thunder_deals_damage_and_might_paralyze() {
+    let mut backend = battle! {
+        "Pikachu" 44 (max ivs, Serious) vs "Metapod" 50 (max ivs, Serious)
+    };
+
+    test_rng_mut!(backend.rng).force_secondary_effect(1);
+    let events = backend.process_turn("Thunder", "Harden");
+
+    assert_event!(events[1], Damage { target: 1, is_critical_hit: false, .. });
+    assert_eq!(backend.get_pokemon(1).status_condition, Some(StatusCondition::Paralysis));
+}
+
+#[test]
+fn thunder_never_misses_in_rain() {
+    let mut backend = battle! {
+        "Pikachu" 44 (moves ["Thunder", "RainDance"], max ivs, Serious)
+        vs
+        "Metapod" 50 (max ivs, Serious)
+    };
+
+    backend.process_turn("RainDance", "Harden");
+    test_rng_mut!(backend.rng).force_miss(1);
+    let events = backend.process_turn("Thunder", "Harden");
+
+    assert_event!(events[1], Damage { target: 1, .. });
+}
+
+#[test]
+fn thunder_has_lower_accuracy_in_harsh_sunlight() {
+    let mut backend = battle! {
+        "Pikachu" 44 (moves ["Thunder", "SunnyDay"], max ivs, Serious)
+        vs
+        "Metapod" 50 (max ivs, Serious)
+    };
+
+    backend.process_turn("SunnyDay", "Harden");
+    backend.process_turn("Thunder", "Harden");
+
+    assert_eq!(test_rng!(backend.rng).get_last_miss_check_chance(), Some(50));
+}
@@ -0,0 +1,64 @@
+use crate::battle::backend::{BattleEvent, FieldPosition, Protection, Team};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn wide_guard_blocks_moves_that_affect_multiple_pokemon() {
+    let mut backend = battle! {
+        "Machop" 20 (moves ["WideGuard"], max ivs, Serious)
+        vs
+        "Rattata" 20 (moves ["RockSlide"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("WideGuard", "RockSlide");
+
+    assert_event!(
+        events[1],
+        VolatileStatusCondition { target: 0, added_flag: Flag::Protection(Protection::WideGuard) }
+    );
+    assert_event!(events[2], UseMove { move_user: 1, .. });
+    assert_event!(events[3], Protected { target: 0, protection: Protection::WideGuard });
+}
+
+#[test]
+fn wide_guard_does_not_block_single_target_moves() {
+    let mut backend = battle! {
+        "Machop" 20 (moves ["WideGuard"], max ivs, Serious)
+        vs
+        "Rattata" 20 (moves ["Tackle"], max ivs, Serious)
+    };
+
+    let events = backend.process_turn("WideGuard", "Tackle");
+
+    assert_event!(events[3], Damage { target: 0, .. });
+}
+
+#[test]
+fn wide_guard_protects_the_whole_team() {
+    let p1 = vec![
+        pokemon_setup!("Machop" 20 (moves ["WideGuard"], max ivs, Serious)),
+        pokemon_setup!("Rattata" 20 (moves ["Splash"], max ivs, Serious)),
+    ];
+    let p2 = vec![
+        pokemon_setup!("Squirtle" 20 (moves ["RockSlide"], max ivs, Serious)),
+        pokemon_setup!("Rattata" 20 (moves ["Splash"], max ivs, Serious)),
+    ];
+    let mut backend = create_double_battle(p1, p2);
+    let _ = backend.tick();
+
+    backend.use_move(FieldPosition { team: Team::P1, slot: 0 }, "WideGuard", None);
+    backend.use_move(FieldPosition { team: Team::P1, slot: 1 }, "Splash", None);
+    backend.use_move(FieldPosition { team: Team::P2, slot: 0 }, "RockSlide", None);
+    backend.use_move(FieldPosition { team: Team::P2, slot: 1 }, "Splash", None);
+    let events: Vec<_> = backend.tick().collect();
+
+    let protected_pokemon: Vec<_> = events
+        .iter()
+        .filter_map(|event| match event {
+            BattleEvent::Protected(Protected { target, .. }) => Some(*target),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(protected_pokemon, vec![0, 1]);
+}
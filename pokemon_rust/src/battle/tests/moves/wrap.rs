@@ -0,0 +1,42 @@
+use crate::battle::backend::{BattleEvent, DamageCause};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn wrap_traps_the_target_and_hurts_it_at_the_end_of_the_turn() {
+    let mut backend = battle! {
+        "Ekans" 10 (moves ["Wrap"], max ivs, Serious)
+        vs
+        "Metapod" 10 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Wrap", "Harden");
+
+    assert_event!(events[1], Damage { target: 1, cause: DamageCause::Move, .. });
+    assert_event!(events[2], VolatileStatusCondition {
+        target: 1,
+        added_flag: Flag::Trapped { trapper: 0, .. },
+    });
+    assert_event!(events[5], Damage { target: 1, cause: DamageCause::Trap, .. });
+    assert!(backend.is_trapped(1));
+}
+
+#[test]
+fn wrap_frees_the_target_when_it_runs_out() {
+    let mut backend = battle! {
+        "Ekans" 10 (moves ["Wrap", "Leer"], max ivs, Serious)
+        vs
+        "Metapod" 10 (max ivs, Serious)
+    };
+
+    test_rng_mut!(backend.rng).force_trap_duration(2);
+
+    backend.process_turn("Wrap", "Harden");
+    let events = backend.process_turn("Leer", "Harden");
+
+    assert_event!(events[4], ExpiredVolatileStatusCondition {
+        target: 1,
+        flag: Flag::Trapped { .. },
+    });
+    assert!(!backend.is_trapped(1));
+}
@@ -0,0 +1,59 @@
+use crate::{battle::backend::BattleEvent, pokemon::StatusCondition};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn yawn_puts_the_target_to_sleep_at_the_end_of_the_next_turn() {
+    let mut backend = battle! {
+        "Kadabra" 30 (moves ["Yawn", "Splash"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    backend.process_turn("Yawn", "Harden");
+    assert!(backend.has_flag(1, "drowsy"));
+    assert!(backend.get_pokemon(1).status_condition.is_none());
+
+    let events = backend.process_turn("Splash", "Harden");
+
+    assert!(events.iter().any(|event| match event {
+        BattleEvent::NonVolatileStatusCondition(NonVolatileStatusCondition {
+            target: 1,
+            condition: StatusCondition::Sleep { .. },
+        }) => true,
+        _ => false,
+    }));
+    assert!(!backend.has_flag(1, "drowsy"));
+}
+
+#[test]
+fn yawn_sleep_duration_is_random() {
+    let mut backend = battle! {
+        "Kadabra" 30 (moves ["Yawn", "Splash"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    test_rng_mut!(backend.rng).force_sleep_duration(2);
+    backend.process_turn("Yawn", "Harden");
+    backend.process_turn("Splash", "Harden");
+
+    assert_eq!(
+        backend.get_pokemon(1).status_condition,
+        Some(StatusCondition::Sleep { remaining_turns: 2 })
+    );
+}
+
+#[test]
+fn yawn_fails_if_the_target_is_already_drowsy() {
+    let mut backend = battle! {
+        "Kadabra" 30 (moves ["Yawn"], max ivs, Serious)
+        vs
+        "Metapod" 30 (max ivs, Serious)
+    };
+
+    backend.process_turn("Yawn", "Harden");
+    let events = backend.process_turn("Yawn", "Harden");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+}
@@ -0,0 +1,112 @@
+use crate::battle::{
+    ai::RandomAi,
+    backend::{rng::SeededBattleRng, BattleEvent, FrontendEvent, FrontendEventKind, Team},
+    replay::{BattleRecorder, Replay},
+};
+
+use super::prelude::*;
+
+fn create_recorder() -> BattleRecorder {
+    let p1 = pokemon_setup!("Rattata" 20 (moves ["Tackle", "TailWhip"], max ivs, Serious));
+    let p2 = pokemon_setup!("Pidgey" 20 (moves ["Tackle", "SandAttack"], max ivs, Serious));
+
+    let battle = create_simple_wild_battle_data(p1, p2);
+
+    BattleRecorder::new(battle, SeededBattleRng::new(42))
+}
+
+fn record_turns(recorder: &mut BattleRecorder, count: usize) {
+    let _ = recorder.tick();
+
+    for turn in 0..count {
+        for team in [Team::P1, Team::P2].iter() {
+            recorder.push_frontend_event(FrontendEvent {
+                team: *team,
+                slot: 0,
+                event: FrontendEventKind::UseMove {
+                    move_index: turn % 2,
+                    target: None,
+                },
+            });
+        }
+
+        let _ = recorder.tick();
+    }
+}
+
+#[test]
+fn records_the_actions_and_events_of_every_turn() {
+    let mut recorder = create_recorder();
+    record_turns(&mut recorder, 3);
+
+    let replay = recorder.get_replay();
+
+    assert_eq!(replay.turns.len(), 4);
+    assert!(replay.turns[0].actions.is_empty());
+    assert_event!(replay.turns[0].events[0], InitialSwitchIn { .. });
+    assert_eq!(replay.turns[1].actions.len(), 2);
+    assert_eq!(replay.rng.get_seed(), 42);
+}
+
+#[test]
+fn replays_a_recorded_battle() {
+    let mut recorder = create_recorder();
+    record_turns(&mut recorder, 4);
+
+    assert!(recorder.get_replay().play().is_ok());
+}
+
+#[test]
+fn replays_a_battle_against_an_ai_that_draws_from_the_battle_rng() {
+    let mut recorder = create_recorder();
+    let _ = recorder.tick();
+
+    for _ in 0..4 {
+        recorder.push_frontend_event(FrontendEvent {
+            team: Team::P1,
+            slot: 0,
+            event: FrontendEventKind::UseMove {
+                move_index: 0,
+                target: None,
+            },
+        });
+        recorder.push_opponent_actions(&mut RandomAi);
+        let _ = recorder.tick();
+    }
+
+    let replay = recorder.get_replay();
+
+    assert!(replay.turns[0].ai_actions.is_none());
+    assert_eq!(replay.turns[1].actions.len(), 1);
+    assert_eq!(replay.turns[1].ai_actions.as_ref().unwrap().len(), 1);
+    assert!(replay.play().is_ok());
+}
+
+#[test]
+fn replays_a_battle_loaded_from_a_file() {
+    let mut recorder = create_recorder();
+    record_turns(&mut recorder, 4);
+
+    let path = std::env::temp_dir().join("pokemon_rust_test_replay.ron");
+    recorder.save(&path).unwrap();
+    let replay = Replay::load(&path);
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(replay.turns.len(), recorder.get_replay().turns.len());
+    assert!(replay.play().is_ok());
+}
+
+#[test]
+fn reports_the_first_turn_where_the_replay_diverges() {
+    let mut recorder = create_recorder();
+    record_turns(&mut recorder, 4);
+
+    let mut replay = recorder.get_replay().clone();
+    replay.turns[2].events.pop();
+    replay.turns[3].events.pop();
+
+    let divergence = replay.play().unwrap_err();
+
+    assert_eq!(divergence.turn, 2);
+    assert_eq!(divergence.actual.len(), divergence.expected.len() + 1);
+}
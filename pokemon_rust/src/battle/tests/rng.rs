@@ -0,0 +1,95 @@
+use crate::{
+    battle::backend::{
+        rng::{BattleRng, SeededBattleRng},
+        BattleBackend,
+        BattleEvent,
+    },
+    pokemon::{get_all_moves, movement::MultiHit},
+};
+
+use super::{prelude::*, TestMethods};
+
+fn create_seeded_battle(seed: u64) -> BattleBackend {
+    let mut backend = battle_setup! {
+        "Onix" 20 (moves ["Tackle"], max ivs, Serious)
+        vs
+        "Onix" 20 (moves ["Tackle"], max ivs, Serious)
+    };
+
+    backend.rng = Box::new(SeededBattleRng::new(seed));
+    let _ = backend.tick();
+    backend
+}
+
+fn process_turns(backend: &mut BattleBackend, count: usize) -> Vec<BattleEvent> {
+    (0..count).flat_map(|_| backend.process_turn("Tackle", "Tackle")).collect()
+}
+
+#[test]
+fn battles_with_the_same_seed_play_out_the_same_way() {
+    let mut backend1 = create_seeded_battle(42);
+    let mut backend2 = create_seeded_battle(42);
+
+    assert_eq!(process_turns(&mut backend1, 5), process_turns(&mut backend2, 5));
+}
+
+#[test]
+fn battles_with_different_seeds_diverge() {
+    let mut backend1 = create_seeded_battle(1);
+    let mut backend2 = create_seeded_battle(2);
+
+    assert_ne!(process_turns(&mut backend1, 5), process_turns(&mut backend2, 5));
+}
+
+#[test]
+fn seeded_rng_can_be_resumed_from_its_serialized_state() {
+    let mut rng = SeededBattleRng::new(1234);
+
+    for _ in 0..10 {
+        rng.get_damage_modifier();
+    }
+
+    let state = ron::to_string(&rng).unwrap();
+    let mut restored: SeededBattleRng = ron::from_str(&state).unwrap();
+
+    assert_eq!(restored, rng);
+    assert_eq!(restored.get_seed(), 1234);
+
+    for _ in 0..10 {
+        assert_eq!(restored.check_uniform_multi_hit(0, 1000), rng.check_uniform_multi_hit(0, 1000));
+    }
+}
+
+#[test]
+fn custom_multi_hit_moves_advance_the_battle_rng() {
+    let mut rng = SeededBattleRng::new(7);
+    let initial_rng = rng.clone();
+
+    match get_all_moves().get_move("CometPunch").unwrap().multi_hit {
+        Some(MultiHit::Custom(callback)) => callback(&mut rng),
+        _ => panic!("Pattern mismatch"),
+    };
+
+    assert_ne!(rng, initial_rng);
+}
+
+#[test]
+fn accuracy_boosts_above_100_never_miss() {
+    let mut backend = battle_setup! {
+        "Butterfree" 20 (moves ["Tackle"], ability "CompoundEyes", max ivs, Serious)
+        vs
+        "Onix" 20 (moves ["Harden"], max ivs, Serious)
+    };
+
+    backend.rng = Box::new(SeededBattleRng::new(3));
+    let _ = backend.tick();
+
+    for _ in 0..10 {
+        let events = backend.process_turn("Tackle", "Harden");
+
+        assert!(!events.iter().any(|event| match event {
+            BattleEvent::Miss(_) => true,
+            _ => false,
+        }));
+    }
+}
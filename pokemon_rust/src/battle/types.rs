@@ -1,11 +1,17 @@
 use amethyst::ecs::{Component, DenseVecStorage};
 
-use crate::{overworld::entities::character::CharacterId, pokemon::Pokemon};
+use crate::{
+    battle::{ai::BattleAiKind, backend::event::BattleEnd},
+    overworld::entities::character::CharacterId,
+    pokemon::Pokemon,
+};
+
+use serde::{Deserialize, Serialize};
 
 use std::collections::VecDeque;
 
 /// Represents a Pokémon Battle.
-#[derive(Clone)]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct Battle {
     /// The type of battle that is happening.
     pub battle_type: BattleType,
@@ -16,6 +22,12 @@ pub struct Battle {
     pub p1: BattleCharacterTeam,
     /// The Pokémon that make up the second team.
     pub p2: BattleCharacterTeam,
+    /// How the battle ended. This is filled by the battle system once the
+    /// battle is over and signals that the battle state can be left.
+    pub outcome: Option<BattleEnd>,
+    /// The wild Pokémon that was caught during the battle, if any. This is
+    /// filled by the battle system alongside `outcome`.
+    pub caught_pokemon: Option<Pokemon>,
 }
 
 impl Battle {
@@ -29,29 +41,47 @@ impl Battle {
             turn: 0,
             p1,
             p2,
+            outcome: None,
+            caught_pokemon: None,
         }
     }
 }
 
 /// Represents the type of battle that is happening.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum BattleType {
     Single,
+    /// Only supported by the backend for now, since the frontend can only
+    /// gather the actions of a single Pokémon per team. The battle state
+    /// refuses to start them.
+    Double,
+}
+
+impl BattleType {
+    /// Returns how many Pokémon of each team are on the field at once.
+    pub fn get_active_slot_count(&self) -> usize {
+        match self {
+            BattleType::Single => 1,
+            BattleType::Double => 2,
+        }
+    }
 }
 
 /// Represents which Pokémon make up a team.
-#[derive(Clone)]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct BattleCharacterTeam {
-    // TODO: change this to a Vec to implement doubles/triples
     /// The active Pokémon of this team.
     pub active_pokemon: Option<Pokemon>,
     /// The Pokémon party of this team.
     pub party: Party,
     /// If this team is owned by a trainer, contains its character ID.
     pub character_id: Option<CharacterId>,
+    /// The strategy used to choose the actions of this team, or `None` if
+    /// it's controlled by the player.
+    pub ai: Option<BattleAiKind>,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct Party {
     pub pokemon: VecDeque<Pokemon>,
 }
@@ -25,4 +25,7 @@ pub struct GameConfig {
     pub play_sfx: bool,
     /// Decides whether the FPS should be printed.
     pub show_fps: bool,
+    /// Decides whether battles should be recorded to the `replays`
+    /// directory, so that they can be played again to reproduce bugs.
+    pub record_battles: bool,
 }
@@ -6,11 +6,13 @@ use amethyst::{
 
 use crate::{
     audio::{AudioFileFormat, Music},
+    battle::{ai::BattleAiKind, types::BattleType},
     common::Direction,
     map::MapCoordinates,
     overworld::{
         entities::character::CharacterId,
         events::{
+            BattleStartEvent,
             BgmChangeEvent,
             ChainedEvents,
             CharacterMoveEvent,
@@ -106,6 +108,16 @@ pub(super) fn create_text_event(context: &mut ExecutionContext, text: String) ->
     context.store(event)
 }
 
+pub(super) fn create_trainer_battle_event(
+    context: &mut ExecutionContext,
+    character_id: CharacterId,
+    ai: BattleAiKind,
+) -> usize {
+    let event = BattleStartEvent::against_trainer(BattleType::Single, character_id, ai);
+
+    context.store(event)
+}
+
 pub(super) fn create_warp_event(
     context: &mut ExecutionContext,
     map: String,
@@ -137,7 +149,9 @@ pub(super) fn dispatch_event(context: &mut ExecutionContext, key: usize) {
 fn remove_event(context: &mut ExecutionContext, key: usize) -> Box<dyn GameEvent + Send + Sync> {
     let event = context.remove_boxed(key);
 
-    if event.is::<BgmChangeEvent>() {
+    if event.is::<BattleStartEvent>() {
+        event.downcast::<BattleStartEvent>().unwrap()
+    } else if event.is::<BgmChangeEvent>() {
         event.downcast::<BgmChangeEvent>().unwrap()
     } else if event.is::<ChainedEvents>() {
         event.downcast::<ChainedEvents>().unwrap()
@@ -9,6 +9,7 @@ mod polymorphic_container;
 use amethyst::{ecs::World, utils::application_root_dir};
 
 use crate::{
+    battle::ai::BattleAiKind,
     common::{AssetTracker, Direction},
     map::{CoordinateSystem, GameScriptParameters},
     overworld::entities::character::CharacterId,
@@ -26,6 +27,7 @@ use self::{
         create_npc_rotate_event,
         create_npc_rotate_towards_player_event,
         create_text_event,
+        create_trainer_battle_event,
         create_warp_event,
         dispatch_event,
         preload_bgm,
@@ -69,6 +71,26 @@ impl<'lua> FromLua<'lua> for Direction {
     }
 }
 
+impl<'lua> FromLua<'lua> for BattleAiKind {
+    fn from_lua(lua_value: Value<'lua>, context: Context<'lua>) -> LuaResult<Self> {
+        let lua_type_name = get_lua_type_name(&lua_value);
+        let ai = match context.coerce_integer(lua_value)? {
+            Some(0) => BattleAiKind::Random,
+            Some(1) => BattleAiKind::Greedy,
+            Some(2) => BattleAiKind::Lookahead,
+            _ => {
+                return Err(LuaError::FromLuaConversionError {
+                    from: lua_type_name,
+                    to: "BattleAiKind",
+                    message: Some("expected a value in the range 0..=2".to_string()),
+                })
+            },
+        };
+
+        Ok(ai)
+    }
+}
+
 /// Returns a string represention of a Lua type. This is a copy of
 /// `Value::type_name()`, which for some reason is private...
 fn get_lua_type_name(value: &Value) -> &'static str {
@@ -231,6 +253,8 @@ where
                 rust_create_npc_rotate_towards_player_event:
                     create_npc_rotate_towards_player_event(character_id: CharacterId),
                 rust_create_text_event: create_text_event(text: String),
+                rust_create_trainer_battle_event:
+                    create_trainer_battle_event(character_id: CharacterId, ai: BattleAiKind),
                 rust_create_warp_event: create_warp_event(map: String, x: u32, y: u32),
                 rust_add_event: add_event(chain_key: usize, new_event: usize),
                 rust_dispatch_event: dispatch_event(key: usize),
@@ -273,6 +273,12 @@ pub struct TileData {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct MapId(String);
 
+impl MapId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
 // TODO: find a better name
 pub struct ValidatedGameAction {
     pub when: GameActionKind,
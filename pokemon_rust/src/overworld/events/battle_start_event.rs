@@ -3,7 +3,10 @@
 use amethyst::ecs::{World, WorldExt};
 
 use crate::{
-    battle::types::{Battle, BattleCharacterTeam, BattleType, Party},
+    battle::{
+        ai::BattleAiKind,
+        types::{Battle, BattleCharacterTeam, BattleType, Party},
+    },
     map::MapHandler,
     overworld::entities::character::{CharacterId, PlayerEntity},
     pokemon::{generator::generate_pokemon, get_all_moves, get_all_pokemon_species},
@@ -19,15 +22,19 @@ pub struct BattleStartEvent {
 
 #[derive(Clone)]
 enum BattleOpponent {
-    Trainer(CharacterId),
+    Trainer(CharacterId, BattleAiKind),
     WildPokemon,
 }
 
 impl BattleStartEvent {
-    pub fn against_trainer(battle_type: BattleType, character_id: CharacterId) -> BattleStartEvent {
+    pub fn against_trainer(
+        battle_type: BattleType,
+        character_id: CharacterId,
+        ai: BattleAiKind,
+    ) -> BattleStartEvent {
         BattleStartEvent {
             battle_type,
-            opponent: BattleOpponent::Trainer(character_id),
+            opponent: BattleOpponent::Trainer(character_id, ai),
         }
     }
 
@@ -62,38 +69,46 @@ impl GameEvent for BattleStartEvent {
 
         let battle_type = self.battle_type.clone();
 
-        let party = {
-            let rattata = generate_pokemon(&pokedex.get_species("Clefairy").unwrap(), &movedex, 10);
-
-            Party {
-                pokemon: vec![rattata].into(),
-            }
+        world.register::<Party>();
+        let player_party = world.read_storage::<Party>().get(player_entity).cloned();
+
+        // TODO: handle players without Pokémon able to fight (e.g after losing
+        // a battle) instead of lending them one
+        let party = match player_party {
+            Some(party) if party.pokemon.iter().any(|pokemon| pokemon.current_hp > 0) => party,
+            _ => {
+                let rattata =
+                    generate_pokemon(&pokedex.get_species("Clefairy").unwrap(), &movedex, 10);
+
+                Party {
+                    pokemon: vec![rattata].into(),
+                }
+            },
         };
 
-        // TODO: do this somewhere to persist the player's Party
-        // world
-        //     .write_storage::<Party>()
-        //     .insert(player_entity, party)
-        //     .expect("Failed to attach Party");
-
         let p1 = BattleCharacterTeam {
             active_pokemon: None,
             party,
             character_id: Some(player_id),
+            ai: None,
         };
 
         let p2 = {
             let pidgey = generate_pokemon(&pokedex.get_species("Butterfree").unwrap(), &movedex, 9);
 
+            // Wild Pokémon act randomly
+            let (character_id, ai) = match self.opponent {
+                BattleOpponent::Trainer(character_id, ai) => (Some(character_id), ai),
+                BattleOpponent::WildPokemon => (None, BattleAiKind::Random),
+            };
+
             BattleCharacterTeam {
                 active_pokemon: None,
                 party: Party {
                     pokemon: vec![pidgey].into(),
                 },
-                character_id: match self.opponent {
-                    BattleOpponent::Trainer(character_id) => Some(character_id),
-                    BattleOpponent::WildPokemon => None,
-                },
+                character_id,
+                ai: Some(ai),
             }
         };
 
@@ -0,0 +1,743 @@
+use crate::{
+    battle::backend::{BattleBackend, DamageCause, Flag, TypeEffectiveness, Weather},
+    pokemon::{
+        get_all_moves,
+        movement::{Move, MoveCategory, MoveFlag, MovePower},
+        PokemonType,
+        SimpleStatusCondition,
+        Stat,
+        StatusCondition,
+    },
+};
+
+use std::fmt::{Debug, Error, Formatter};
+
+#[derive(Clone, Default)]
+pub struct AbilityEffect {
+    /// Called when the owner enters the battlefield.
+    pub on_switch_in: Option<fn(backend: &mut BattleBackend, owner: usize)>,
+
+    /// Called when the owner is hit by a move that makes contact.
+    pub on_contact: Option<fn(backend: &mut BattleBackend, owner: usize, attacker: usize)>,
+
+    /// Called after the owner takes damage from a move.
+    pub on_after_hit: Option<fn(
+        backend: &mut BattleBackend,
+        owner: usize,
+        attacker: usize,
+        mov: &Move,
+    )>,
+
+    /// Called when a move used by the owner makes its target faint.
+    pub on_knock_out: Option<fn(backend: &mut BattleBackend, owner: usize, target: usize)>,
+
+    /// Called when backend.get_stat() is called, receiving the value that it
+    /// is about to return.
+    pub on_stat_calculation: Option<fn(
+        backend: &BattleBackend,
+        owner: usize,
+        stat: Stat,
+        value: usize,
+    ) -> usize>,
+
+    /// Called when the accuracy of a move used by the owner is calculated.
+    pub on_accuracy_calculation: Option<fn(
+        backend: &BattleBackend,
+        owner: usize,
+        target: usize,
+        mov: &Move,
+        accuracy: f32,
+    ) -> f32>,
+
+    /// Called when the accuracy of a move that targets the owner is
+    /// calculated.
+    pub on_evasion_calculation: Option<fn(
+        backend: &BattleBackend,
+        owner: usize,
+        user: usize,
+        mov: &Move,
+        accuracy: f32,
+    ) -> f32>,
+
+    /// Returns a multiplier for the damage dealt by the owner's moves.
+    pub on_modify_damage_dealt: Option<fn(
+        backend: &BattleBackend,
+        owner: usize,
+        target: usize,
+        mov: &Move,
+    ) -> f32>,
+
+    /// Returns a multiplier for the damage taken by the owner from moves.
+    pub on_modify_damage_taken: Option<fn(
+        backend: &BattleBackend,
+        owner: usize,
+        user: usize,
+        mov: &Move,
+    ) -> f32>,
+
+    /// Determines if the owner is immune to a move used by another Pokémon.
+    /// Abilities that absorb moves (e.g Water Absorb) also apply their
+    /// effects here.
+    pub on_check_move_immunity: Option<fn(
+        backend: &mut BattleBackend,
+        owner: usize,
+        user: usize,
+        mov: &Move,
+    ) -> bool>,
+
+    /// Determines if the owner is immune to a non-volatile status condition.
+    pub on_check_status_immunity: Option<fn(
+        backend: &BattleBackend,
+        owner: usize,
+        condition: SimpleStatusCondition,
+    ) -> bool>,
+
+    /// Determines if the owner is immune to a volatile status condition.
+    pub on_check_flag_immunity: Option<fn(
+        backend: &BattleBackend,
+        owner: usize,
+        flag: &Flag,
+    ) -> bool>,
+
+    /// Determines if the owner's stats can't be lowered by other Pokémon.
+    pub on_check_stat_drop_immunity: Option<fn(
+        backend: &BattleBackend,
+        owner: usize,
+        stat: Stat,
+    ) -> bool>,
+
+    /// Called when the turn ends.
+    pub on_turn_end: Option<fn(backend: &mut BattleBackend, owner: usize)>,
+}
+
+impl Debug for AbilityEffect {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        f.write_str("AbilityEffect")
+    }
+}
+
+/// Returns the effects of an ability. Some abilities are also checked
+/// directly by the backend through `BattleBackend::has_ability`, since they
+/// change a single specific mechanic (e.g Run Away or Sturdy).
+pub fn get_ability_effect(ability: &str) -> AbilityEffect {
+    match ability {
+        "Adaptability" => AbilityEffect {
+            on_modify_damage_dealt: Some(|backend, owner, _target, mov| {
+                if backend.has_type(owner, mov.move_type) {
+                    // Raises the STAB from 1.5x to 2x
+                    2. / 1.5
+                } else {
+                    1.
+                }
+            }),
+            ..AbilityEffect::default()
+        },
+        "Anticipation" => AbilityEffect {
+            on_switch_in: Some(|backend, owner| {
+                let movedex = get_all_moves();
+
+                let is_threatened = backend.get_adjacent_foes(owner).into_iter().any(|foe| {
+                    backend
+                        .get_pokemon(foe)
+                        .moves
+                        .iter()
+                        .flatten()
+                        .filter_map(|id| movedex.get_move(id))
+                        .any(|mov| {
+                            let is_damaging = mov.category != MoveCategory::Status;
+                            let effectiveness = backend.get_type_effectiveness(mov, owner);
+
+                            mov.flags.contains(&MoveFlag::OneHitKO)
+                                || (is_damaging && effectiveness > 1.)
+                        })
+                });
+
+                if is_threatened {
+                    backend.activate_ability(owner);
+                }
+            }),
+            ..AbilityEffect::default()
+        },
+        "BigPecks" => AbilityEffect {
+            on_check_stat_drop_immunity: Some(|_backend, _owner, stat| stat == Stat::Defense),
+            ..AbilityEffect::default()
+        },
+        "Blaze" => AbilityEffect {
+            on_modify_damage_dealt: Some(|backend, owner, _target, mov| {
+                get_pinch_modifier(backend, owner, mov, PokemonType::Fire)
+            }),
+            ..AbilityEffect::default()
+        },
+        "Chlorophyll" => AbilityEffect {
+            on_stat_calculation: Some(|backend, _owner, stat, value| {
+                if stat == Stat::Speed && backend.get_weather() == Some(Weather::Sun) {
+                    value * 2
+                } else {
+                    value
+                }
+            }),
+            ..AbilityEffect::default()
+        },
+        "ClearBody" => AbilityEffect {
+            on_check_stat_drop_immunity: Some(|_backend, _owner, _stat| true),
+            ..AbilityEffect::default()
+        },
+        "CloudNine" | "NeutralizingGas" => AbilityEffect {
+            // The effects of these abilities are checked by the backend as
+            // long as their owner is in the battlefield
+            on_switch_in: Some(|backend, owner| {
+                backend.activate_ability(owner);
+            }),
+            ..AbilityEffect::default()
+        },
+        "CompoundEyes" => AbilityEffect {
+            on_accuracy_calculation: Some(|_backend, _owner, _target, _mov, accuracy| {
+                accuracy * 1.3
+            }),
+            ..AbilityEffect::default()
+        },
+        "Download" => AbilityEffect {
+            on_switch_in: Some(|backend, owner| {
+                let foes = backend.get_adjacent_foes(owner);
+
+                if foes.is_empty() {
+                    return;
+                }
+
+                let defense: usize = foes
+                    .iter()
+                    .map(|foe| backend.get_stat(*foe, Stat::Defense))
+                    .sum();
+                let special_defense: usize = foes
+                    .iter()
+                    .map(|foe| backend.get_stat(*foe, Stat::SpecialDefense))
+                    .sum();
+
+                let stat = if defense < special_defense {
+                    Stat::Attack
+                } else {
+                    Stat::SpecialAttack
+                };
+
+                backend.activate_ability(owner);
+                backend.change_stat_stage(owner, stat, 1);
+            }),
+            ..AbilityEffect::default()
+        },
+        "Drought" => AbilityEffect {
+            on_switch_in: Some(|backend, owner| {
+                backend.activate_ability(owner);
+                backend.start_weather(Weather::Sun);
+            }),
+            ..AbilityEffect::default()
+        },
+        "DrySkin" => AbilityEffect {
+            on_modify_damage_taken: Some(|_backend, _owner, _user, mov| {
+                if mov.move_type == PokemonType::Fire {
+                    1.25
+                } else {
+                    1.
+                }
+            }),
+            on_check_move_immunity: Some(|backend, owner, _user, mov| {
+                if mov.move_type != PokemonType::Water {
+                    return false;
+                }
+
+                let max_hp = backend.get_stat(owner, Stat::HP);
+
+                backend.activate_ability(owner);
+                backend.heal(owner, max_hp / 4);
+
+                true
+            }),
+            on_turn_end: Some(|backend, owner| {
+                let max_hp = backend.get_stat(owner, Stat::HP);
+
+                match backend.get_weather() {
+                    Some(Weather::Rain) if backend.get_pokemon(owner).current_hp < max_hp => {
+                        backend.activate_ability(owner);
+                        backend.heal(owner, max_hp / 8);
+                    },
+                    Some(Weather::Sun) => {
+                        backend.activate_ability(owner);
+                        inflict_ability_damage(backend, owner, max_hp / 8);
+                    },
+                    _ => {},
+                }
+            }),
+            ..AbilityEffect::default()
+        },
+        "EffectSpore" => AbilityEffect {
+            on_contact: Some(|backend, owner, attacker| {
+                // Grass-type Pokémon are immune to spores
+                if backend.has_type(attacker, PokemonType::Grass) {
+                    return;
+                }
+
+                if backend.check_ability_activation(30) {
+                    let condition = match backend.get_effect_spore_condition() {
+                        SimpleStatusCondition::Paralysis => StatusCondition::Paralysis,
+                        SimpleStatusCondition::Sleep => StatusCondition::Sleep {
+                            remaining_turns: backend.get_sleep_duration(),
+                        },
+                        _ => StatusCondition::Poison,
+                    };
+
+                    inflict_status_condition(backend, owner, attacker, condition);
+                }
+            }),
+            ..AbilityEffect::default()
+        },
+        "FlashFire" => AbilityEffect {
+            on_modify_damage_dealt: Some(|backend, owner, _target, mov| {
+                if mov.move_type == PokemonType::Fire && backend.has_flag(owner, "flash_fire") {
+                    1.5
+                } else {
+                    1.
+                }
+            }),
+            on_check_move_immunity: Some(|backend, owner, _user, mov| {
+                if mov.move_type != PokemonType::Fire {
+                    return false;
+                }
+
+                backend.activate_ability(owner);
+                backend.add_flag(owner, Flag::FlashFire);
+
+                true
+            }),
+            ..AbilityEffect::default()
+        },
+        "Guts" => AbilityEffect {
+            // Guts also ignores the Attack drop caused by burns
+            on_stat_calculation: Some(|backend, owner, stat, value| {
+                if stat == Stat::Attack && backend.has_non_volatile_status_condition(owner) {
+                    value * 3 / 2
+                } else {
+                    value
+                }
+            }),
+            ..AbilityEffect::default()
+        },
+        "Hustle" => AbilityEffect {
+            on_stat_calculation: Some(|_backend, _owner, stat, value| {
+                if stat == Stat::Attack {
+                    value * 3 / 2
+                } else {
+                    value
+                }
+            }),
+            on_accuracy_calculation: Some(|_backend, _owner, _target, mov, accuracy| {
+                if mov.category == MoveCategory::Physical {
+                    accuracy * 0.8
+                } else {
+                    accuracy
+                }
+            }),
+            ..AbilityEffect::default()
+        },
+        "Hydration" => AbilityEffect {
+            on_turn_end: Some(|backend, owner| {
+                let is_raining = backend.get_weather() == Some(Weather::Rain);
+
+                if is_raining && backend.has_non_volatile_status_condition(owner) {
+                    backend.activate_ability(owner);
+                    backend.remove_non_volatile_status_condition(owner);
+                }
+            }),
+            ..AbilityEffect::default()
+        },
+        "HyperCutter" => AbilityEffect {
+            on_check_stat_drop_immunity: Some(|_backend, _owner, stat| stat == Stat::Attack),
+            ..AbilityEffect::default()
+        },
+        "InnerFocus" => AbilityEffect {
+            // Also protects against Intimidate
+            on_check_flag_immunity: Some(|_backend, _owner, flag| *flag == Flag::Flinch),
+            ..AbilityEffect::default()
+        },
+        "Intimidate" => AbilityEffect {
+            on_switch_in: Some(|backend, owner| {
+                backend.activate_ability(owner);
+
+                for foe in backend.get_adjacent_foes(owner) {
+                    let is_unaffected = ["InnerFocus", "Oblivious", "OwnTempo"]
+                        .iter()
+                        .any(|ability| backend.has_ability(foe, ability));
+
+                    if is_unaffected {
+                        backend.activate_ability(foe);
+                        continue;
+                    }
+
+                    backend.lower_stat_stage_from_foe(foe, Stat::Attack, -1);
+
+                    if backend.has_ability(foe, "Rattled") {
+                        backend.activate_ability(foe);
+                        backend.change_stat_stage(foe, Stat::Speed, 1);
+                    }
+                }
+            }),
+            ..AbilityEffect::default()
+        },
+        "IronFist" => AbilityEffect {
+            on_modify_damage_dealt: Some(|_backend, _owner, _target, mov| {
+                if mov.flags.contains(&MoveFlag::Punch) {
+                    1.2
+                } else {
+                    1.
+                }
+            }),
+            ..AbilityEffect::default()
+        },
+        "KeenEye" => AbilityEffect {
+            // Keen Eye also ignores the evasion boosts of the target, which
+            // is checked by the backend
+            on_check_stat_drop_immunity: Some(|_backend, _owner, stat| stat == Stat::Accuracy),
+            ..AbilityEffect::default()
+        },
+        "Levitate" => AbilityEffect {
+            // Ground-type status moves like Sand Attack still work
+            on_check_move_immunity: Some(|backend, owner, _user, mov| {
+                if mov.move_type != PokemonType::Ground || mov.category == MoveCategory::Status {
+                    return false;
+                }
+
+                backend.activate_ability(owner);
+
+                true
+            }),
+            ..AbilityEffect::default()
+        },
+        "LightningRod" => AbilityEffect {
+            on_check_move_immunity: Some(|backend, owner, _user, mov| {
+                if mov.move_type != PokemonType::Electric {
+                    return false;
+                }
+
+                backend.activate_ability(owner);
+                backend.change_stat_stage(owner, Stat::SpecialAttack, 1);
+
+                true
+            }),
+            ..AbilityEffect::default()
+        },
+        "Limber" => AbilityEffect {
+            on_check_status_immunity: Some(|_backend, _owner, condition| {
+                condition == SimpleStatusCondition::Paralysis
+            }),
+            ..AbilityEffect::default()
+        },
+        "Moxie" => AbilityEffect {
+            on_knock_out: Some(|backend, owner, _target| {
+                backend.activate_ability(owner);
+                backend.change_stat_stage(owner, Stat::Attack, 1);
+            }),
+            ..AbilityEffect::default()
+        },
+        "Overgrow" => AbilityEffect {
+            on_modify_damage_dealt: Some(|backend, owner, _target, mov| {
+                get_pinch_modifier(backend, owner, mov, PokemonType::Grass)
+            }),
+            ..AbilityEffect::default()
+        },
+        "OwnTempo" => AbilityEffect {
+            // Also protects against Intimidate
+            on_check_flag_immunity: Some(|_backend, _owner, flag| match flag {
+                Flag::Confusion { .. } => true,
+                _ => false,
+            }),
+            ..AbilityEffect::default()
+        },
+        "RainDish" => AbilityEffect {
+            on_turn_end: Some(|backend, owner| {
+                let max_hp = backend.get_stat(owner, Stat::HP);
+                let is_raining = backend.get_weather() == Some(Weather::Rain);
+
+                if is_raining && backend.get_pokemon(owner).current_hp < max_hp {
+                    backend.activate_ability(owner);
+                    backend.heal(owner, max_hp / 16);
+                }
+            }),
+            ..AbilityEffect::default()
+        },
+        "Rattled" => AbilityEffect {
+            // Also triggered by Intimidate
+            on_after_hit: Some(|backend, owner, _attacker, mov| {
+                let is_scary = [PokemonType::Bug, PokemonType::Dark, PokemonType::Ghost]
+                    .contains(&mov.move_type);
+
+                if is_scary && mov.category != MoveCategory::Status {
+                    backend.activate_ability(owner);
+                    backend.change_stat_stage(owner, Stat::Speed, 1);
+                }
+            }),
+            ..AbilityEffect::default()
+        },
+        "Reckless" => AbilityEffect {
+            on_modify_damage_dealt: Some(|_backend, _owner, _target, mov| {
+                if mov.recoil.is_some() {
+                    1.2
+                } else {
+                    1.
+                }
+            }),
+            ..AbilityEffect::default()
+        },
+        "SandForce" => AbilityEffect {
+            // Also protects against sandstorm damage
+            on_modify_damage_dealt: Some(|backend, _owner, _target, mov| {
+                let is_boosted_type = [PokemonType::Ground, PokemonType::Rock, PokemonType::Steel]
+                    .contains(&mov.move_type);
+
+                if is_boosted_type && backend.get_weather() == Some(Weather::Sandstorm) {
+                    1.3
+                } else {
+                    1.
+                }
+            }),
+            ..AbilityEffect::default()
+        },
+        "SandVeil" => AbilityEffect {
+            // Also protects against sandstorm damage
+            on_evasion_calculation: Some(|backend, _owner, _user, _mov, accuracy| {
+                if backend.get_weather() == Some(Weather::Sandstorm) {
+                    accuracy * 0.8
+                } else {
+                    accuracy
+                }
+            }),
+            ..AbilityEffect::default()
+        },
+        "SheerForce" => AbilityEffect {
+            // The secondary effects themselves are removed by the backend
+            on_modify_damage_dealt: Some(|_backend, _owner, _target, mov| {
+                if mov.secondary_effect.is_some() && mov.category != MoveCategory::Status {
+                    1.3
+                } else {
+                    1.
+                }
+            }),
+            ..AbilityEffect::default()
+        },
+        "ShedSkin" => AbilityEffect {
+            on_turn_end: Some(|backend, owner| {
+                if backend.has_non_volatile_status_condition(owner)
+                    && backend.check_ability_activation(33)
+                {
+                    backend.activate_ability(owner);
+                    backend.remove_non_volatile_status_condition(owner);
+                }
+            }),
+            ..AbilityEffect::default()
+        },
+        "SolarPower" => AbilityEffect {
+            on_stat_calculation: Some(|backend, _owner, stat, value| {
+                if stat == Stat::SpecialAttack && backend.get_weather() == Some(Weather::Sun) {
+                    value * 3 / 2
+                } else {
+                    value
+                }
+            }),
+            on_turn_end: Some(|backend, owner| {
+                if backend.get_weather() == Some(Weather::Sun) {
+                    let max_hp = backend.get_stat(owner, Stat::HP);
+
+                    backend.activate_ability(owner);
+                    inflict_ability_damage(backend, owner, max_hp / 8);
+                }
+            }),
+            ..AbilityEffect::default()
+        },
+        "Static" => AbilityEffect {
+            on_contact: Some(|backend, owner, attacker| {
+                if backend.check_ability_activation(30) {
+                    inflict_status_condition(backend, owner, attacker, StatusCondition::Paralysis);
+                }
+            }),
+            ..AbilityEffect::default()
+        },
+        "Sturdy" => AbilityEffect {
+            // Sturdy also lets its owner survive any hit at full HP, which is
+            // checked by the backend
+            on_check_move_immunity: Some(|backend, owner, _user, mov| {
+                if !mov.flags.contains(&MoveFlag::OneHitKO) {
+                    return false;
+                }
+
+                backend.activate_ability(owner);
+
+                true
+            }),
+            ..AbilityEffect::default()
+        },
+        "SwiftSwim" => AbilityEffect {
+            on_stat_calculation: Some(|backend, _owner, stat, value| {
+                if stat == Stat::Speed && backend.get_weather() == Some(Weather::Rain) {
+                    value * 2
+                } else {
+                    value
+                }
+            }),
+            ..AbilityEffect::default()
+        },
+        "TangledFeet" => AbilityEffect {
+            on_evasion_calculation: Some(|backend, owner, _user, _mov, accuracy| {
+                if backend.has_flag(owner, "confusion") {
+                    accuracy * 0.5
+                } else {
+                    accuracy
+                }
+            }),
+            ..AbilityEffect::default()
+        },
+        "TintedLens" => AbilityEffect {
+            on_modify_damage_dealt: Some(|backend, _owner, target, mov| {
+                if backend.get_type_effectiveness(mov, target) < 1. {
+                    2.
+                } else {
+                    1.
+                }
+            }),
+            ..AbilityEffect::default()
+        },
+        "Torrent" => AbilityEffect {
+            on_modify_damage_dealt: Some(|backend, owner, _target, mov| {
+                get_pinch_modifier(backend, owner, mov, PokemonType::Water)
+            }),
+            ..AbilityEffect::default()
+        },
+        "Trace" => AbilityEffect {
+            on_switch_in: Some(|backend, owner| {
+                let traced_ability = backend
+                    .get_adjacent_foes(owner)
+                    .into_iter()
+                    .map(|foe| backend.get_ability(foe).to_string())
+                    .find(|ability| !["Trace", "NeutralizingGas"].contains(&ability.as_str()));
+
+                if let Some(ability) = traced_ability {
+                    backend.activate_ability(owner);
+                    backend.add_flag(owner, Flag::Ability(ability.clone()));
+
+                    if let Some(on_switch_in) = get_ability_effect(&ability).on_switch_in {
+                        on_switch_in(backend, owner);
+                    }
+                }
+            }),
+            ..AbilityEffect::default()
+        },
+        "Unburden" => AbilityEffect {
+            on_stat_calculation: Some(|backend, owner, stat, value| {
+                if stat == Stat::Speed && backend.has_flag(owner, "unburden") {
+                    value * 2
+                } else {
+                    value
+                }
+            }),
+            ..AbilityEffect::default()
+        },
+        "WaterAbsorb" => AbilityEffect {
+            on_check_move_immunity: Some(|backend, owner, _user, mov| {
+                if mov.move_type != PokemonType::Water {
+                    return false;
+                }
+
+                let max_hp = backend.get_stat(owner, Stat::HP);
+
+                backend.activate_ability(owner);
+                backend.heal(owner, max_hp / 4);
+
+                true
+            }),
+            ..AbilityEffect::default()
+        },
+        "WeakArmor" => AbilityEffect {
+            on_after_hit: Some(|backend, owner, _attacker, mov| {
+                if mov.category == MoveCategory::Physical {
+                    backend.activate_ability(owner);
+                    backend.change_stat_stage(owner, Stat::Defense, -1);
+                    backend.change_stat_stage(owner, Stat::Speed, 2);
+                }
+            }),
+            ..AbilityEffect::default()
+        },
+        // Abilities whose effects are entirely implemented in the backend.
+        // Oblivious only protects against Intimidate, since infatuation and
+        // Taunt don't exist yet
+        "ArenaTrap" | "BattleArmor" | "Defiant" | "FriendGuard" | "LiquidOoze" | "MagicGuard"
+        | "NoGuard" | "Oblivious" | "Regenerator" | "RockHead" | "RunAway" | "ShellArmor"
+        | "ShieldDust" | "Sniper" | "Steadfast" | "Stench" | "Synchronize" | "Unnerve" => {
+            AbilityEffect::default()
+        },
+        // Abilities that depend on mechanics that don't exist yet (e.g
+        // infatuation or moving last), or that only reveal information to
+        // the player
+        "Analytic" | "CuteCharm" | "Damp" | "Forewarn" | "Harvest" => AbilityEffect::default(),
+        _ => panic!("Unknown ability: {}", ability),
+    }
+}
+
+/// Returns the display name of an ability, e.g "Lightning Rod" for
+/// "LightningRod".
+pub fn get_ability_display_name(ability: &str) -> String {
+    let mut result = String::new();
+
+    for (i, character) in ability.chars().enumerate() {
+        if i > 0 && character.is_uppercase() {
+            result.push(' ');
+        }
+
+        result.push(character);
+    }
+
+    result
+}
+
+/// Used by Blaze, Overgrow and Torrent, which boost moves of a given type
+/// when their owner has 1/3 of its HP or less.
+fn get_pinch_modifier(
+    backend: &BattleBackend,
+    owner: usize,
+    mov: &Move,
+    boosted_type: PokemonType,
+) -> f32 {
+    let pokemon = backend.get_pokemon(owner);
+    let max_hp = backend.get_stat(owner, Stat::HP);
+    let is_damaging = match mov.base_power {
+        MovePower::Constant(_) => true,
+        MovePower::Special => false,
+    };
+
+    if is_damaging && mov.move_type == boosted_type && pokemon.current_hp * 3 <= max_hp {
+        1.5
+    } else {
+        1.
+    }
+}
+
+/// Used by contact abilities that inflict a status condition on the
+/// attacker.
+fn inflict_status_condition(
+    backend: &mut BattleBackend,
+    owner: usize,
+    target: usize,
+    condition: StatusCondition,
+) {
+    if backend.can_inflict_non_volatile_status_condition_to(target, condition.into()) {
+        backend.activate_ability(owner);
+        backend.add_non_volatile_status_condition(target, condition);
+    }
+}
+
+fn inflict_ability_damage(backend: &mut BattleBackend, owner: usize, damage: usize) {
+    backend.inflict_calculated_damage(
+        owner,
+        damage.max(1),
+        TypeEffectiveness::Normal,
+        false,
+        None,
+        false,
+        DamageCause::Ability,
+    );
+}
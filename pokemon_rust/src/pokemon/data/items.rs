@@ -0,0 +1,304 @@
+use crate::{
+    battle::backend::BattleBackend,
+    pokemon::{
+        get_ability_display_name,
+        movement::{Move, MoveCategory, MoveFlag},
+        PokemonType,
+        Stat,
+        StatusCondition,
+    },
+};
+
+use std::fmt::{Debug, Error, Formatter};
+
+#[derive(Clone, Default)]
+pub struct ItemEffect {
+    /// If `true`, the holder can only use the first move it used after
+    /// entering the battlefield (e.g Choice Band).
+    pub locks_move: bool,
+
+    /// Called when backend.get_stat() is called, receiving the value that it
+    /// is about to return.
+    pub on_stat_calculation: Option<fn(
+        backend: &BattleBackend,
+        holder: usize,
+        stat: Stat,
+        value: usize,
+    ) -> usize>,
+
+    /// Returns a multiplier for the damage dealt by the holder's moves.
+    pub on_modify_damage_dealt: Option<fn(
+        backend: &BattleBackend,
+        holder: usize,
+        target: usize,
+        mov: &Move,
+    ) -> f32>,
+
+    /// Returns a multiplier for the damage taken by the holder from moves.
+    pub on_modify_damage_taken: Option<fn(
+        backend: &BattleBackend,
+        holder: usize,
+        user: usize,
+        mov: &Move,
+    ) -> f32>,
+
+    /// Called after a move deals damage to the holder itself, rather than to
+    /// its substitute. Items that only weaken a single hit (e.g Occa Berry)
+    /// are consumed here.
+    pub on_damage_taken: Option<fn(
+        backend: &mut BattleBackend,
+        holder: usize,
+        user: usize,
+        mov: &Move,
+    )>,
+
+    /// Called after the holder gets a status condition, either volatile or
+    /// non-volatile.
+    pub on_status_condition: Option<fn(backend: &mut BattleBackend, holder: usize)>,
+
+    /// Called when the turn ends.
+    pub on_turn_end: Option<fn(backend: &mut BattleBackend, holder: usize)>,
+}
+
+impl Debug for ItemEffect {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        f.write_str("ItemEffect")
+    }
+}
+
+/// Returns the effects of an item. Items that do nothing in battle (e.g
+/// evolution stones) have no effects.
+pub fn get_item_effect(item: &str) -> ItemEffect {
+    match item {
+        "AspearBerry" | "CheriBerry" | "ChestoBerry" | "PechaBerry" | "RawstBerry" => {
+            ItemEffect {
+                on_status_condition: Some(|backend, holder| {
+                    let item = backend.get_held_item(holder).unwrap();
+                    let condition = backend.get_pokemon(holder).status_condition;
+
+                    let is_cured = match condition {
+                        Some(condition) => cures_status_condition(item, condition),
+                        None => false,
+                    };
+
+                    if is_cured && backend.can_consume_berry(holder) {
+                        backend.consume_item(holder);
+                        backend.remove_non_volatile_status_condition(holder);
+                    }
+                }),
+                ..ItemEffect::default()
+            }
+        },
+        "ChoiceBand" | "ChoiceScarf" | "ChoiceSpecs" => ItemEffect {
+            locks_move: true,
+            on_stat_calculation: Some(|backend, holder, stat, value| {
+                let item = backend.get_held_item(holder).unwrap();
+
+                if get_choice_item_stat(item) == stat {
+                    value * 3 / 2
+                } else {
+                    value
+                }
+            }),
+            ..ItemEffect::default()
+        },
+        "ExpertBelt" => ItemEffect {
+            on_modify_damage_dealt: Some(|backend, _holder, target, mov| {
+                if backend.get_type_effectiveness(mov, target) > 1. {
+                    1.2
+                } else {
+                    1.
+                }
+            }),
+            ..ItemEffect::default()
+        },
+        "Leftovers" => ItemEffect {
+            on_turn_end: Some(|backend, holder| {
+                let max_hp = backend.get_stat(holder, Stat::HP);
+
+                if backend.get_pokemon(holder).current_hp < max_hp {
+                    backend.activate_item(holder);
+                    backend.heal(holder, max_hp / 16);
+                }
+            }),
+            ..ItemEffect::default()
+        },
+        "LumBerry" => ItemEffect {
+            on_status_condition: Some(|backend, holder| {
+                let has_status_condition = backend.has_non_volatile_status_condition(holder);
+                let is_confused = backend.has_flag(holder, "confusion");
+
+                if !has_status_condition && !is_confused || !backend.can_consume_berry(holder) {
+                    return;
+                }
+
+                backend.consume_item(holder);
+
+                if has_status_condition {
+                    backend.remove_non_volatile_status_condition(holder);
+                }
+
+                if is_confused {
+                    backend.remove_volatile_status_condition(holder, "confusion");
+                }
+            }),
+            ..ItemEffect::default()
+        },
+        "MuscleBand" => ItemEffect {
+            on_modify_damage_dealt: Some(|_backend, _holder, _target, mov| {
+                if mov.category == MoveCategory::Physical {
+                    1.1
+                } else {
+                    1.
+                }
+            }),
+            ..ItemEffect::default()
+        },
+        "PersimBerry" => ItemEffect {
+            on_status_condition: Some(|backend, holder| {
+                if backend.has_flag(holder, "confusion") && backend.can_consume_berry(holder) {
+                    backend.consume_item(holder);
+                    backend.remove_volatile_status_condition(holder, "confusion");
+                }
+            }),
+            ..ItemEffect::default()
+        },
+        "WiseGlasses" => ItemEffect {
+            on_modify_damage_dealt: Some(|_backend, _holder, _target, mov| {
+                if mov.category == MoveCategory::Special {
+                    1.1
+                } else {
+                    1.
+                }
+            }),
+            ..ItemEffect::default()
+        },
+        _ if get_boosted_type(item).is_some() => ItemEffect {
+            on_modify_damage_dealt: Some(|backend, holder, _target, mov| {
+                let item = backend.get_held_item(holder).unwrap();
+
+                if !mov.flags.contains(&MoveFlag::Typeless)
+                    && get_boosted_type(item) == Some(mov.move_type)
+                {
+                    1.2
+                } else {
+                    1.
+                }
+            }),
+            ..ItemEffect::default()
+        },
+        _ if get_resisted_type(item).is_some() => ItemEffect {
+            on_modify_damage_taken: Some(|backend, holder, _user, mov| {
+                if is_weakened_by_berry(backend, holder, mov) {
+                    0.5
+                } else {
+                    1.
+                }
+            }),
+            on_damage_taken: Some(|backend, holder, _user, mov| {
+                if is_weakened_by_berry(backend, holder, mov) {
+                    backend.consume_item(holder);
+                }
+            }),
+            ..ItemEffect::default()
+        },
+        _ => ItemEffect::default(),
+    }
+}
+
+/// Returns the display name of an item, e.g "Choice Band" for "ChoiceBand".
+pub fn get_item_display_name(item: &str) -> String {
+    // Item ids follow the same naming convention as ability ids
+    get_ability_display_name(item)
+}
+
+/// Returns `true` if a berry cures a given non-volatile status condition.
+fn cures_status_condition(item: &str, condition: StatusCondition) -> bool {
+    match (item, condition) {
+        ("AspearBerry", StatusCondition::Freeze)
+        | ("CheriBerry", StatusCondition::Paralysis)
+        | ("ChestoBerry", StatusCondition::Sleep { .. })
+        | ("PechaBerry", StatusCondition::Poison)
+        | ("PechaBerry", StatusCondition::Toxic { .. })
+        | ("RawstBerry", StatusCondition::Burn) => true,
+        _ => false,
+    }
+}
+
+/// Returns the stat boosted by a Choice item.
+fn get_choice_item_stat(item: &str) -> Stat {
+    match item {
+        "ChoiceBand" => Stat::Attack,
+        "ChoiceScarf" => Stat::Speed,
+        "ChoiceSpecs" => Stat::SpecialAttack,
+        _ => unreachable!(),
+    }
+}
+
+/// Returns the type boosted by a type-enhancing item, e.g Charcoal.
+fn get_boosted_type(item: &str) -> Option<PokemonType> {
+    let boosted_type = match item {
+        "BlackBelt" => PokemonType::Fight,
+        "BlackGlasses" => PokemonType::Dark,
+        "Charcoal" => PokemonType::Fire,
+        "DragonFang" => PokemonType::Dragon,
+        "HardStone" => PokemonType::Rock,
+        "Magnet" => PokemonType::Electric,
+        "MetalCoat" => PokemonType::Steel,
+        "MiracleSeed" => PokemonType::Grass,
+        "MysticWater" => PokemonType::Water,
+        "NeverMeltIce" => PokemonType::Ice,
+        "PoisonBarb" => PokemonType::Poison,
+        "SharpBeak" => PokemonType::Flying,
+        "SilkScarf" => PokemonType::Normal,
+        "SilverPowder" => PokemonType::Bug,
+        "SoftSand" => PokemonType::Ground,
+        "SpellTag" => PokemonType::Ghost,
+        "TwistedSpoon" => PokemonType::Psychic,
+        _ => return None,
+    };
+
+    Some(boosted_type)
+}
+
+/// Returns `true` if the type-resist berry held by a Pokémon weakens a move
+/// that hits it. Chilan Berry weakens every Normal-type move, while the other
+/// berries only weaken super effective moves. Typeless moves (e.g Struggle)
+/// are never weakened.
+fn is_weakened_by_berry(backend: &BattleBackend, holder: usize, mov: &Move) -> bool {
+    let item = backend.get_held_item(holder).unwrap();
+    let resisted_type = get_resisted_type(item).unwrap();
+
+    !mov.flags.contains(&MoveFlag::Typeless)
+        && mov.move_type == resisted_type
+        && (resisted_type == PokemonType::Normal
+            || backend.get_type_effectiveness(mov, holder) > 1.)
+        && backend.can_consume_berry(holder)
+}
+
+/// Returns the type weakened by a type-resist berry, e.g Occa Berry.
+fn get_resisted_type(item: &str) -> Option<PokemonType> {
+    let resisted_type = match item {
+        "BabiriBerry" => PokemonType::Steel,
+        "ChartiBerry" => PokemonType::Rock,
+        "ChilanBerry" => PokemonType::Normal,
+        "ChopleBerry" => PokemonType::Fight,
+        "CobaBerry" => PokemonType::Flying,
+        "ColburBerry" => PokemonType::Dark,
+        "HabanBerry" => PokemonType::Dragon,
+        "KasibBerry" => PokemonType::Ghost,
+        "KebiaBerry" => PokemonType::Poison,
+        "OccaBerry" => PokemonType::Fire,
+        "PasshoBerry" => PokemonType::Water,
+        "PayapaBerry" => PokemonType::Psychic,
+        "RindoBerry" => PokemonType::Grass,
+        "RoseliBerry" => PokemonType::Fairy,
+        "ShucaBerry" => PokemonType::Ground,
+        "TangaBerry" => PokemonType::Bug,
+        "WacanBerry" => PokemonType::Electric,
+        "YacheBerry" => PokemonType::Ice,
+        _ => return None,
+    };
+
+    Some(resisted_type)
+}
@@ -1,3 +1,5 @@
+pub mod abilities;
+pub mod items;
 pub mod movement;
 pub mod pokemon;
 pub mod status_conditions;
@@ -1,22 +1,27 @@
-use crate::pokemon::{
-    movement::{
-        ModifiedAccuracy,
-        ModifiedUsageAttempt,
-        Move,
-        MoveCategory,
-        MoveDex,
-        MoveFlag,
-        MovePower,
-        MultiHit,
-        SecondaryEffect,
-        SimpleEffect,
-        SimpleEffectTarget,
-        TargetType,
+use crate::{
+    battle::backend::{Protection, SideCondition, Weather},
+    pokemon::{
+        movement::{
+            CalledMove,
+            Charge,
+            FixedDamage,
+            ModifiedAccuracy,
+            ModifiedUsageAttempt,
+            Move,
+            MoveCategory,
+            MoveDex,
+            MoveFlag,
+            MovePower,
+            MultiHit,
+            SecondaryEffect,
+            SimpleEffect,
+            SimpleEffectTarget,
+            TargetType,
+        },
+        PokemonType,
+        Stat,
+        StatusCondition,
     },
-    PokemonType,
-    SimpleStatusCondition,
-    Stat,
-    StatusCondition,
 };
 
 use lazy_static::lazy_static;
@@ -39,6 +44,31 @@ lazy_static! {
     static ref MOVEDEX: MoveDex = {
         let mut result = Vec::new();
 
+        result.push(Move {
+            id: "Absorb".to_string(),
+            display_name: "Absorb".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Grass,
+            category: MoveCategory::Special,
+            base_power: MovePower::Constant(20),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 25,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: Some(0.5),
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
         result.push(Move {
             id: "Acid".to_string(),
             display_name: "Acid".to_string(),
@@ -47,6 +77,7 @@ lazy_static! {
             category: MoveCategory::Special,
             base_power: MovePower::Constant(40),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -55,6 +86,10 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::AllAdjacentFoes,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::StatChange {
@@ -73,6 +108,7 @@ lazy_static! {
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
+            fixed_damage: None,
             accuracy: None,
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -81,13 +117,14 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::User,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatChange {
-                    changes: vec![(Stat::Defense, 2)],
-                    target: SimpleEffectTarget::MoveUser,
-                }
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatChange {
+                changes: vec![(Stat::Defense, 2)],
+                target: SimpleEffectTarget::MoveUser,
             }),
+            secondary_effect: None,
             critical_hit: false,
         });
 
@@ -99,6 +136,7 @@ lazy_static! {
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
+            fixed_damage: None,
             accuracy: None,
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -107,13 +145,14 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::User,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatChange {
-                    changes: vec![(Stat::Speed, 2)],
-                    target: SimpleEffectTarget::MoveUser,
-                }
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatChange {
+                changes: vec![(Stat::Speed, 2)],
+                target: SimpleEffectTarget::MoveUser,
             }),
+            secondary_effect: None,
             critical_hit: false,
         });
 
@@ -125,6 +164,7 @@ lazy_static! {
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
+            fixed_damage: None,
             accuracy: None,
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -133,13 +173,14 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::User,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatChange {
-                    changes: vec![(Stat::SpecialDefense, 2)],
-                    target: SimpleEffectTarget::MoveUser,
-                }
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatChange {
+                changes: vec![(Stat::SpecialDefense, 2)],
+                target: SimpleEffectTarget::MoveUser,
             }),
+            secondary_effect: None,
             critical_hit: false,
         });
 
@@ -151,6 +192,7 @@ lazy_static! {
             category: MoveCategory::Special,
             base_power: MovePower::Constant(65),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -159,6 +201,10 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::StatChange {
@@ -177,6 +223,7 @@ lazy_static! {
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
+            fixed_damage: None,
             accuracy: None,
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -185,13 +232,64 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::User,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatChange {
-                    changes: vec![(Stat::Defense, 2)],
-                    target: SimpleEffectTarget::MoveUser,
-                }
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatChange {
+                changes: vec![(Stat::Defense, 2)],
+                target: SimpleEffectTarget::MoveUser,
             }),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "BatonPass".to_string(),
+            display_name: "Baton Pass".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 40,
+            priority: 0,
+            target_type: TargetType::User,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::BatonPass),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Bind".to_string(),
+            display_name: "Bind".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(15),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(85),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Contact],
+            on_usage_attempt: None,
+            pp: 20,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Trap),
+            secondary_effect: None,
             critical_hit: false,
         });
 
@@ -203,14 +301,19 @@ lazy_static! {
             category: MoveCategory::Physical,
             base_power: MovePower::Constant(60),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Contact],
             on_usage_attempt: None,
             pp: 25,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 30,
                 effect: SimpleEffect::Flinch,
@@ -218,6 +321,39 @@ lazy_static! {
             critical_hit: false,
         });
 
+        result.push(Move {
+            id: "Blizzard".to_string(),
+            display_name: "Blizzard".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Ice,
+            category: MoveCategory::Special,
+            base_power: MovePower::Constant(110),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(70),
+            accuracy_modifier: Some(|backend, _user, _target, _mov| {
+                match backend.get_weather() {
+                    Some(Weather::Hail) => ModifiedAccuracy::Hit,
+                    _ => ModifiedAccuracy::OriginalValue,
+                }
+            }),
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 5,
+            priority: 0,
+            target_type: TargetType::AllAdjacentFoes,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 10,
+                effect: SimpleEffect::StatusCondition(StatusCondition::Freeze),
+            }),
+            critical_hit: false,
+        });
+
         result.push(Move {
             id: "BoneClub".to_string(),
             display_name: "Bone Club".to_string(),
@@ -226,6 +362,7 @@ lazy_static! {
             category: MoveCategory::Physical,
             base_power: MovePower::Constant(65),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(85),
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -234,6 +371,10 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::Flinch,
@@ -241,6 +382,31 @@ lazy_static! {
             critical_hit: false,
         });
 
+        result.push(Move {
+            id: "BraveBird".to_string(),
+            display_name: "Brave Bird".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Flying,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(120),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Contact],
+            on_usage_attempt: None,
+            pp: 15,
+            priority: 0,
+            target_type: TargetType::SingleTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: Some(1. / 3.),
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
         result.push(Move {
             id: "Bubble".to_string(),
             display_name: "Bubble".to_string(),
@@ -249,6 +415,7 @@ lazy_static! {
             category: MoveCategory::Special,
             base_power: MovePower::Constant(40),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -257,6 +424,10 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::AllAdjacentFoes,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::StatChange {
@@ -275,6 +446,7 @@ lazy_static! {
             category: MoveCategory::Special,
             base_power: MovePower::Constant(65),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -283,6 +455,10 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::StatChange {
@@ -301,14 +477,15 @@ lazy_static! {
             category: MoveCategory::Physical,
             base_power: MovePower::Constant(18),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(85),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Contact, MoveFlag::Punch],
             on_usage_attempt: None,
             pp: 15,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
-            multi_hit: Some(MultiHit::Custom(|mut rng| {
+            multi_hit: Some(MultiHit::Custom(|rng| {
                 let value = rng.check_custom_multi_hit(1, 6);
 
                 match value {
@@ -319,6 +496,10 @@ lazy_static! {
                     _ => unreachable!(),
                 }
             })),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
@@ -331,24 +512,20 @@ lazy_static! {
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
             flags: HashSet::new(),
-            on_usage_attempt: Some(|backend, _user, target, _mov| {
-                if backend.has_flag(target, "confusion") {
-                    return ModifiedUsageAttempt::Fail;
-                }
-
-                ModifiedUsageAttempt::Continue
-            }),
+            on_usage_attempt: None,
             pp: 10,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::Confusion,
-            }),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Confusion),
+            secondary_effect: None,
             critical_hit: false,
         });
 
@@ -360,6 +537,7 @@ lazy_static! {
             category: MoveCategory::Special,
             base_power: MovePower::Constant(50),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -368,6 +546,10 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::Confusion,
@@ -383,14 +565,19 @@ lazy_static! {
             category: MoveCategory::Physical,
             base_power: MovePower::Constant(10),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Contact],
             on_usage_attempt: None,
             pp: 35,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::StatChange {
@@ -402,203 +589,740 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "CrabHammer".to_string(),
-            display_name: "Crab Hammer".to_string(),
+            id: "Copycat".to_string(),
+            display_name: "Copycat".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Water,
-            category: MoveCategory::Physical,
-            base_power: MovePower::Constant(100),
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
             power_modifier: None,
-            accuracy: Some(90),
+            fixed_damage: None,
+            accuracy: None,
             accuracy_modifier: None,
             flags: HashSet::new(),
             on_usage_attempt: None,
-            pp: 10,
+            pp: 20,
             priority: 0,
-            target_type: TargetType::SingleAdjacentTarget,
+            target_type: TargetType::User,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::CallMove(CalledMove::LastUsedInBattle)),
             secondary_effect: None,
-            critical_hit: true,
+            critical_hit: false,
         });
 
         result.push(Move {
-            id: "Cut".to_string(),
-            display_name: "Cut".to_string(),
+            id: "Counter".to_string(),
+            display_name: "Counter".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Normal,
+            move_type: PokemonType::Fight,
             category: MoveCategory::Physical,
-            base_power: MovePower::Constant(50),
+            base_power: MovePower::Special,
             power_modifier: None,
-            accuracy: Some(95),
+            fixed_damage: Some(FixedDamage::Custom(|backend, user, _target| {
+                backend.get_received_damage(user).map_or(0, |received_damage| {
+                    received_damage.amount * 2
+                })
+            })),
+            accuracy: Some(100),
             accuracy_modifier: None,
-            flags: HashSet::new(),
-            on_usage_attempt: None,
-            pp: 30,
-            priority: 0,
-            target_type: TargetType::SingleAdjacentTarget,
+            flags: flags![MoveFlag::Contact],
+            on_usage_attempt: Some(|backend, user, _target, _mov| {
+                // Counter only works after being hit by a physical move from
+                // a foe in the same turn
+                let was_hit = match backend.get_received_damage(user) {
+                    Some(received_damage) => {
+                        received_damage.category == MoveCategory::Physical
+                            && backend.get_pokemon_team(received_damage.source)
+                                != backend.get_pokemon_team(user)
+                    },
+                    None => false,
+                };
+
+                if was_hit {
+                    ModifiedUsageAttempt::Continue
+                } else {
+                    ModifiedUsageAttempt::Fail
+                }
+            }),
+            pp: 20,
+            priority: -5,
+            target_type: TargetType::SingleAdjacentFoe,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "DizzyPunch".to_string(),
-            display_name: "Dizzy Punch".to_string(),
+            id: "CrabHammer".to_string(),
+            display_name: "Crab Hammer".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Normal,
+            move_type: PokemonType::Water,
             category: MoveCategory::Physical,
-            base_power: MovePower::Constant(70),
+            base_power: MovePower::Constant(100),
             power_modifier: None,
-            accuracy: Some(100),
+            fixed_damage: None,
+            accuracy: Some(90),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Contact],
             on_usage_attempt: None,
             pp: 10,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 20,
-                effect: SimpleEffect::Confusion,
-            }),
-            critical_hit: false,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: true,
         });
 
         result.push(Move {
-            id: "DoubleKick".to_string(),
-            display_name: "Double Kick".to_string(),
+            id: "Curse".to_string(),
+            display_name: "Curse".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Fight,
-            category: MoveCategory::Physical,
-            base_power: MovePower::Constant(30),
+            move_type: PokemonType::Ghost,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
             power_modifier: None,
-            accuracy: Some(100),
+            fixed_damage: None,
+            accuracy: None,
             accuracy_modifier: None,
             flags: HashSet::new(),
             on_usage_attempt: None,
-            pp: 30,
+            pp: 10,
             priority: 0,
-            target_type: TargetType::SingleAdjacentTarget,
-            multi_hit: Some(MultiHit::Uniform {
-                min_hits: 2,
-                max_hits: 2,
-            }),
+            target_type: TargetType::User,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Curse),
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "DoubleSlap".to_string(),
-            display_name: "Double Slap".to_string(),
+            id: "Cut".to_string(),
+            display_name: "Cut".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
             category: MoveCategory::Physical,
-            base_power: MovePower::Constant(15),
+            base_power: MovePower::Constant(50),
             power_modifier: None,
-            accuracy: Some(85),
+            fixed_damage: None,
+            accuracy: Some(95),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Contact],
             on_usage_attempt: None,
-            pp: 10,
+            pp: 30,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
-            multi_hit: Some(MultiHit::Custom(|mut rng| {
-                let value = rng.check_custom_multi_hit(1, 6);
-
-                match value {
-                    1 | 2 => 2,
-                    3 | 4 => 3,
-                    5 => 4,
-                    6 => 5,
-                    _ => unreachable!(),
-                }
-            })),
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "DoubleTeam".to_string(),
-            display_name: "Double Team".to_string(),
+            id: "DestinyBond".to_string(),
+            display_name: "Destiny Bond".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Normal,
+            move_type: PokemonType::Ghost,
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
+            fixed_damage: None,
             accuracy: None,
             accuracy_modifier: None,
             flags: HashSet::new(),
             on_usage_attempt: None,
-            pp: 15,
+            pp: 5,
             priority: 0,
             target_type: TargetType::User,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatChange {
-                    changes: vec![(Stat::Evasion, 1)],
-                    target: SimpleEffectTarget::MoveUser,
-                }
-            }),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::DestinyBond),
+            secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "DrillPeck".to_string(),
-            display_name: "Drill Peck".to_string(),
+            id: "Detect".to_string(),
+            display_name: "Detect".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Flying,
-            category: MoveCategory::Physical,
-            base_power: MovePower::Constant(80),
+            move_type: PokemonType::Fight,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
             power_modifier: None,
-            accuracy: Some(100),
+            fixed_damage: None,
+            accuracy: None,
             accuracy_modifier: None,
             flags: HashSet::new(),
             on_usage_attempt: None,
-            pp: 20,
-            priority: 0,
-            target_type: TargetType::SingleTarget,
+            pp: 5,
+            priority: 4,
+            target_type: TargetType::User,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Protection(Protection::Full)),
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "EggBomb".to_string(),
-            display_name: "Egg Bomb".to_string(),
+            id: "Dig".to_string(),
+            display_name: "Dig".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Normal,
+            move_type: PokemonType::Ground,
             category: MoveCategory::Physical,
-            base_power: MovePower::Constant(100),
+            base_power: MovePower::Constant(80),
             power_modifier: None,
-            accuracy: Some(75),
+            fixed_damage: None,
+            accuracy: Some(100),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Contact],
             on_usage_attempt: None,
             pp: 10,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: Some(Charge {
+                semi_invulnerable: true,
+                on_charge: None,
+            }),
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Ember".to_string(),
-            display_name: "Ember".to_string(),
+            id: "Disable".to_string(),
+            display_name: "Disable".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Fire,
-            category: MoveCategory::Special,
-            base_power: MovePower::Constant(40),
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
             flags: HashSet::new(),
             on_usage_attempt: None,
-            pp: 25,
+            pp: 20,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Disable),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "DizzyPunch".to_string(),
+            display_name: "Dizzy Punch".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(70),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Contact, MoveFlag::Punch],
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 20,
+                effect: SimpleEffect::Confusion,
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "DoubleEdge".to_string(),
+            display_name: "Double-Edge".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(120),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Contact],
+            on_usage_attempt: None,
+            pp: 15,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: Some(1. / 3.),
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "DoubleKick".to_string(),
+            display_name: "Double Kick".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Fight,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(30),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Contact],
+            on_usage_attempt: None,
+            pp: 30,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: Some(MultiHit::Uniform {
+                min_hits: 2,
+                max_hits: 2,
+            }),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "DoubleSlap".to_string(),
+            display_name: "Double Slap".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(15),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(85),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Contact],
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: Some(MultiHit::Custom(|rng| {
+                let value = rng.check_custom_multi_hit(1, 6);
+
+                match value {
+                    1 | 2 => 2,
+                    3 | 4 => 3,
+                    5 => 4,
+                    6 => 5,
+                    _ => unreachable!(),
+                }
+            })),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "DoubleTeam".to_string(),
+            display_name: "Double Team".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 15,
+            priority: 0,
+            target_type: TargetType::User,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatChange {
+                changes: vec![(Stat::Evasion, 1)],
+                target: SimpleEffectTarget::MoveUser,
+            }),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "DrainPunch".to_string(),
+            display_name: "Drain Punch".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Fight,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(75),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Contact, MoveFlag::Punch],
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: Some(0.5),
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "DrillPeck".to_string(),
+            display_name: "Drill Peck".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Flying,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(80),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Contact],
+            on_usage_attempt: None,
+            pp: 20,
+            priority: 0,
+            target_type: TargetType::SingleTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "EggBomb".to_string(),
+            display_name: "Egg Bomb".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(100),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(75),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Ember".to_string(),
+            display_name: "Ember".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Fire,
+            category: MoveCategory::Special,
+            base_power: MovePower::Constant(40),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 25,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 10,
+                effect: SimpleEffect::StatusCondition(StatusCondition::Burn),
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Encore".to_string(),
+            display_name: "Encore".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 5,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Encore),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Endure".to_string(),
+            display_name: "Endure".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 4,
+            target_type: TargetType::User,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Protection(Protection::Endure)),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "FalseSwipe".to_string(),
+            display_name: "False Swipe".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(40),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Contact, MoveFlag::NonLethal],
+            on_usage_attempt: None,
+            pp: 40,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "FireBlast".to_string(),
+            display_name: "Fire Blast".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Fire,
+            category: MoveCategory::Special,
+            base_power: MovePower::Constant(110),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(85),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 5,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 10,
+                effect: SimpleEffect::StatusCondition(StatusCondition::Burn),
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "FirePunch".to_string(),
+            display_name: "Fire Punch".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Fire,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(75),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Contact, MoveFlag::Punch],
+            on_usage_attempt: None,
+            pp: 15,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 10,
+                effect: SimpleEffect::StatusCondition(StatusCondition::Burn),
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "FireSpin".to_string(),
+            display_name: "Fire Spin".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Fire,
+            category: MoveCategory::Special,
+            base_power: MovePower::Constant(35),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(85),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 15,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Trap),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Fissure".to_string(),
+            display_name: "Fissure".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Ground,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(30),
+            accuracy_modifier: Some(|backend, user, target, _mov| {
+                let user_level = backend.get_pokemon(user).level;
+                let target_level = backend.get_pokemon(target).level;
+
+                if target_level > user_level {
+                    return ModifiedAccuracy::Miss;
+                }
+
+                return ModifiedAccuracy::NewValue(user_level - target_level + 30);
+            }),
+            flags: flags![MoveFlag::OneHitKO],
+            on_usage_attempt: None,
+            pp: 5,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Flamethrower".to_string(),
+            display_name: "Flamethrower".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Fire,
+            category: MoveCategory::Special,
+            base_power: MovePower::Constant(90),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 15,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 10,
+                effect: SimpleEffect::StatusCondition(StatusCondition::Burn),
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "FlareBlitz".to_string(),
+            display_name: "Flare Blitz".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Fire,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(120),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Contact],
+            on_usage_attempt: None,
+            pp: 15,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: Some(1. / 3.),
+            primary_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Burn),
@@ -607,59 +1331,345 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "FireBlast".to_string(),
-            display_name: "Fire Blast".to_string(),
+            id: "Flash".to_string(),
+            display_name: "Flash".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 20,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatChange {
+                changes: vec![(Stat::Accuracy, -1)],
+                target: SimpleEffectTarget::MoveTarget,
+            }),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "FuryAttack".to_string(),
+            display_name: "Fury Attack".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(15),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(85),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Contact],
+            on_usage_attempt: None,
+            pp: 20,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: Some(MultiHit::Custom(|rng| {
+                let value = rng.check_custom_multi_hit(1, 6);
+
+                match value {
+                    1 | 2 => 2,
+                    3 | 4 => 3,
+                    5 => 4,
+                    6 => 5,
+                    _ => unreachable!(),
+                }
+            })),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "GigaDrain".to_string(),
+            display_name: "Giga Drain".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Grass,
+            category: MoveCategory::Special,
+            base_power: MovePower::Constant(75),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: Some(0.5),
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Glare".to_string(),
+            display_name: "Glare".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 30,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatusCondition(StatusCondition::Paralysis)),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Growl".to_string(),
+            display_name: "Growl".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Sound],
+            on_usage_attempt: None,
+            pp: 40,
+            priority: 0,
+            target_type: TargetType::AllAdjacentFoes,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatChange {
+                changes: vec![(Stat::Attack, -1)],
+                target: SimpleEffectTarget::MoveTarget,
+            }),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Guillotine".to_string(),
+            display_name: "Guillotine".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(30),
+            accuracy_modifier: Some(|backend, user, target, _mov| {
+                let user_level = backend.get_pokemon(user).level;
+                let target_level = backend.get_pokemon(target).level;
+
+                if target_level > user_level {
+                    return ModifiedAccuracy::Miss;
+                }
+
+                return ModifiedAccuracy::NewValue(user_level - target_level + 30);
+            }),
+            flags: flags![MoveFlag::OneHitKO, MoveFlag::Contact],
+            on_usage_attempt: None,
+            pp: 5,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Gust".to_string(),
+            display_name: "Gust".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Flying,
+            category: MoveCategory::Special,
+            base_power: MovePower::Constant(40),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 35,
+            priority: 0,
+            target_type: TargetType::SingleTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Hail".to_string(),
+            display_name: "Hail".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Ice,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::User,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Weather(Weather::Hail)),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Harden".to_string(),
+            display_name: "Harden".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 30,
+            priority: 0,
+            target_type: TargetType::User,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatChange {
+                changes: vec![(Stat::Defense, 1)],
+                target: SimpleEffectTarget::MoveUser,
+            }),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Haze".to_string(),
+            display_name: "Haze".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Ice,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 30,
+            priority: 0,
+            target_type: TargetType::Everyone,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Haze),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Headbutt".to_string(),
+            display_name: "Headbutt".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Fire,
-            category: MoveCategory::Special,
-            base_power: MovePower::Constant(110),
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(70),
             power_modifier: None,
-            accuracy: Some(85),
+            fixed_damage: None,
+            accuracy: Some(100),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Contact],
             on_usage_attempt: None,
-            pp: 5,
+            pp: 15,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: Some(SecondaryEffect {
-                chance: 10,
-                effect: SimpleEffect::StatusCondition(StatusCondition::Burn),
+                chance: 30,
+                effect: SimpleEffect::Flinch,
             }),
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "FirePunch".to_string(),
-            display_name: "Fire Punch".to_string(),
+            id: "HornAttack".to_string(),
+            display_name: "Horn Attack".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Fire,
+            move_type: PokemonType::Normal,
             category: MoveCategory::Physical,
-            base_power: MovePower::Constant(75),
+            base_power: MovePower::Constant(65),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Contact],
             on_usage_attempt: None,
-            pp: 15,
+            pp: 25,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 10,
-                effect: SimpleEffect::StatusCondition(StatusCondition::Burn),
-            }),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Fissure".to_string(),
-            display_name: "Fissure".to_string(),
+            id: "HornDrill".to_string(),
+            display_name: "Horn Drill".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Ground,
+            move_type: PokemonType::Normal,
             category: MoveCategory::Physical,
             base_power: MovePower::Special,
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(30),
             accuracy_modifier: Some(|backend, user, target, _mov| {
                 let user_level = backend.get_pokemon(user).level;
@@ -671,647 +1681,822 @@ lazy_static! {
 
                 return ModifiedAccuracy::NewValue(user_level - target_level + 30);
             }),
-            flags: flags![MoveFlag::OneHitKO],
+            flags: flags![MoveFlag::OneHitKO, MoveFlag::Contact],
             on_usage_attempt: None,
             pp: 5,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Flamethrower".to_string(),
-            display_name: "Flamethrower".to_string(),
+            id: "Hurricane".to_string(),
+            display_name: "Hurricane".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Fire,
+            move_type: PokemonType::Flying,
             category: MoveCategory::Special,
-            base_power: MovePower::Constant(90),
+            base_power: MovePower::Constant(110),
             power_modifier: None,
-            accuracy: Some(100),
-            accuracy_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(70),
+            accuracy_modifier: Some(|backend, _user, _target, _mov| {
+                match backend.get_weather() {
+                    Some(Weather::Rain) => ModifiedAccuracy::Hit,
+                    Some(Weather::Sun) => ModifiedAccuracy::NewValue(50),
+                    _ => ModifiedAccuracy::OriginalValue,
+                }
+            }),
             flags: HashSet::new(),
             on_usage_attempt: None,
-            pp: 15,
+            pp: 10,
             priority: 0,
-            target_type: TargetType::SingleAdjacentTarget,
+            target_type: TargetType::SingleTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: Some(SecondaryEffect {
-                chance: 10,
-                effect: SimpleEffect::StatusCondition(StatusCondition::Burn),
+                chance: 30,
+                effect: SimpleEffect::Confusion,
             }),
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Flash".to_string(),
-            display_name: "Flash".to_string(),
+            id: "HydroPump".to_string(),
+            display_name: "Hydro Pump".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Normal,
-            category: MoveCategory::Status,
-            base_power: MovePower::Special,
+            move_type: PokemonType::Water,
+            category: MoveCategory::Special,
+            base_power: MovePower::Constant(110),
             power_modifier: None,
-            accuracy: Some(100),
+            fixed_damage: None,
+            accuracy: Some(80),
             accuracy_modifier: None,
             flags: HashSet::new(),
             on_usage_attempt: None,
-            pp: 20,
+            pp: 5,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatChange {
-                    changes: vec![(Stat::Accuracy, -1)],
-                    target: SimpleEffectTarget::MoveTarget,
-                }
-            }),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "FuryAttack".to_string(),
-            display_name: "Fury Attack".to_string(),
+            id: "HyperBeam".to_string(),
+            display_name: "Hyper Beam".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Special,
+            base_power: MovePower::Constant(150),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(90),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Recharge],
+            on_usage_attempt: None,
+            pp: 5,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "HyperFang".to_string(),
+            display_name: "Hyper Fang".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
             category: MoveCategory::Physical,
-            base_power: MovePower::Constant(15),
+            base_power: MovePower::Constant(80),
             power_modifier: None,
-            accuracy: Some(85),
+            fixed_damage: None,
+            accuracy: Some(90),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Contact],
+            on_usage_attempt: None,
+            pp: 15,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 10,
+                effect: SimpleEffect::Flinch,
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Hypnosis".to_string(),
+            display_name: "Hypnosis".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Psychic,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(60),
             accuracy_modifier: None,
             flags: HashSet::new(),
             on_usage_attempt: None,
             pp: 20,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
-            multi_hit: Some(MultiHit::Custom(|mut rng| {
-                let value = rng.check_custom_multi_hit(1, 6);
-
-                match value {
-                    1 | 2 => 2,
-                    3 | 4 => 3,
-                    5 => 4,
-                    6 => 5,
-                    _ => unreachable!(),
-                }
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatusCondition(StatusCondition::Sleep {
+                // TODO: randomize duration
+                remaining_turns: 1,
             })),
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Glare".to_string(),
-            display_name: "Glare".to_string(),
+            id: "IceBeam".to_string(),
+            display_name: "Ice Beam".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Normal,
-            category: MoveCategory::Status,
-            base_power: MovePower::Special,
+            move_type: PokemonType::Ice,
+            category: MoveCategory::Special,
+            base_power: MovePower::Constant(90),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
             flags: HashSet::new(),
-            on_usage_attempt: Some(|backend, _user, target, _mov| {
-                if !backend.can_inflict_non_volatile_status_condition_to(target, SimpleStatusCondition::Paralysis) {
-                    return ModifiedUsageAttempt::Fail;
-                }
-
-                ModifiedUsageAttempt::Continue
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 10,
+                effect: SimpleEffect::StatusCondition(StatusCondition::Freeze),
             }),
-            pp: 30,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "IcePunch".to_string(),
+            display_name: "Ice Punch".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Ice,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(75),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Contact, MoveFlag::Punch],
+            on_usage_attempt: None,
+            pp: 15,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatusCondition(StatusCondition::Paralysis),
+                chance: 10,
+                effect: SimpleEffect::StatusCondition(StatusCondition::Freeze),
             }),
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Growl".to_string(),
-            display_name: "Growl".to_string(),
+            id: "KarateChop".to_string(),
+            display_name: "Karate Chop".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Normal,
+            move_type: PokemonType::Fight,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(50),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Contact],
+            on_usage_attempt: None,
+            pp: 25,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: true,
+        });
+
+        result.push(Move {
+            id: "Kinesis".to_string(),
+            display_name: "Kinesis".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Psychic,
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
-            accuracy: Some(100),
+            fixed_damage: None,
+            accuracy: Some(80),
             accuracy_modifier: None,
             flags: HashSet::new(),
             on_usage_attempt: None,
-            pp: 40,
+            pp: 15,
             priority: 0,
-            target_type: TargetType::AllAdjacentFoes,
+            target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatChange {
-                    changes: vec![(Stat::Attack, -1)],
-                    target: SimpleEffectTarget::MoveTarget,
-                }
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatChange {
+                changes: vec![(Stat::Accuracy, -1)],
+                target: SimpleEffectTarget::MoveTarget,
             }),
+            secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Guillotine".to_string(),
-            display_name: "Guillotine".to_string(),
+            id: "LastResort".to_string(),
+            display_name: "Last Resort".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
             category: MoveCategory::Physical,
-            base_power: MovePower::Special,
+            base_power: MovePower::Constant(140),
             power_modifier: None,
-            accuracy: Some(30),
-            accuracy_modifier: Some(|backend, user, target, _mov| {
-                let user_level = backend.get_pokemon(user).level;
-                let target_level = backend.get_pokemon(target).level;
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Contact],
+            on_usage_attempt: Some(|backend, user, _target, mov| {
+                // Last Resort only works once the user has used every other
+                // move it knows since entering the battlefield
+                let used_moves = backend.get_used_moves(user);
+                let other_moves: Vec<_> = backend
+                    .get_pokemon(user)
+                    .moves
+                    .iter()
+                    .flatten()
+                    .filter(|movement| **movement != mov.id)
+                    .collect();
 
-                if target_level > user_level {
-                    return ModifiedAccuracy::Miss;
-                }
+                let has_used_other_moves = !other_moves.is_empty()
+                    && other_moves.iter().all(|movement| used_moves.contains(movement));
 
-                return ModifiedAccuracy::NewValue(user_level - target_level + 30);
+                if has_used_other_moves {
+                    ModifiedUsageAttempt::Continue
+                } else {
+                    ModifiedUsageAttempt::Fail
+                }
             }),
-            flags: flags![MoveFlag::OneHitKO],
-            on_usage_attempt: None,
             pp: 5,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Gust".to_string(),
-            display_name: "Gust".to_string(),
+            id: "LeechLife".to_string(),
+            display_name: "Leech Life".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Flying,
-            category: MoveCategory::Special,
-            base_power: MovePower::Constant(40),
+            move_type: PokemonType::Bug,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(80),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Contact],
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: Some(0.5),
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "LeechSeed".to_string(),
+            display_name: "Leech Seed".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Grass,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
             power_modifier: None,
-            accuracy: Some(100),
+            fixed_damage: None,
+            accuracy: Some(90),
             accuracy_modifier: None,
             flags: HashSet::new(),
             on_usage_attempt: None,
-            pp: 35,
+            pp: 10,
             priority: 0,
-            target_type: TargetType::SingleTarget,
+            target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::LeechSeed),
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Harden".to_string(),
-            display_name: "Harden".to_string(),
+            id: "Leer".to_string(),
+            display_name: "Leer".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
-            accuracy: None,
+            fixed_damage: None,
+            accuracy: Some(100),
             accuracy_modifier: None,
             flags: HashSet::new(),
             on_usage_attempt: None,
             pp: 30,
             priority: 0,
-            target_type: TargetType::User,
+            target_type: TargetType::AllAdjacentFoes,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatChange {
-                    changes: vec![(Stat::Defense, 1)],
-                    target: SimpleEffectTarget::MoveUser,
-                }
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatChange {
+                changes: vec![(Stat::Defense, -1)],
+                target: SimpleEffectTarget::MoveTarget,
             }),
+            secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Headbutt".to_string(),
-            display_name: "Headbutt".to_string(),
+            id: "Lick".to_string(),
+            display_name: "Lick".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Normal,
+            move_type: PokemonType::Ghost,
             category: MoveCategory::Physical,
-            base_power: MovePower::Constant(70),
+            base_power: MovePower::Constant(30),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Contact],
             on_usage_attempt: None,
-            pp: 15,
+            pp: 30,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 30,
-                effect: SimpleEffect::Flinch,
+                effect: SimpleEffect::StatusCondition(StatusCondition::Paralysis),
             }),
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "HornAttack".to_string(),
-            display_name: "Horn Attack".to_string(),
+            id: "LifeDew".to_string(),
+            display_name: "Life Dew".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Normal,
-            category: MoveCategory::Physical,
-            base_power: MovePower::Constant(65),
+            move_type: PokemonType::Water,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
             power_modifier: None,
-            accuracy: Some(100),
+            fixed_damage: None,
+            accuracy: None,
             accuracy_modifier: None,
             flags: HashSet::new(),
             on_usage_attempt: None,
-            pp: 25,
+            pp: 10,
             priority: 0,
-            target_type: TargetType::SingleAdjacentTarget,
+            target_type: TargetType::AllyTeam,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Heal(25)),
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "HornDrill".to_string(),
-            display_name: "Horn Drill".to_string(),
+            id: "LightScreen".to_string(),
+            display_name: "Light Screen".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Normal,
-            category: MoveCategory::Physical,
+            move_type: PokemonType::Psychic,
+            category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
-            accuracy: Some(30),
-            accuracy_modifier: Some(|backend, user, target, _mov| {
-                let user_level = backend.get_pokemon(user).level;
-                let target_level = backend.get_pokemon(target).level;
-
-                if target_level > user_level {
-                    return ModifiedAccuracy::Miss;
-                }
-
-                return ModifiedAccuracy::NewValue(user_level - target_level + 30);
-            }),
-            flags: flags![MoveFlag::OneHitKO],
+            fixed_damage: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
             on_usage_attempt: None,
-            pp: 5,
+            pp: 30,
             priority: 0,
-            target_type: TargetType::SingleAdjacentTarget,
+            target_type: TargetType::User,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::SideCondition(SideCondition::LightScreen)),
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "HydroPump".to_string(),
-            display_name: "Hydro Pump".to_string(),
+            id: "LovelyKiss".to_string(),
+            display_name: "Lovely Kiss".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Water,
-            category: MoveCategory::Special,
-            base_power: MovePower::Constant(110),
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
             power_modifier: None,
-            accuracy: Some(80),
+            fixed_damage: None,
+            accuracy: Some(75),
             accuracy_modifier: None,
             flags: HashSet::new(),
             on_usage_attempt: None,
-            pp: 5,
+            pp: 10,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatusCondition(StatusCondition::Sleep {
+                // TODO: randomize duration
+                remaining_turns: 1,
+            })),
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "HyperFang".to_string(),
-            display_name: "Hyper Fang".to_string(),
+            id: "MachPunch".to_string(),
+            display_name: "Mach Punch".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Normal,
+            move_type: PokemonType::Fight,
             category: MoveCategory::Physical,
-            base_power: MovePower::Constant(80),
+            base_power: MovePower::Constant(40),
             power_modifier: None,
-            accuracy: Some(90),
+            fixed_damage: None,
+            accuracy: Some(100),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Contact, MoveFlag::Punch],
             on_usage_attempt: None,
-            pp: 15,
-            priority: 0,
+            pp: 30,
+            priority: 1,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 10,
-                effect: SimpleEffect::Flinch,
-            }),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Hypnosis".to_string(),
-            display_name: "Hypnosis".to_string(),
+            id: "Meditate".to_string(),
+            display_name: "Meditate".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Psychic,
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
-            accuracy: Some(60),
+            fixed_damage: None,
+            accuracy: None,
             accuracy_modifier: None,
             flags: HashSet::new(),
-            on_usage_attempt: Some(|backend, _user, target, _mov| {
-                if !backend.can_inflict_non_volatile_status_condition_to(target, SimpleStatusCondition::Sleep) {
-                    return ModifiedUsageAttempt::Fail;
-                }
-
-                ModifiedUsageAttempt::Continue
-            }),
-            pp: 20,
+            on_usage_attempt: None,
+            pp: 40,
             priority: 0,
-            target_type: TargetType::SingleAdjacentTarget,
+            target_type: TargetType::User,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatusCondition(StatusCondition::Sleep {
-                    // TODO: randomize duration
-                    remaining_turns: 1,
-                }),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatChange {
+                changes: vec![(Stat::Attack, 1)],
+                target: SimpleEffectTarget::MoveUser,
             }),
+            secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "IceBeam".to_string(),
-            display_name: "Ice Beam".to_string(),
+            id: "MegaDrain".to_string(),
+            display_name: "Mega Drain".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Ice,
+            move_type: PokemonType::Grass,
             category: MoveCategory::Special,
-            base_power: MovePower::Constant(90),
+            base_power: MovePower::Constant(40),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
             flags: HashSet::new(),
             on_usage_attempt: None,
-            pp: 10,
+            pp: 15,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 10,
-                effect: SimpleEffect::StatusCondition(StatusCondition::Freeze),
-            }),
+            charge: None,
+            drain: Some(0.5),
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "IcePunch".to_string(),
-            display_name: "Ice Punch".to_string(),
+            id: "MegaKick".to_string(),
+            display_name: "Mega Kick".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Ice,
+            move_type: PokemonType::Normal,
             category: MoveCategory::Physical,
-            base_power: MovePower::Constant(75),
+            base_power: MovePower::Constant(120),
             power_modifier: None,
-            accuracy: Some(100),
+            fixed_damage: None,
+            accuracy: Some(75),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Contact],
             on_usage_attempt: None,
-            pp: 15,
+            pp: 5,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 10,
-                effect: SimpleEffect::StatusCondition(StatusCondition::Freeze),
-            }),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "KarateChop".to_string(),
-            display_name: "Karate Chop".to_string(),
+            id: "MegaPunch".to_string(),
+            display_name: "Mega Punch".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Fight,
+            move_type: PokemonType::Normal,
             category: MoveCategory::Physical,
-            base_power: MovePower::Constant(50),
+            base_power: MovePower::Constant(80),
             power_modifier: None,
-            accuracy: Some(100),
+            fixed_damage: None,
+            accuracy: Some(85),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Contact, MoveFlag::Punch],
             on_usage_attempt: None,
-            pp: 25,
+            pp: 20,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: None,
-            critical_hit: true,
+            critical_hit: false,
         });
 
         result.push(Move {
-            id: "Kinesis".to_string(),
-            display_name: "Kinesis".to_string(),
+            id: "Metronome".to_string(),
+            display_name: "Metronome".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Psychic,
+            move_type: PokemonType::Normal,
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
-            accuracy: Some(80),
+            fixed_damage: None,
+            accuracy: None,
             accuracy_modifier: None,
             flags: HashSet::new(),
             on_usage_attempt: None,
-            pp: 15,
+            pp: 10,
             priority: 0,
-            target_type: TargetType::SingleAdjacentTarget,
+            target_type: TargetType::User,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatChange {
-                    changes: vec![(Stat::Accuracy, -1)],
-                    target: SimpleEffectTarget::MoveTarget,
-                }
-            }),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::CallMove(CalledMove::Random)),
+            secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Leer".to_string(),
-            display_name: "Leer".to_string(),
+            id: "MirrorMove".to_string(),
+            display_name: "Mirror Move".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Normal,
+            move_type: PokemonType::Flying,
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
-            accuracy: Some(100),
+            fixed_damage: None,
+            accuracy: None,
             accuracy_modifier: None,
             flags: HashSet::new(),
             on_usage_attempt: None,
-            pp: 30,
+            pp: 20,
             priority: 0,
-            target_type: TargetType::AllAdjacentFoes,
+            target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatChange {
-                    changes: vec![(Stat::Defense, -1)],
-                    target: SimpleEffectTarget::MoveTarget,
-                }
-            }),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::CallMove(CalledMove::LastUsedByTarget)),
+            secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Lick".to_string(),
-            display_name: "Lick".to_string(),
+            id: "Mist".to_string(),
+            display_name: "Mist".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Ghost,
-            category: MoveCategory::Physical,
-            base_power: MovePower::Constant(30),
+            move_type: PokemonType::Ice,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
             power_modifier: None,
-            accuracy: Some(100),
+            fixed_damage: None,
+            accuracy: None,
             accuracy_modifier: None,
             flags: HashSet::new(),
             on_usage_attempt: None,
             pp: 30,
             priority: 0,
-            target_type: TargetType::SingleAdjacentTarget,
+            target_type: TargetType::User,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 30,
-                effect: SimpleEffect::StatusCondition(StatusCondition::Paralysis),
-            }),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::SideCondition(SideCondition::Mist)),
+            secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "LovelyKiss".to_string(),
-            display_name: "Lovely Kiss".to_string(),
+            id: "Moonlight".to_string(),
+            display_name: "Moonlight".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Normal,
+            move_type: PokemonType::Fairy,
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
-            accuracy: Some(75),
-            accuracy_modifier: None,
-            flags: HashSet::new(),
-            on_usage_attempt: Some(|backend, _user, target, _mov| {
-                if !backend.can_inflict_non_volatile_status_condition_to(target, SimpleStatusCondition::Sleep) {
-                    return ModifiedUsageAttempt::Fail;
-                }
-
-                ModifiedUsageAttempt::Continue
-            }),
-            pp: 10,
-            priority: 0,
-            target_type: TargetType::SingleAdjacentTarget,
-            multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatusCondition(StatusCondition::Sleep {
-                    // TODO: randomize duration
-                    remaining_turns: 1,
-                }),
-            }),
-            critical_hit: false,
-        });
-
-        result.push(Move {
-            id: "MachPunch".to_string(),
-            display_name: "Mach Punch".to_string(),
-            description: "".to_string(), // TODO
-            move_type: PokemonType::Fight,
-            category: MoveCategory::Physical,
-            base_power: MovePower::Constant(40),
-            power_modifier: None,
-            accuracy: Some(100),
+            fixed_damage: None,
+            accuracy: None,
             accuracy_modifier: None,
             flags: HashSet::new(),
             on_usage_attempt: None,
-            pp: 30,
-            priority: 1,
-            target_type: TargetType::SingleAdjacentTarget,
+            pp: 5,
+            priority: 0,
+            target_type: TargetType::User,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::WeatherDependentHeal),
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Meditate".to_string(),
-            display_name: "Meditate".to_string(),
-            description: "".to_string(), // TODO
-            move_type: PokemonType::Psychic,
-            category: MoveCategory::Status,
+            id: "NightShade".to_string(),
+            display_name: "Night Shade".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Ghost,
+            category: MoveCategory::Special,
             base_power: MovePower::Special,
             power_modifier: None,
-            accuracy: None,
+            fixed_damage: Some(FixedDamage::UserLevel),
+            accuracy: Some(100),
             accuracy_modifier: None,
             flags: HashSet::new(),
             on_usage_attempt: None,
-            pp: 40,
+            pp: 15,
             priority: 0,
-            target_type: TargetType::User,
+            target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatChange {
-                    changes: vec![(Stat::Attack, 1)],
-                    target: SimpleEffectTarget::MoveUser,
-                }
-            }),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "MegaKick".to_string(),
-            display_name: "Mega Kick".to_string(),
+            id: "Peck".to_string(),
+            display_name: "Peck".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Normal,
+            move_type: PokemonType::Flying,
             category: MoveCategory::Physical,
-            base_power: MovePower::Constant(120),
+            base_power: MovePower::Constant(35),
             power_modifier: None,
-            accuracy: Some(75),
+            fixed_damage: None,
+            accuracy: Some(100),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Contact],
             on_usage_attempt: None,
-            pp: 5,
+            pp: 35,
             priority: 0,
-            target_type: TargetType::SingleAdjacentTarget,
+            target_type: TargetType::SingleTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "MegaPunch".to_string(),
-            display_name: "Mega Punch".to_string(),
+            id: "PerishSong".to_string(),
+            display_name: "Perish Song".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
-            category: MoveCategory::Physical,
-            base_power: MovePower::Constant(80),
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
             power_modifier: None,
-            accuracy: Some(85),
+            fixed_damage: None,
+            accuracy: None,
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Sound],
             on_usage_attempt: None,
-            pp: 20,
+            pp: 5,
             priority: 0,
-            target_type: TargetType::SingleAdjacentTarget,
+            target_type: TargetType::Everyone,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::PerishSong),
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Peck".to_string(),
-            display_name: "Peck".to_string(),
+            id: "PetalDance".to_string(),
+            display_name: "Petal Dance".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Flying,
-            category: MoveCategory::Physical,
-            base_power: MovePower::Constant(35),
+            move_type: PokemonType::Grass,
+            category: MoveCategory::Special,
+            base_power: MovePower::Constant(120),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Contact, MoveFlag::Rampage],
             on_usage_attempt: None,
-            pp: 35,
+            pp: 10,
             priority: 0,
-            target_type: TargetType::SingleTarget,
+            target_type: TargetType::SingleAdjacentFoe,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
@@ -1324,24 +2509,20 @@ lazy_static! {
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(90),
             accuracy_modifier: None,
             flags: HashSet::new(),
-            on_usage_attempt: Some(|backend, _user, target, _mov| {
-                if !backend.can_inflict_non_volatile_status_condition_to(target, SimpleStatusCondition::Poison) {
-                    return ModifiedUsageAttempt::Fail;
-                }
-
-                ModifiedUsageAttempt::Continue
-            }),
+            on_usage_attempt: None,
             pp: 40,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatusCondition(StatusCondition::Poison),
-            }),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatusCondition(StatusCondition::Poison)),
+            secondary_effect: None,
             critical_hit: false,
         });
 
@@ -1353,24 +2534,20 @@ lazy_static! {
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(75),
             accuracy_modifier: None,
             flags: HashSet::new(),
-            on_usage_attempt: Some(|backend, _user, target, _mov| {
-                if !backend.can_inflict_non_volatile_status_condition_to(target, SimpleStatusCondition::Poison) {
-                    return ModifiedUsageAttempt::Fail;
-                }
-
-                ModifiedUsageAttempt::Continue
-            }),
+            on_usage_attempt: None,
             pp: 35,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatusCondition(StatusCondition::Poison),
-            }),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatusCondition(StatusCondition::Poison)),
+            secondary_effect: None,
             critical_hit: false,
         });
 
@@ -1382,6 +2559,7 @@ lazy_static! {
             category: MoveCategory::Physical,
             base_power: MovePower::Constant(15),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -1390,6 +2568,10 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 30,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Poison),
@@ -1405,14 +2587,44 @@ lazy_static! {
             category: MoveCategory::Physical,
             base_power: MovePower::Constant(40),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Contact],
             on_usage_attempt: None,
             pp: 35,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Protect".to_string(),
+            display_name: "Protect".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 4,
+            target_type: TargetType::User,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Protection(Protection::Full)),
             secondary_effect: None,
             critical_hit: false,
         });
@@ -1425,6 +2637,7 @@ lazy_static! {
             category: MoveCategory::Special,
             base_power: MovePower::Constant(90),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -1433,6 +2646,10 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::StatChange {
@@ -1451,6 +2668,7 @@ lazy_static! {
             category: MoveCategory::Special,
             base_power: MovePower::Constant(65),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -1459,6 +2677,10 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::Confusion,
@@ -1466,6 +2688,31 @@ lazy_static! {
             critical_hit: false,
         });
 
+        result.push(Move {
+            id: "PsychUp".to_string(),
+            display_name: "Psych Up".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::PsychUp),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
         result.push(Move {
             id: "QuickAttack".to_string(),
             display_name: "Quick Attack".to_string(),
@@ -1474,14 +2721,69 @@ lazy_static! {
             category: MoveCategory::Physical,
             base_power: MovePower::Constant(40),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Contact],
             on_usage_attempt: None,
             pp: 30,
             priority: 1,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "QuickGuard".to_string(),
+            display_name: "Quick Guard".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Fight,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 15,
+            priority: 3,
+            target_type: TargetType::User,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Protection(Protection::QuickGuard)),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "RainDance".to_string(),
+            display_name: "Rain Dance".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Water,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 5,
+            priority: 0,
+            target_type: TargetType::User,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Weather(Weather::Rain)),
             secondary_effect: None,
             critical_hit: false,
         });
@@ -1494,6 +2796,7 @@ lazy_static! {
             category: MoveCategory::Physical,
             base_power: MovePower::Constant(55),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(95),
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -1502,10 +2805,64 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::AllAdjacentFoes,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: None,
             critical_hit: true,
         });
 
+        result.push(Move {
+            id: "Recover".to_string(),
+            display_name: "Recover".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::User,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Heal(50)),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Reflect".to_string(),
+            display_name: "Reflect".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Psychic,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 20,
+            priority: 0,
+            target_type: TargetType::User,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::SideCondition(SideCondition::Reflect)),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
         result.push(Move {
             id: "RockSlide".to_string(),
             display_name: "Rock Slide".to_string(),
@@ -1514,6 +2871,7 @@ lazy_static! {
             category: MoveCategory::Physical,
             base_power: MovePower::Constant(75),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(90),
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -1522,6 +2880,10 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::AllAdjacentFoes,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 30,
                 effect: SimpleEffect::Flinch,
@@ -1537,6 +2899,7 @@ lazy_static! {
             category: MoveCategory::Physical,
             base_power: MovePower::Constant(50),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(90),
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -1545,44 +2908,157 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "RollingKick".to_string(),
+            display_name: "Rolling Kick".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Fight,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(60),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(85),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Contact],
+            on_usage_attempt: None,
+            pp: 15,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 30,
+                effect: SimpleEffect::Flinch,
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Roost".to_string(),
+            display_name: "Roost".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Flying,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::User,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Roost),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Safeguard".to_string(),
+            display_name: "Safeguard".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 25,
+            priority: 0,
+            target_type: TargetType::User,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::SideCondition(SideCondition::Safeguard)),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            // TODO: Sand Attack should still affect flying-types and Pokémon
+            // with Levitate
+            id: "SandAttack".to_string(),
+            display_name: "Sand Attack".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Ground,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 15,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatChange {
+                changes: vec![(Stat::Accuracy, -1)],
+                target: SimpleEffectTarget::MoveTarget,
+            }),
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "RollingKick".to_string(),
-            display_name: "Rolling Kick".to_string(),
+            id: "Sandstorm".to_string(),
+            display_name: "Sandstorm".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Fight,
-            category: MoveCategory::Physical,
-            base_power: MovePower::Constant(60),
+            move_type: PokemonType::Rock,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
             power_modifier: None,
-            accuracy: Some(85),
+            fixed_damage: None,
+            accuracy: None,
             accuracy_modifier: None,
             flags: HashSet::new(),
             on_usage_attempt: None,
-            pp: 15,
+            pp: 10,
             priority: 0,
-            target_type: TargetType::SingleAdjacentTarget,
+            target_type: TargetType::User,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 30,
-                effect: SimpleEffect::Flinch,
-            }),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Weather(Weather::Sandstorm)),
+            secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            // TODO: Sand Attack should still affect flying-types and Pokémon
-            // with Levitate
-            id: "SandAttack".to_string(),
-            display_name: "Sand Attack".to_string(),
+            id: "SandTomb".to_string(),
+            display_name: "Sand Tomb".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Ground,
-            category: MoveCategory::Status,
-            base_power: MovePower::Special,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(35),
             power_modifier: None,
-            accuracy: Some(100),
+            fixed_damage: None,
+            accuracy: Some(85),
             accuracy_modifier: None,
             flags: HashSet::new(),
             on_usage_attempt: None,
@@ -1590,13 +3066,11 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatChange {
-                    changes: vec![(Stat::Accuracy, -1)],
-                    target: SimpleEffectTarget::MoveTarget,
-                }
-            }),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Trap),
+            secondary_effect: None,
             critical_hit: false,
         });
 
@@ -1608,14 +3082,19 @@ lazy_static! {
             category: MoveCategory::Physical,
             base_power: MovePower::Constant(40),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Contact],
             on_usage_attempt: None,
             pp: 35,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
@@ -1628,21 +3107,48 @@ lazy_static! {
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(85),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Sound],
             on_usage_attempt: None,
             pp: 40,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatChange {
-                    changes: vec![(Stat::Defense, -2)],
-                    target: SimpleEffectTarget::MoveTarget,
-                }
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatChange {
+                changes: vec![(Stat::Defense, -2)],
+                target: SimpleEffectTarget::MoveTarget,
             }),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "SeismicToss".to_string(),
+            display_name: "Seismic Toss".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Fight,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: Some(FixedDamage::UserLevel),
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Contact],
+            on_usage_attempt: None,
+            pp: 20,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
             critical_hit: false,
         });
 
@@ -1654,6 +3160,7 @@ lazy_static! {
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
+            fixed_damage: None,
             accuracy: None,
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -1662,13 +3169,14 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::User,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatChange {
-                    changes: vec![(Stat::Attack, 1)],
-                    target: SimpleEffectTarget::MoveUser,
-                }
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatChange {
+                changes: vec![(Stat::Attack, 1)],
+                target: SimpleEffectTarget::MoveUser,
             }),
+            secondary_effect: None,
             critical_hit: false,
         });
 
@@ -1680,27 +3188,79 @@ lazy_static! {
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(55),
             accuracy_modifier: None,
-            flags: HashSet::new(),
-            on_usage_attempt: Some(|backend, _user, target, _mov| {
-                if !backend.can_inflict_non_volatile_status_condition_to(target, SimpleStatusCondition::Sleep) {
-                    return ModifiedUsageAttempt::Fail;
-                }
-
-                ModifiedUsageAttempt::Continue
-            }),
+            flags: flags![MoveFlag::Sound],
+            on_usage_attempt: None,
             pp: 15,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatusCondition(StatusCondition::Sleep {
-                    // TODO: randomize duration
-                    remaining_turns: 1,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatusCondition(StatusCondition::Sleep {
+                // TODO: randomize duration
+                remaining_turns: 1,
+            })),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "SkullBash".to_string(),
+            display_name: "Skull Bash".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(130),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Contact],
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: Some(Charge {
+                semi_invulnerable: false,
+                on_charge: Some(|backend, user| {
+                    backend.change_stat_stage(user, Stat::Defense, 1);
+                    true
                 }),
             }),
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "SlackOff".to_string(),
+            display_name: "Slack Off".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::User,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Heal(50)),
+            secondary_effect: None,
             critical_hit: false,
         });
 
@@ -1712,14 +3272,19 @@ lazy_static! {
             category: MoveCategory::Physical,
             base_power: MovePower::Constant(80),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(75),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Contact],
             on_usage_attempt: None,
             pp: 20,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
@@ -1732,14 +3297,19 @@ lazy_static! {
             category: MoveCategory::Physical,
             base_power: MovePower::Constant(70),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Contact],
             on_usage_attempt: None,
             pp: 20,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: None,
             critical_hit: true,
         });
@@ -1752,27 +3322,23 @@ lazy_static! {
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(75),
             accuracy_modifier: None,
             flags: HashSet::new(),
-            on_usage_attempt: Some(|backend, _user, target, _mov| {
-                if !backend.can_inflict_non_volatile_status_condition_to(target, SimpleStatusCondition::Sleep) {
-                    return ModifiedUsageAttempt::Fail;
-                }
-
-                ModifiedUsageAttempt::Continue
-            }),
+            on_usage_attempt: None,
             pp: 15,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatusCondition(StatusCondition::Sleep {
-                    // TODO: randomize duration
-                    remaining_turns: 1,
-                }),
-            }),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatusCondition(StatusCondition::Sleep {
+                // TODO: randomize duration
+                remaining_turns: 1,
+            })),
+            secondary_effect: None,
             critical_hit: false,
         });
 
@@ -1784,6 +3350,7 @@ lazy_static! {
             category: MoveCategory::Special,
             base_power: MovePower::Constant(65),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -1792,6 +3359,10 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 30,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Poison),
@@ -1807,6 +3378,7 @@ lazy_static! {
             category: MoveCategory::Special,
             base_power: MovePower::Constant(30),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(70),
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -1815,6 +3387,10 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 40,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Poison),
@@ -1830,6 +3406,7 @@ lazy_static! {
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -1838,13 +3415,72 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatChange {
-                    changes: vec![(Stat::Accuracy, -1)],
-                    target: SimpleEffectTarget::MoveTarget,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatChange {
+                changes: vec![(Stat::Accuracy, -1)],
+                target: SimpleEffectTarget::MoveTarget,
+            }),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "SolarBeam".to_string(),
+            display_name: "Solar Beam".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Grass,
+            category: MoveCategory::Special,
+            base_power: MovePower::Constant(120),
+            power_modifier: Some(|backend, _user, _target, _mov| {
+                match backend.get_weather() {
+                    Some(Weather::Rain) | Some(Weather::Sandstorm) | Some(Weather::Hail) => 60,
+                    _ => 120,
                 }
             }),
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: Some(Charge {
+                semi_invulnerable: false,
+                on_charge: Some(|backend, _user| backend.get_weather() != Some(Weather::Sun)),
+            }),
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Spite".to_string(),
+            display_name: "Spite".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Ghost,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Spite),
+            secondary_effect: None,
             critical_hit: false,
         });
 
@@ -1856,6 +3492,7 @@ lazy_static! {
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
+            fixed_damage: None,
             accuracy: None,
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -1864,6 +3501,10 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::User,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
@@ -1876,102 +3517,226 @@ lazy_static! {
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
             flags: HashSet::new(),
-            on_usage_attempt: Some(|backend, _user, target, _mov| {
-                if !backend.can_inflict_non_volatile_status_condition_to(target, SimpleStatusCondition::Sleep) {
-                    return ModifiedUsageAttempt::Fail;
-                }
+            on_usage_attempt: None,
+            pp: 15,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatusCondition(StatusCondition::Sleep {
+                // TODO: randomize duration
+                remaining_turns: 1,
+            })),
+            secondary_effect: None,
+            critical_hit: false,
+        });
 
-                ModifiedUsageAttempt::Continue
-            }),
+        result.push(Move {
+            id: "StealthRock".to_string(),
+            display_name: "Stealth Rock".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Rock,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 20,
+            priority: 0,
+            target_type: TargetType::User,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::SideCondition(SideCondition::StealthRock)),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Strength".to_string(),
+            display_name: "Strength".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(80),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Contact],
+            on_usage_attempt: None,
             pp: 15,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatusCondition(StatusCondition::Sleep {
-                    // TODO: randomize duration
-                    remaining_turns: 1,
-                }),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "StringShot".to_string(),
+            display_name: "String Shot".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Bug,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(95),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 40,
+            priority: 0,
+            target_type: TargetType::AllAdjacentFoes,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatChange {
+                changes: vec![(Stat::Speed, -1)],
+                target: SimpleEffectTarget::MoveTarget,
             }),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Struggle".to_string(),
+            display_name: "Struggle".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(50),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Contact, MoveFlag::Typeless, MoveFlag::MaxHpRecoil],
+            on_usage_attempt: None,
+            pp: 1,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentFoe,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: Some(0.25),
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "StunSpore".to_string(),
+            display_name: "Stun Spore".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Grass,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(75),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 30,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatusCondition(StatusCondition::Paralysis)),
+            secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Strength".to_string(),
-            display_name: "Strength".to_string(),
+            id: "Substitute".to_string(),
+            display_name: "Substitute".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
-            category: MoveCategory::Physical,
-            base_power: MovePower::Constant(80),
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
             power_modifier: None,
-            accuracy: Some(100),
+            fixed_damage: None,
+            accuracy: None,
             accuracy_modifier: None,
             flags: HashSet::new(),
             on_usage_attempt: None,
-            pp: 15,
+            pp: 10,
             priority: 0,
-            target_type: TargetType::SingleAdjacentTarget,
+            target_type: TargetType::User,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Substitute),
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "StringShot".to_string(),
-            display_name: "String Shot".to_string(),
+            id: "SunnyDay".to_string(),
+            display_name: "Sunny Day".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Bug,
+            move_type: PokemonType::Fire,
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
-            accuracy: Some(95),
+            fixed_damage: None,
+            accuracy: None,
             accuracy_modifier: None,
             flags: HashSet::new(),
             on_usage_attempt: None,
-            pp: 40,
+            pp: 5,
             priority: 0,
-            target_type: TargetType::AllAdjacentFoes,
+            target_type: TargetType::User,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatChange {
-                    changes: vec![(Stat::Speed, -1)],
-                    target: SimpleEffectTarget::MoveTarget,
-                }
-            }),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Weather(Weather::Sun)),
+            secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "StunSpore".to_string(),
-            display_name: "Stun Spore".to_string(),
+            id: "SuperFang".to_string(),
+            display_name: "Super Fang".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Grass,
-            category: MoveCategory::Status,
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Physical,
             base_power: MovePower::Special,
             power_modifier: None,
-            accuracy: Some(75),
+            fixed_damage: Some(FixedDamage::TargetCurrentHp(50)),
+            accuracy: Some(90),
             accuracy_modifier: None,
-            flags: HashSet::new(),
-            on_usage_attempt: Some(|backend, _user, target, _mov| {
-                if !backend.can_inflict_non_volatile_status_condition_to(target, SimpleStatusCondition::Paralysis) {
-                    return ModifiedUsageAttempt::Fail;
-                }
-
-                ModifiedUsageAttempt::Continue
-            }),
-            pp: 30,
+            flags: flags![MoveFlag::Contact],
+            on_usage_attempt: None,
+            pp: 10,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatusCondition(StatusCondition::Paralysis),
-            }),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
             critical_hit: false,
         });
 
@@ -1983,24 +3748,20 @@ lazy_static! {
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(55),
             accuracy_modifier: None,
-            flags: HashSet::new(),
-            on_usage_attempt: Some(|backend, _user, target, _mov| {
-                if backend.has_flag(target, "confusion") {
-                    return ModifiedUsageAttempt::Fail;
-                }
-
-                ModifiedUsageAttempt::Continue
-            }),
+            flags: flags![MoveFlag::Sound],
+            on_usage_attempt: None,
             pp: 20,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::Confusion,
-            }),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Confusion),
+            secondary_effect: None,
             critical_hit: false,
         });
 
@@ -2012,6 +3773,7 @@ lazy_static! {
             category: MoveCategory::Special,
             base_power: MovePower::Constant(60),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: None,
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -2020,6 +3782,10 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::AllAdjacentFoes,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
@@ -2032,6 +3798,7 @@ lazy_static! {
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
+            fixed_damage: None,
             accuracy: None,
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -2040,13 +3807,39 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::User,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatChange {
-                    changes: vec![(Stat::Attack, 2)],
-                    target: SimpleEffectTarget::MoveUser,
-                }
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatChange {
+                changes: vec![(Stat::Attack, 2)],
+                target: SimpleEffectTarget::MoveUser,
             }),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Synthesis".to_string(),
+            display_name: "Synthesis".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Grass,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 5,
+            priority: 0,
+            target_type: TargetType::User,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::WeatherDependentHeal),
+            secondary_effect: None,
             critical_hit: false,
         });
 
@@ -2058,14 +3851,19 @@ lazy_static! {
             category: MoveCategory::Physical,
             base_power: MovePower::Constant(40),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Contact],
             on_usage_attempt: None,
             pp: 35,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
@@ -2078,6 +3876,7 @@ lazy_static! {
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -2086,13 +3885,123 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::AllAdjacentFoes,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatChange {
-                    changes: vec![(Stat::Defense, -1)],
-                    target: SimpleEffectTarget::MoveTarget,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatChange {
+                changes: vec![(Stat::Defense, -1)],
+                target: SimpleEffectTarget::MoveTarget,
+            }),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Tailwind".to_string(),
+            display_name: "Tailwind".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Flying,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 15,
+            priority: 0,
+            target_type: TargetType::User,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::SideCondition(SideCondition::Tailwind)),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "TakeDown".to_string(),
+            display_name: "Take Down".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(90),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(85),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Contact],
+            on_usage_attempt: None,
+            pp: 20,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: Some(0.25),
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Thrash".to_string(),
+            display_name: "Thrash".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(120),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Contact, MoveFlag::Rampage],
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentFoe,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Thunder".to_string(),
+            display_name: "Thunder".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Electric,
+            category: MoveCategory::Special,
+            base_power: MovePower::Constant(110),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(70),
+            accuracy_modifier: Some(|backend, _user, _target, _mov| {
+                match backend.get_weather() {
+                    Some(Weather::Rain) => ModifiedAccuracy::Hit,
+                    Some(Weather::Sun) => ModifiedAccuracy::NewValue(50),
+                    _ => ModifiedAccuracy::OriginalValue,
                 }
             }),
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 10,
+                effect: SimpleEffect::StatusCondition(StatusCondition::Paralysis),
+            }),
             critical_hit: false,
         });
 
@@ -2104,6 +4013,7 @@ lazy_static! {
             category: MoveCategory::Special,
             base_power: MovePower::Constant(90),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -2112,6 +4022,10 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Paralysis),
@@ -2127,14 +4041,19 @@ lazy_static! {
             category: MoveCategory::Physical,
             base_power: MovePower::Constant(75),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Contact, MoveFlag::Punch],
             on_usage_attempt: None,
             pp: 15,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Paralysis),
@@ -2150,6 +4069,7 @@ lazy_static! {
             category: MoveCategory::Special,
             base_power: MovePower::Constant(40),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -2158,6 +4078,10 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Paralysis),
@@ -2173,24 +4097,20 @@ lazy_static! {
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(90),
             accuracy_modifier: None,
             flags: HashSet::new(),
-            on_usage_attempt: Some(|backend, _user, target, _mov| {
-                if !backend.can_inflict_non_volatile_status_condition_to(target, SimpleStatusCondition::Paralysis) {
-                    return ModifiedUsageAttempt::Fail;
-                }
-
-                ModifiedUsageAttempt::Continue
-            }),
+            on_usage_attempt: None,
             pp: 20,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatusCondition(StatusCondition::Paralysis),
-            }),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatusCondition(StatusCondition::Paralysis)),
+            secondary_effect: None,
             critical_hit: false,
         });
 
@@ -2202,6 +4122,7 @@ lazy_static! {
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(90),
             accuracy_modifier: Some(|backend, user, _target, _mov| {
                 if backend.has_type(user, PokemonType::Poison) {
@@ -2211,21 +4132,18 @@ lazy_static! {
                 ModifiedAccuracy::OriginalValue
             }),
             flags: HashSet::new(),
-            on_usage_attempt: Some(|backend, _user, target, _mov| {
-                if !backend.can_inflict_non_volatile_status_condition_to(target, SimpleStatusCondition::Toxic) {
-                    return ModifiedUsageAttempt::Fail;
-                }
-
-                ModifiedUsageAttempt::Continue
-            }),
+            on_usage_attempt: None,
             pp: 10,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatusCondition(StatusCondition::Toxic { counter: 1 }),
-            }),
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatusCondition(StatusCondition::Toxic {
+                counter: 1,
+            })),
+            secondary_effect: None,
             critical_hit: false,
         });
 
@@ -2237,14 +4155,19 @@ lazy_static! {
             category: MoveCategory::Physical,
             base_power: MovePower::Constant(45),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Contact],
             on_usage_attempt: None,
             pp: 25,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
@@ -2257,14 +4180,19 @@ lazy_static! {
             category: MoveCategory::Physical,
             base_power: MovePower::Constant(55),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Contact],
             on_usage_attempt: None,
             pp: 30,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
@@ -2277,14 +4205,19 @@ lazy_static! {
             category: MoveCategory::Physical,
             base_power: MovePower::Constant(80),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Contact],
             on_usage_attempt: None,
             pp: 15,
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 20,
                 effect: SimpleEffect::Flinch,
@@ -2300,6 +4233,7 @@ lazy_static! {
             category: MoveCategory::Special,
             base_power: MovePower::Constant(40),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -2308,6 +4242,60 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Whirlpool".to_string(),
+            display_name: "Whirlpool".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Water,
+            category: MoveCategory::Special,
+            base_power: MovePower::Constant(35),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(85),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 15,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Trap),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "WideGuard".to_string(),
+            display_name: "Wide Guard".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Rock,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 3,
+            target_type: TargetType::User,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Protection(Protection::WideGuard)),
             secondary_effect: None,
             critical_hit: false,
         });
@@ -2320,14 +4308,19 @@ lazy_static! {
             category: MoveCategory::Physical,
             base_power: MovePower::Constant(60),
             power_modifier: None,
+            fixed_damage: None,
             accuracy: Some(100),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::Contact],
             on_usage_attempt: None,
             pp: 35,
             priority: 0,
             target_type: TargetType::SingleTarget,
             multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
@@ -2340,6 +4333,7 @@ lazy_static! {
             category: MoveCategory::Status,
             base_power: MovePower::Special,
             power_modifier: None,
+            fixed_damage: None,
             accuracy: None,
             accuracy_modifier: None,
             flags: HashSet::new(),
@@ -2348,13 +4342,64 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::User,
             multi_hit: None,
-            secondary_effect: Some(SecondaryEffect {
-                chance: 100,
-                effect: SimpleEffect::StatChange {
-                    changes: vec![(Stat::Defense, 1)],
-                    target: SimpleEffectTarget::MoveUser,
-                }
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::StatChange {
+                changes: vec![(Stat::Defense, 1)],
+                target: SimpleEffectTarget::MoveUser,
             }),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Wrap".to_string(),
+            display_name: "Wrap".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(15),
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: Some(90),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::Contact],
+            on_usage_attempt: None,
+            pp: 20,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Trap),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: "Yawn".to_string(),
+            display_name: "Yawn".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            fixed_damage: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            charge: None,
+            drain: None,
+            recoil: None,
+            primary_effect: Some(SimpleEffect::Yawn),
+            secondary_effect: None,
             critical_hit: false,
         });
 
@@ -2365,8 +4410,39 @@ lazy_static! {
                 .collect::<HashMap<_, _>>()
         )
     };
+
+    static ref CONFUSION_MOVE: Move = Move {
+        id: "ConfusionSelfHit".to_string(),
+        display_name: "Confusion".to_string(),
+        description: "".to_string(),
+        move_type: PokemonType::Normal,
+        category: MoveCategory::Physical,
+        base_power: MovePower::Constant(40),
+        power_modifier: None,
+        fixed_damage: None,
+        accuracy: None,
+        accuracy_modifier: None,
+        flags: flags![MoveFlag::Typeless],
+        on_usage_attempt: None,
+        pp: 1,
+        priority: 0,
+        target_type: TargetType::User,
+        multi_hit: None,
+        charge: None,
+        drain: None,
+        recoil: None,
+        primary_effect: None,
+        secondary_effect: None,
+        critical_hit: false,
+    };
 }
 
 pub fn get_all_moves() -> &'static MoveDex {
     &MOVEDEX
 }
+
+/// Returns the attack that a confused Pokémon uses against itself. It isn't
+/// part of the MoveDex, since it can't be learned or called by other moves.
+pub fn get_confusion_move() -> &'static Move {
+    &CONFUSION_MOVE
+}
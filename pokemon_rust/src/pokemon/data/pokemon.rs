@@ -75,17 +75,17 @@ lazy_static! {
                 1: "Growl",
                 3: "VineWhip",
                 // 6: "Growth",
-                // 9: "LeechSeed",
+                9: "LeechSeed",
                 12: "RazorLeaf",
                 15: "PoisonPowder",
                 15: "SleepPowder",
                 // 18: "SeedBomb",
-                // 21: "TakeDown",
+                21: "TakeDown",
                 // 24: "SweetScent",
-                // 27: "Synthesis",
+                27: "Synthesis",
                 // 30: "WorrySeed",
-                // 33: "DoubleEdge",
-                // 36: "SolarBeam",
+                33: "DoubleEdge",
+                36: "SolarBeam",
             ],
         });
 
@@ -112,9 +112,9 @@ lazy_static! {
                 20: "Slash",
                 24: "Flamethrower",
                 // 28: "ScaryFace",
-                // 32: "FireSpin",
+                32: "FireSpin",
                 // 36: "Inferno",
-                // 40: "FlareBlitz",
+                40: "FlareBlitz",
             ],
         });
 
@@ -140,13 +140,13 @@ lazy_static! {
                 // 12: "Bite",
                 13: "Bubble",
                 // 15: "WaterPulse",
-                // 18: "Protect",
-                // 21: "RainDance",
+                18: "Protect",
+                21: "RainDance",
                 // 24: "AquaTail",
                 // 27: "ShellSmash",
                 // 30: "IronDefense",
                 33: "HydroPump",
-                // 36: "SkullBash",
+                36: "SkullBash",
             ],
         });
 
@@ -219,9 +219,9 @@ lazy_static! {
                 16: "Psybeam",
                 // 20: "Whirlwind",
                 // 24: "AirSlash",
-                // 28: "Safeguard",
+                28: "Safeguard",
                 // 32: "BugBuzz",
-                // 36: "TailWind",
+                36: "Tailwind",
                 // 40: "RagePowder",
                 // 44: "QuiverDance",
             ],
@@ -269,11 +269,11 @@ lazy_static! {
                 // 25: "FeatherDance",
                 29: "Agility",
                 33: "WingAttack",
-                // 37: "Roost",
-                // 41: "TailWind",
-                // 45: "MirrorMove",
+                37: "Roost",
+                41: "Tailwind",
+                45: "MirrorMove",
                 // 49: "AirSlash",
-                // 53: "Hurricane",
+                53: "Hurricane",
             ],
         });
 
@@ -296,7 +296,7 @@ lazy_static! {
                 6: "QuickAttack",
                 // 9: "FocusEnergy",
                 12: "Bite",
-                // 15: "SuperFang",
+                15: "SuperFang",
                 // 18 "Crunch",
                 21: "HyperFang",
                 // 24: "SuckerPunch",
@@ -323,8 +323,8 @@ lazy_static! {
                 8: "Leer",
                 // 11: "FocusEnergy",
                 16: "FuryAttack",
-                // 19: "MirrorMove",
-                // 24: "Roost",
+                19: "MirrorMove",
+                24: "Roost",
                 27: "Agility",
                 32: "DrillPeck",
             ],
@@ -344,12 +344,12 @@ lazy_static! {
             abilities: ["Intimidate", "ShedSkin"],
             hidden_abilities: ["Unnerve"],
             move_table: [
-                // 1: "Wrap",
+                1: "Wrap",
                 // 1: "PoisonSting",
                 // 4: "Leer",
                 // 8: "Acid",
                 // 12: "Bite",
-                // 16: "Haze",
+                16: "Haze",
                 20: "Glare",
                 // 24: "PoisonJab",
                 28: "Slam",
@@ -390,8 +390,8 @@ lazy_static! {
                 // 28: "Slam",
                 // 32: "Discharge",
                 36: "Thunderbolt",
-                // 40: "LightScreen",
-                // 44: "Thunder",
+                40: "LightScreen",
+                44: "Thunder",
             ],
         });
 
@@ -412,20 +412,20 @@ lazy_static! {
                 1: "Sing",
                 // 1: "SweetKiss",
                 // 1: "DisarmingVoice",
-                // 1: "Encore",
+                1: "Encore",
                 // 1: "Charm",
                 // 1: "Splash",
                 1: "Pound",
-                // 1: "Copycat",
+                1: "Copycat",
                 1: "Growl",
                 // 1: "DefenseCurl",
                 // 4: "StoredPower",
                 // 8: "Minimize",
                 10: "DoubleSlap",
                 // 12: "AfterYou",
-                // 16: "LifeDew",
-                // 20: "Metronome",
-                // 24: "Moonlight",
+                16: "LifeDew",
+                20: "Metronome",
+                24: "Moonlight",
                 // 28: "Gravity",
                 // 32: "MeteorMash",
                 // 36: "FollowMe",
@@ -451,17 +451,17 @@ lazy_static! {
             move_table: [
                 1: "Ember",
                 1: "TailWhip",
-                // 4: "Disable",
+                4: "Disable",
                 8: "QuickAttack",
-                // 12: "Spite",
+                12: "Spite",
                 // 16: "Incinerate",
                 20: "ConfuseRay",
                 // 24: "WillOWisp",
                 // 28: "ExtraSensory",
                 // 32: "Flamethrower",
                 // 36: "Imprison",
-                // 40: "FireSpin",
-                // 44: "Safeguard",
+                40: "FireSpin",
+                44: "Safeguard",
                 // 48: "Inferno",
                 // 52: "Grudge",
                 56: "FireBlast",
@@ -482,20 +482,20 @@ lazy_static! {
             abilities: ["Chlorophyll"],
             hidden_abilities: ["RunAway"],
             move_table: [
-                // 1: "Absorb",
+                1: "Absorb",
                 // 1: "Growth",
                 4: "Acid",
                 // 8: "SweetScent",
-                // 12: "MegaDrain",
+                12: "MegaDrain",
                 14: "PoisonPowder",
                 16: "StunSpore",
                 18: "SleepPowder",
-                // 20: "GigaDrain",
+                20: "GigaDrain",
                 24: "Toxic",
                 // 28: "MoonBlast",
                 // 32: "GrassyTerrain",
-                // 36: "Moonlight",
-                // 40: "PetalDance",
+                36: "Moonlight",
+                40: "PetalDance",
             ],
         });
 
@@ -517,11 +517,11 @@ lazy_static! {
                 2: "SleepPowder",
                 4: "StunSpore",
                 6: "PoisonPowder",
-                // 9: "Absorb",
+                9: "Absorb",
                 // 12: "Growth",
                 // 15: "FurySwipes",
                 19: "Slash",
-                // 23: "LeechLife",
+                23: "LeechLife",
                 27: "Spore",
                 // 31: "XScissor",
             ],
@@ -549,8 +549,8 @@ lazy_static! {
                 // 16: "Bulldoze",
                 // 20: "SuckerPunch",
                 // 24: "Slash",
-                // 28: "Sandstorm",
-                // 32: "Dig",
+                28: "Sandstorm",
+                32: "Dig",
                 // 36: "EarthPower",
                 // 40: "Earthquake",
                 44: "Fissure",
@@ -579,14 +579,14 @@ lazy_static! {
                 // 1: "Psybeam",
                 // 1: "Confusion",
                 // 1: "Teleport",
-                // 1: "Disable",
+                1: "Disable",
                 1: "Flash",
-                // 6: "Disable",
+                6: "Disable",
                 13: "Flash",
-                // 19: "NightShade",
-                // 26: "Substitute",
-                // 32: "Reflect",
-                // 39: "Recover",
+                19: "NightShade",
+                26: "Substitute",
+                32: "Reflect",
+                39: "Recover",
                 45: "Psychic",
             ],
         });
@@ -617,10 +617,10 @@ lazy_static! {
                 29: "Strength",
                 // 32: "DualChop",
                 // 36: "BulkUp",
-                // 40: "SeismicToss",
+                40: "SeismicToss",
                 // 44: "DynamicPunch",
                 // 48: "CrossChop",
-                // 52: "DoubleEdge",
+                52: "DoubleEdge",
             ],
         });
 
@@ -643,7 +643,7 @@ lazy_static! {
                 9: "Supersonic",
                 13: "Acid",
                 18: "BubbleBeam",
-                // 22: "Wrap",
+                22: "Wrap",
                 // 27: "Surf",
                 31: "Barrier",
                 // 36: "PoisonJab",
@@ -667,21 +667,21 @@ lazy_static! {
             hidden_abilities: ["Regenerator"],
             move_table: [
                 1: "Tackle",
-                // 1: "Curse",
+                1: "Curse",
                 3: "Growl",
                 6: "WaterGun",
-                // 9: "Yawn",
+                9: "Yawn",
                 // 12: "Confusion",
-                // 15: "Disable",
+                15: "Disable",
                 // 18: "WaterPulse",
                 21: "Headbutt",
                 // 24: "ZenHeadbutt",
                 27: "Amnesia",
                 // 30: "Surf",
-                // 33: "SlackOff",
+                33: "SlackOff",
                 36: "Psychic",
-                // 39: "PsychUp",
-                // 42: "RainDance",
+                39: "PsychUp",
+                42: "RainDance",
                 // 45: "HealPulse",
             ],
         });
@@ -708,13 +708,13 @@ lazy_static! {
                 // 20: "AerialAce",
                 // 25: "AirCutter",
                 // 30: "KnockOff",
-                // 35: "FalseSwipe",
+                35: "FalseSwipe",
                 // 40: "Slash",
                 // 45: "SwordsDance",
                 // 50: "AirSlash",
                 // 55: "LeafBlade",
                 60: "Agility",
-                // 65: "BraveBird",
+                65: "BraveBird",
             ],
         });
 
@@ -734,22 +734,22 @@ lazy_static! {
             move_table: [
                 1: "Tackle",
                 1: "Harden",
-                // 1: "Bind",
+                1: "Bind",
                 1: "RockThrow",
                 // 4: "SmackDown",
                 // 8: "RockPolish",
                 // 12: "DragonBreath",
-                // 16: "Curse",
+                16: "Curse",
                 20: "RockSlide",
                 24: "Screech",
-                // 28: "SandTomb",
-                // 32: "StealthRock",
+                28: "SandTomb",
+                32: "StealthRock",
                 // 36: "Slam",
-                // 40: "Sandstorm",
-                // 44: "Dig",
+                40: "Sandstorm",
+                44: "Dig",
                 // 48: "IronTail",
                 // 52: "StoneEdge",
-                // 56: "DoubleEdge",
+                56: "DoubleEdge",
             ],
         });
 
@@ -773,7 +773,7 @@ lazy_static! {
                 4: "Harden",
                 // 8: "MetalClaw",
                 // 12: "MudShot",
-                // 16: "Protect",
+                16: "Protect",
                 20: "BubbleBeam",
                 // 24: "Stomp",
                 // 29: "Flail",
@@ -833,8 +833,8 @@ lazy_static! {
                 // 18: "Rage",
                 // 24: "FocusEnergy",
                 // 26: "Bonemerang",
-                // 30: "Thrash",
-                // 36: "DoubleEdge",
+                30: "Thrash",
+                36: "DoubleEdge",
             ],
         });
 
@@ -865,9 +865,9 @@ lazy_static! {
                 5: "Meditate",
                 // 8: "LowKick",
                 9: "RollingKick",
-                // 12: "Endure",
+                12: "Endure",
                 // 16: "Revenge",
-                // 21: "WideGuard",
+                21: "WideGuard",
                 // 24: "BlazeKick",
                 // 28: "MindReader",
                 32: "MegaKick",
@@ -892,7 +892,7 @@ lazy_static! {
             hidden_abilities: ["InnerFocus"],
             move_table: [
                 1: "CometPunch",
-                // 1: "DrainPunch",
+                1: "DrainPunch",
                 // 1: "Feint",
                 // 1: "VacuumWave",
                 // 1: "BulletPunch",
@@ -904,9 +904,9 @@ lazy_static! {
                 // 1: "DrainPunch",
                 4: "MachPunch",
                 // 8: "Power-UpPunch",
-                // 12: "Detect",
+                12: "Detect",
                 // 16: "Revenge",
-                // 21: "QuickGuard",
+                21: "QuickGuard",
                 24: "ThunderPunch",
                 24: "IcePunch",
                 24: "FirePunch",
@@ -914,7 +914,7 @@ lazy_static! {
                 28: "Agility",
                 32: "MegaPunch",
                 // 36: "CloseCombat",
-                // 40: "Counter",
+                40: "Counter",
                 // 44: "FocusPunch",
             ],
         });
@@ -934,14 +934,14 @@ lazy_static! {
             hidden_abilities: ["CloudNine"],
             move_table: [
                 1: "Lick",
-                // 5: "Wrap",
+                5: "Wrap",
                 10: "Acid",
                 // 15: "Stomp",
-                // 20: "Disable",
-                // 25: "Bind",
+                20: "Disable",
+                25: "Bind",
                 // 30: "Slam",
                 35: "Screech",
-                // 40: "Thrash",
+                40: "Thrash",
                 // 45: "PowerWhip",
             ],
         });
@@ -967,14 +967,14 @@ lazy_static! {
                 // 12: "ClearSmog",
                 // 16: "Assurance",
                 20: "Sludge",
-                // 24: "Haze",
+                24: "Haze",
                 // 28: "SelfDestruct",
                 // 32: "SludgeBomb",
                 36: "Toxic",
                 // 40: "Belch",
                 // 44: "Explosion",
                 // 48: "Memento",
-                // 52: "DestinyBond",
+                52: "DestinyBond",
             ],
         });
 
@@ -1001,7 +1001,7 @@ lazy_static! {
                 // 25: "Stomp",
                 // 30: "RockBlast",
                 // 35: "Drillrun",
-                // 40: "TakeDown",
+                40: "TakeDown",
                 // 45: "Earthquake",
                 // 50: "StoneEdge",
                 // 55: "Megahorn",
@@ -1032,7 +1032,7 @@ lazy_static! {
                 // 30: "BodySlam",
                 35: "LovelyKiss",
                 40: "Psychic",
-                // 45: "Blizzard",
+                45: "Blizzard",
             ],
         });
 
@@ -1078,19 +1078,19 @@ lazy_static! {
                 // 1: "Twister",
                 // TODO: Learns when evolving
                 // 1: "Bite",
-                // 4: "Whirlpool",
+                4: "Whirlpool",
                 // 8: "IceFang",
                 // 12: "Brine",
                 // 16: "ScaryFace",
                 21: "Waterfall",
                 // 24: "Crunch",
-                // 28: "RainDance",
+                28: "RainDance",
                 // 32: "AquaTail",
                 // 36: "DragonDance",
                 40: "HydroPump",
-                // 44: "Hurricane",
-                // 48: "Thrash",
-                // 52: "HyperBeam",
+                44: "Hurricane",
+                48: "Thrash",
+                52: "HyperBeam",
             ],
         });
 
@@ -1111,17 +1111,17 @@ lazy_static! {
                 1: "Growl",
                 1: "WaterGun",
                 5: "Sing",
-                // 10: "Mist",
-                // 15: "LifeDew",
+                10: "Mist",
+                15: "LifeDew",
                 // 20: "IceShard",
                 25: "ConfuseRay",
                 // 30: "WaterPulse",
                 // 35: "Brine",
                 // 40: "BodySlam",
                 45: "IceBeam",
-                // 50: "RainDance",
+                50: "RainDance",
                 55: "HydroPump",
-                // 60: "PerishSong",
+                60: "PerishSong",
                 // 65: "SheerCold",
             ],
         });
@@ -1150,12 +1150,12 @@ lazy_static! {
                 // 15: "BabyDollEyes",
                 20: "Swift",
                 // 25: "Bite",
-                // 30: "Copycat",
-                // 35: "BatonPass",
-                // 40: "TakeDown",
+                30: "Copycat",
+                35: "BatonPass",
+                40: "TakeDown",
                 // 45: "Charm",
-                // 50: "DoubleEdge",
-                // 55: "LastResort",
+                50: "DoubleEdge",
+                55: "LastResort",
             ],
         });
 
@@ -1177,11 +1177,11 @@ lazy_static! {
                 // 1: "Covet",
                 // 1: "Swift",
                 // 1: "Bite",
-                // 1: "Copycat",
-                // 1: "BatonPass",
-                // 1: "TakeDown",
+                1: "Copycat",
+                1: "BatonPass",
+                1: "TakeDown",
                 // 1: "Charm",
-                // 1: "DoubleEdge",
+                1: "DoubleEdge",
                 // 1: "HelpingHand",
                 1: "Tackle",
                 1: "Growl",
@@ -1191,14 +1191,14 @@ lazy_static! {
                 5: "SandAttack",
                 10: "QuickAttack",
                 // 15: "BabyDollEyes",
-                // 20: "Haze",
+                20: "Haze",
                 // 25: "WaterPulse",
                 30: "AuroraBeam",
                 // 35: "AquaRing",
                 // 40: "MuddyWater",
                 45: "AcidArmor",
                 50: "HydroPump",
-                // 55: "LastResort",
+                55: "LastResort",
             ],
         });
 
@@ -1224,8 +1224,8 @@ lazy_static! {
                 // 22: "TriAttack",
                 // 27: "ThunderWave",
                 // 31: "Conversion",
-                // 36: "Recover",
-                // 40: "HyperBeam",
+                36: "Recover",
+                40: "HyperBeam",
             ],
         });
 
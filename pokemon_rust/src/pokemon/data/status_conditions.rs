@@ -73,8 +73,8 @@ pub fn get_status_condition_effect(
             on_stat_calculation: None,
             on_before_use_move: None,
             on_try_use_move: None,
-            on_try_deal_damage: Some(|_backend, _user, _target, mov, damage_dealt| {
-                if mov.category == MoveCategory::Physical {
+            on_try_deal_damage: Some(|backend, user, _target, mov, damage_dealt| {
+                if mov.category == MoveCategory::Physical && !backend.has_ability(user, "Guts") {
                     damage_dealt / 2
                 } else {
                     damage_dealt
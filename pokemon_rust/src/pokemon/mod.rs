@@ -6,6 +6,8 @@ use amethyst::ecs::World;
 
 use crate::constants::MOVE_LIMIT;
 
+use serde::{Deserialize, Serialize};
+
 use std::{
     collections::HashMap,
     fmt::{Debug, Error, Formatter},
@@ -13,7 +15,9 @@ use std::{
 };
 
 pub use self::data::{
-    movement::get_all_moves,
+    abilities::{get_ability_display_name, get_ability_effect, AbilityEffect},
+    items::{get_item_display_name, get_item_effect, ItemEffect},
+    movement::{get_all_moves, get_confusion_move},
     pokemon::get_all_pokemon_species,
     status_conditions::{get_status_condition_effect, StatusConditionEffect},
 };
@@ -55,7 +59,7 @@ impl PokeDex {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Pokemon {
     pub species_id: String,
     pub nature: Nature,
@@ -125,7 +129,7 @@ pub struct PokemonSpeciesData {
     pub evolution_data: Vec<EvolutionData>,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 pub enum Nature {
     Hardy,
     Lonely,
@@ -191,14 +195,14 @@ impl Nature {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum Gender {
     Male,
     Female,
     Genderless,
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum StatusCondition {
     Burn,
     Freeze,
@@ -208,7 +212,7 @@ pub enum StatusCondition {
     Sleep { remaining_turns: usize },
 }
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub enum SimpleStatusCondition {
     Burn,
     Freeze,
@@ -231,7 +235,7 @@ impl From<StatusCondition> for SimpleStatusCondition {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum PokerusData {
     Unaffected,
     Cured,
@@ -370,7 +374,7 @@ pub enum TimeOfDay {
     Night,
 }
 
-#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub enum Stat {
     HP,
     Attack,
@@ -1,4 +1,4 @@
-use crate::battle::backend::{rng::BattleRng, BattleBackend};
+use crate::battle::backend::{rng::BattleRng, BattleBackend, Protection, SideCondition, Weather};
 
 use std::collections::{HashMap, HashSet};
 
@@ -16,6 +16,10 @@ impl MoveDex {
     pub fn get_move(&self, id: &str) -> Option<&Move> {
         self.data.get(id)
     }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Move> {
+        self.data.values()
+    }
 }
 
 pub struct Move {
@@ -25,7 +29,10 @@ pub struct Move {
     pub move_type: PokemonType,
     pub category: MoveCategory,
     pub base_power: MovePower,
-    pub power_modifier: Option<MoveCallback<usize>>,
+    pub power_modifier: Option<ExtendedMoveCallback<usize>>,
+    /// Makes the move deal damage without the usual damage formula, e.g
+    /// Seismic Toss.
+    pub fixed_damage: Option<FixedDamage>,
     /// The accuracy of this move. This is None for moves that never miss,
     /// e.g Swift.
     pub accuracy: Option<usize>,
@@ -36,6 +43,18 @@ pub struct Move {
     pub priority: i8,
     pub target_type: TargetType,
     pub multi_hit: Option<MultiHit>,
+    /// Makes the user spend a turn charging before the move hits.
+    pub charge: Option<Charge>,
+    /// The fraction of the damage dealt that is restored to the user, e.g
+    /// 0.5 for Giga Drain.
+    pub drain: Option<f32>,
+    /// The fraction of the damage dealt that the user takes as recoil, e.g
+    /// 1/3 for Double-Edge. See `MoveFlag::MaxHpRecoil` for exceptions.
+    pub recoil: Option<f32>,
+    /// An effect that is always applied when the move hits. If the effect of
+    /// a Status move would do nothing (e.g the target already has a status
+    /// condition), the move fails.
+    pub primary_effect: Option<SimpleEffect>,
     /// A "shortcut" for moves with a simple secondary effect
     pub secondary_effect: Option<SecondaryEffect>,
     /// In this game, there's no RNG in critical hits: a move either always
@@ -62,6 +81,19 @@ pub enum MovePower {
     Special,
 }
 
+/// Ways for a move to deal damage that doesn't depend on the stats of the
+/// user and the target. Type immunities still apply.
+pub enum FixedDamage {
+    /// Always deals the same amount of damage, e.g Dragon Rage.
+    Constant(usize),
+    /// Deals damage equal to the level of the user, e.g Seismic Toss.
+    UserLevel,
+    /// Deals a percentage of the current HP of the target, e.g Super Fang.
+    TargetCurrentHp(usize),
+    /// Depends on what happened earlier in the battle, e.g Counter.
+    Custom(fn(backend: &BattleBackend, user: usize, target: usize) -> usize),
+}
+
 pub type MoveCallback<T = ()> = fn(user: &Pokemon, target: &Pokemon, movement: &Move) -> T;
 pub type ExtendedMoveCallback<T = ()> = fn(backend: &BattleBackend, user: usize, target: usize, movement: &Move) -> T;
 
@@ -75,7 +107,29 @@ pub enum ModifiedAccuracy {
 
 #[derive(Clone, Copy, Eq, Hash, PartialEq)]
 pub enum MoveFlag {
+    /// Moves that make physical contact with the target, which triggers
+    /// abilities like Static.
+    Contact,
+    /// Moves that always leave the target with at least 1 HP, e.g False
+    /// Swipe.
+    NonLethal,
     OneHitKO,
+    /// Punching moves, which are boosted by Iron Fist.
+    Punch,
+    /// Moves that force the user to recharge in the next turn, e.g Hyper
+    /// Beam.
+    Recharge,
+    /// Moves that the user keeps using for 2-3 turns, becoming confused
+    /// afterwards, e.g Thrash.
+    Rampage,
+    /// Sound-based moves, which go through substitutes.
+    Sound,
+    /// Moves without a type, which are never super effective nor boosted by
+    /// STAB, e.g Struggle.
+    Typeless,
+    /// Moves whose recoil is a fraction of the maximum HP of the user instead
+    /// of the damage dealt, e.g Struggle. Rock Head doesn't prevent it.
+    MaxHpRecoil,
 }
 
 #[derive(Eq, PartialEq)]
@@ -116,7 +170,17 @@ pub enum MultiHit {
         min_hits: usize,
         max_hits: usize,
     },
-    Custom(fn(rng: Box<dyn BattleRng>) -> usize),
+    Custom(fn(rng: &mut dyn BattleRng) -> usize),
+}
+
+/// Moves that spend a turn charging before hitting, e.g Solar Beam.
+pub struct Charge {
+    /// If `true`, the user can't be hit by most moves while charging, e.g
+    /// Dig.
+    pub semi_invulnerable: bool,
+    /// Called in the charging turn. Returning `false` makes the move hit
+    /// right away instead (e.g Solar Beam in harsh sunlight).
+    pub on_charge: Option<fn(backend: &mut BattleBackend, user: usize) -> bool>,
 }
 
 pub struct SecondaryEffect {
@@ -132,9 +196,62 @@ pub enum SimpleEffect {
         target: SimpleEffectTarget,
     },
     StatusCondition(StatusCondition),
+    Weather(Weather),
+    SideCondition(SideCondition),
+    /// Restores a percentage of the maximum HP of the target.
+    Heal(usize),
+    /// Restores half the maximum HP of the target, or more or less depending
+    /// on the weather, e.g Synthesis.
+    WeatherDependentHeal,
+    /// Restores half the maximum HP of the user, which loses its Flying type
+    /// until the end of the turn.
+    Roost,
+    Protection(Protection),
+    /// Traps the target for a few turns, e.g Wrap.
+    Trap,
+    /// Uses another move instead, e.g Metronome.
+    CallMove(CalledMove),
+    /// Prevents the target from using its last move for a few turns.
+    Disable,
+    /// Forces the target to repeat its last move for a few turns.
+    Encore,
+    /// Lowers the PP of the last move used by the target.
+    Spite,
+    /// Trades a quarter of the user's maximum HP for a substitute, which
+    /// takes hits in its place.
+    Substitute,
+    /// Drains HP from the target at the end of each turn.
+    LeechSeed,
+    /// Ghost-type users sacrifice half their HP to hurt a foe at the end of
+    /// each turn. Other users trade Speed for Attack and Defense instead.
+    Curse,
+    /// Makes every Pokémon that hears the song faint in three turns.
+    PerishSong,
+    /// Makes the target fall asleep at the end of the next turn.
+    Yawn,
+    /// Takes down the foe that knocks out the user before its next move.
+    DestinyBond,
+    /// Resets the stat stages of the target.
+    Haze,
+    /// Copies the stat stages of the target to the user.
+    PsychUp,
+    /// Switches the user out, passing its stat stages and some of its
+    /// volatile status conditions to its replacement.
+    BatonPass,
     OnHit(MoveCallback),
 }
 
+/// The move used by a move that calls other moves.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CalledMove {
+    /// A random move, e.g Metronome.
+    Random,
+    /// The last move used in the battle, e.g Copycat.
+    LastUsedInBattle,
+    /// The last move used by the target, e.g Mirror Move.
+    LastUsedByTarget,
+}
+
 pub enum SimpleEffectTarget {
     MoveTarget,
     MoveUser,